@@ -0,0 +1,175 @@
+//! Commit activity over time.
+//!
+//! Buckets commit timestamps by calendar day for the contribution calendar
+//! and by weekday and hour for the punch card. Both use the author's local
+//! time, so a commit made at 9am shows at 9am wherever it was made.
+
+use std::collections::HashMap;
+
+use crate::git::CommitInfo;
+
+/// Seconds in a calendar day.
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Commit counts of a history by day, weekday and hour.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Activity {
+    days: HashMap<i64, usize>,
+    punch_card: [[usize; 24]; 7],
+    today: i64,
+    total: usize,
+    last_commit: Option<i64>,
+}
+
+impl Activity {
+    /// Buckets the commits of a history.
+    ///
+    /// # Arguments
+    ///
+    /// * `commits`: Commits to count, each listed once
+    /// * `now`: Current time (Unix seconds), the last day of the calendar
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gitkyl::{Activity, list_commits};
+    /// use std::path::Path;
+    ///
+    /// let commits = list_commits(Path::new("."), None, None)?;
+    /// let activity = Activity::from_commits(&commits, 1_700_000_000);
+    /// println!("{} commits", activity.total());
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn from_commits(commits: &[CommitInfo], now: i64) -> Self {
+        let mut activity = Self {
+            today: now.div_euclid(SECONDS_PER_DAY),
+            ..Self::default()
+        };
+
+        for commit in commits {
+            let local = commit.date() + i64::from(commit.utc_offset());
+            let day = local.div_euclid(SECONDS_PER_DAY);
+            let hour = (local.rem_euclid(SECONDS_PER_DAY) / 3600) as usize;
+
+            *activity.days.entry(day).or_default() += 1;
+            activity.punch_card[weekday(day)][hour] += 1;
+            activity.total += 1;
+            activity.last_commit = activity.last_commit.max(Some(commit.date()));
+        }
+
+        activity
+    }
+
+    /// Day number (days since 1970-01-01) of the generation time.
+    pub fn today(&self) -> i64 {
+        self.today
+    }
+
+    /// Commits made on a day, by day number.
+    pub fn commits_on(&self, day: i64) -> usize {
+        self.days.get(&day).copied().unwrap_or(0)
+    }
+
+    /// Commits made from a day up to today, by day number.
+    pub fn commits_since(&self, day: i64) -> usize {
+        self.days
+            .iter()
+            .filter(|&(&d, _)| d >= day && d <= self.today)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    /// Commit counts by weekday (0 is Sunday) and hour of day.
+    pub fn punch_card(&self) -> &[[usize; 24]; 7] {
+        &self.punch_card
+    }
+
+    /// Number of counted commits.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Timestamp of the latest commit (Unix seconds).
+    pub fn last_commit(&self) -> Option<i64> {
+        self.last_commit
+    }
+}
+
+/// Weekday of a day number, 0 for Sunday through 6 for Saturday.
+pub(crate) fn weekday(day: i64) -> usize {
+    // 1970-01-01 was a Thursday
+    (day + 4).rem_euclid(7) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commit_at(date: i64, utc_offset: i32) -> CommitInfo {
+        CommitInfo::new(
+            format!("{:040x}", date),
+            "Change".to_string(),
+            "Change".to_string(),
+            "Alice".to_string(),
+            date,
+        )
+        .with_utc_offset(utc_offset)
+    }
+
+    #[test]
+    fn test_weekday() {
+        assert_eq!(weekday(0), 4, "1970-01-01 was a Thursday");
+        assert_eq!(weekday(3), 0, "1970-01-04 was a Sunday");
+        assert_eq!(weekday(-1), 3);
+    }
+
+    #[test]
+    fn test_from_commits_buckets_by_local_time() {
+        // Arrange: 2023-11-14 22:13 UTC, once in UTC and once at UTC+02:00
+        let date = 1_700_000_000;
+        let commits = vec![commit_at(date, 0), commit_at(date, 7200)];
+
+        // Act
+        let activity = Activity::from_commits(&commits, date);
+
+        // Assert
+        let day = date / SECONDS_PER_DAY;
+        assert_eq!(activity.total(), 2);
+        assert_eq!(activity.commits_on(day), 1);
+        assert_eq!(
+            activity.commits_on(day + 1),
+            1,
+            "Local time is past midnight"
+        );
+        assert_eq!(activity.punch_card()[2][22], 1, "Tuesday 22:00");
+        assert_eq!(activity.punch_card()[3][0], 1, "Wednesday 00:00");
+        assert_eq!(activity.last_commit(), Some(date));
+    }
+
+    #[test]
+    fn test_commits_since_counts_window() {
+        // Arrange
+        let now = 100 * SECONDS_PER_DAY;
+        let commits = vec![
+            commit_at(10 * SECONDS_PER_DAY, 0),
+            commit_at(60 * SECONDS_PER_DAY, 0),
+            commit_at(99 * SECONDS_PER_DAY, 0),
+        ];
+
+        // Act
+        let activity = Activity::from_commits(&commits, now);
+
+        // Assert
+        assert_eq!(activity.today(), 100);
+        assert_eq!(activity.commits_since(50), 2);
+        assert_eq!(activity.commits_since(0), 3);
+    }
+
+    #[test]
+    fn test_empty_history() {
+        let activity = Activity::from_commits(&[], 0);
+
+        assert_eq!(activity.total(), 0);
+        assert_eq!(activity.last_commit(), None);
+    }
+}
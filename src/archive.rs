@@ -0,0 +1,915 @@
+//! Source archive generation.
+//!
+//! Builds `.tar.gz` and `.zip` archives of a commit's tree directly from git
+//! objects, matching `git archive`: entries share a common prefix directory,
+//! file times are the commit time, the commit hash is recorded in the
+//! archive (pax global header or zip comment), and paths marked
+//! `export-ignore` in the tree's `.gitattributes` files are left out.
+
+use anyhow::{Context, Result};
+use flate2::Compression;
+use flate2::write::{DeflateEncoder, GzEncoder};
+use gix::bstr::ByteSlice;
+use std::io::Write;
+use std::path::Path;
+
+use crate::attributes::{attributes_base, is_set_on_path, parse_rules, sort_rules};
+use crate::git::resolve_commit;
+use crate::util::civil_from_days;
+
+/// Attribute leaving paths out of archives.
+const EXPORT_IGNORE: &str = "export-ignore";
+
+/// Tar block size in bytes.
+const TAR_BLOCK: usize = 512;
+
+/// Largest size representable in the 11 octal digits of a ustar header.
+const TAR_MAX_SIZE: u64 = 0o77777777777;
+
+/// Sizes and offsets from this value on move to zip64 extra fields.
+const ZIP64_LIMIT: u64 = 0xffff_ffff;
+
+/// Entry counts from this value on move to the zip64 end record.
+const ZIP64_COUNT_LIMIT: usize = 0xffff;
+
+/// Zip version needed for zip64 structures (4.5).
+const ZIP64_VERSION: u16 = 45;
+
+/// Downloadable archive format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    /// Gzip compressed tarball
+    TarGz,
+    /// Zip file with deflate compression
+    Zip,
+}
+
+impl ArchiveFormat {
+    /// All formats, in the order they are offered for download.
+    pub const ALL: [ArchiveFormat; 2] = [ArchiveFormat::Zip, ArchiveFormat::TarGz];
+
+    /// File extension without leading dot.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ArchiveFormat::TarGz => "tar.gz",
+            ArchiveFormat::Zip => "zip",
+        }
+    }
+}
+
+/// Kind of archive entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EntryKind {
+    File,
+    Executable,
+    Symlink,
+}
+
+impl EntryKind {
+    /// Unix permission bits including the file type.
+    fn unix_mode(&self) -> u32 {
+        match self {
+            EntryKind::File => 0o100644,
+            EntryKind::Executable => 0o100755,
+            EntryKind::Symlink => 0o120777,
+        }
+    }
+}
+
+/// Returns the archive file name (without extension) for a reference.
+///
+/// Slashes in reference names are replaced so archives stay in a single
+/// directory, and a `.git` suffix of the repository name is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use gitkyl::archive_name;
+///
+/// assert_eq!(archive_name("gitkyl", "v1.0.0"), "gitkyl-v1.0.0");
+/// assert_eq!(archive_name("tools.git", "release/2.x"), "tools-release-2.x");
+/// ```
+pub fn archive_name(repo_name: &str, ref_name: &str) -> String {
+    let repo_name = repo_name.strip_suffix(".git").unwrap_or(repo_name);
+    format!("{}-{}", repo_name, ref_name.replace('/', "-"))
+}
+
+/// Writes an archive of the tree at a reference.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `ref_name`: Reference (branch/tag) to archive
+/// * `format`: Archive format
+/// * `prefix`: Directory name every entry is placed under
+/// * `writer`: Destination for the archive bytes
+///
+/// # Errors
+///
+/// Returns error if the repository or reference cannot be read, objects are
+/// missing, or writing fails
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::{ArchiveFormat, write_archive};
+/// use std::path::Path;
+///
+/// let file = std::fs::File::create("gitkyl-v1.0.0.zip")?;
+/// write_archive(Path::new("."), "v1.0.0", ArchiveFormat::Zip, "gitkyl-v1.0.0", file)?;
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn write_archive(
+    repo_path: impl AsRef<Path>,
+    ref_name: &str,
+    format: ArchiveFormat,
+    prefix: &str,
+    writer: impl Write,
+) -> Result<()> {
+    let repo = gix::open(repo_path.as_ref()).with_context(|| {
+        format!(
+            "Failed to open repository at {}",
+            repo_path.as_ref().display()
+        )
+    })?;
+
+    let commit = resolve_commit(&repo, Some(ref_name))?;
+    let mtime = commit
+        .committer()
+        .context("Failed to read committer")?
+        .time
+        .seconds;
+    let commit_id = commit.id.to_hex().to_string();
+
+    let tree = commit.tree().context("Failed to read commit tree")?;
+    let mut files: Vec<(String, gix::ObjectId, EntryKind)> = tree
+        .traverse()
+        .breadthfirst
+        .files()
+        .context("Failed to traverse tree")?
+        .into_iter()
+        .filter_map(|entry| {
+            let kind = if entry.mode.is_link() {
+                EntryKind::Symlink
+            } else if entry.mode.is_executable() {
+                EntryKind::Executable
+            } else if entry.mode.is_blob() {
+                EntryKind::File
+            } else {
+                return None;
+            };
+            Some((entry.filepath.to_str_lossy().to_string(), entry.oid, kind))
+        })
+        .collect();
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let read = |oid: gix::ObjectId| -> Result<Vec<u8>> {
+        Ok(repo
+            .find_object(oid)
+            .with_context(|| format!("Failed to find blob {}", oid))?
+            .detach()
+            .data)
+    };
+
+    let mut rules = Vec::new();
+    for (path, oid, _) in &files {
+        if let Some(base) = attributes_base(path) {
+            rules.extend(parse_rules(
+                base,
+                &read(*oid)?.to_str_lossy(),
+                EXPORT_IGNORE,
+            ));
+        }
+    }
+    sort_rules(&mut rules);
+
+    let prefix = prefix.trim_end_matches('/');
+    let mut sink: Box<dyn ArchiveSink> = match format {
+        ArchiveFormat::TarGz => Box::new(TarSink::new(
+            GzEncoder::new(writer, Compression::default()),
+            mtime,
+            &commit_id,
+        )?),
+        ArchiveFormat::Zip => Box::new(ZipSink::new(writer, mtime, &commit_id)),
+    };
+
+    for (path, oid, kind) in &files {
+        if is_set_on_path(path, &rules) {
+            continue;
+        }
+        let data = read(*oid)?;
+        sink.add(&format!("{}/{}", prefix, path), *kind, &data)
+            .with_context(|| format!("Failed to archive {}", path))?;
+    }
+
+    sink.finish()
+}
+
+/// Destination receiving archive entries.
+trait ArchiveSink {
+    /// Adds an entry with its content (the target for symlinks).
+    fn add(&mut self, path: &str, kind: EntryKind, data: &[u8]) -> Result<()>;
+
+    /// Writes trailing structures and flushes the output.
+    fn finish(self: Box<Self>) -> Result<()>;
+}
+
+/// Writes a ustar archive with pax headers for long names.
+struct TarSink<W: Write> {
+    out: W,
+    mtime: u64,
+}
+
+impl<W: Write> TarSink<W> {
+    /// Starts an archive with a pax global header recording the commit.
+    fn new(mut out: W, mtime: i64, commit_id: &str) -> Result<Self> {
+        let mtime = mtime.max(0) as u64;
+        let comment = pax_record("comment", commit_id);
+        out.write_all(&tar_header(
+            "pax_global_header",
+            0o666,
+            comment.len() as u64,
+            mtime,
+            b'g',
+            "",
+        ))?;
+        write_padded(&mut out, &comment)?;
+        Ok(Self { out, mtime })
+    }
+}
+
+impl<W: Write> ArchiveSink for TarSink<W> {
+    fn add(&mut self, path: &str, kind: EntryKind, data: &[u8]) -> Result<()> {
+        let (typeflag, size, link) = match kind {
+            EntryKind::Symlink => (b'2', 0, String::from_utf8_lossy(data).to_string()),
+            _ => (b'0', data.len() as u64, String::new()),
+        };
+        let mut extended = Vec::new();
+        if split_ustar_name(path).is_none() {
+            extended.extend(pax_record("path", path));
+        }
+        if size > TAR_MAX_SIZE {
+            extended.extend(pax_record("size", &size.to_string()));
+        }
+        if link.len() > 100 {
+            extended.extend(pax_record("linkpath", &link));
+        }
+        if !extended.is_empty() {
+            self.out.write_all(&tar_header(
+                "pax_header",
+                0o666,
+                extended.len() as u64,
+                self.mtime,
+                b'x',
+                "",
+            ))?;
+            write_padded(&mut self.out, &extended)?;
+        }
+
+        // Sizes beyond the octal field are only given by the pax record
+        let mode = kind.unix_mode() & 0o7777;
+        let header_size = if size > TAR_MAX_SIZE { 0 } else { size };
+        self.out.write_all(&tar_header(
+            path,
+            mode,
+            header_size,
+            self.mtime,
+            typeflag,
+            &link,
+        ))?;
+        if typeflag == b'0' {
+            write_padded(&mut self.out, data)?;
+        }
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<()> {
+        self.out.write_all(&[0u8; TAR_BLOCK * 2])?;
+        self.out.flush().context("Failed to write archive")
+    }
+}
+
+/// Builds a 512 byte ustar header.
+///
+/// Names that do not fit are truncated; callers precede such entries with
+/// a pax header carrying the full name.
+fn tar_header(
+    path: &str,
+    mode: u32,
+    size: u64,
+    mtime: u64,
+    typeflag: u8,
+    link: &str,
+) -> [u8; TAR_BLOCK] {
+    fn put(header: &mut [u8], offset: usize, len: usize, value: &[u8]) {
+        let n = value.len().min(len);
+        header[offset..offset + n].copy_from_slice(&value[..n]);
+    }
+    fn octal(header: &mut [u8], offset: usize, len: usize, value: u64) {
+        let digits = format!("{:0width$o}", value, width = len - 1);
+        put(header, offset, len - 1, digits.as_bytes());
+    }
+
+    let mut header = [0u8; TAR_BLOCK];
+    let (prefix, name) = split_ustar_name(path).unwrap_or(("", path));
+    put(&mut header, 0, 100, name.as_bytes());
+    octal(&mut header, 100, 8, mode as u64);
+    octal(&mut header, 108, 8, 0);
+    octal(&mut header, 116, 8, 0);
+    octal(&mut header, 124, 12, size);
+    octal(&mut header, 136, 12, mtime);
+    header[148..156].fill(b' ');
+    header[156] = typeflag;
+    put(&mut header, 157, 100, link.as_bytes());
+    put(&mut header, 257, 8, b"ustar\x0000");
+    put(&mut header, 265, 32, b"root");
+    put(&mut header, 297, 32, b"root");
+    put(&mut header, 345, 155, prefix.as_bytes());
+
+    let checksum: u32 = header.iter().map(|&b| b as u32).sum();
+    put(
+        &mut header,
+        148,
+        8,
+        format!("{:06o}\0 ", checksum).as_bytes(),
+    );
+    header
+}
+
+/// Splits a path into ustar prefix and name fields, if it fits.
+fn split_ustar_name(path: &str) -> Option<(&str, &str)> {
+    if path.len() <= 100 {
+        return Some(("", path));
+    }
+    path.match_indices('/')
+        .map(|(idx, _)| (&path[..idx], &path[idx + 1..]))
+        .find(|(prefix, name)| prefix.len() <= 155 && name.len() <= 100 && !name.is_empty())
+}
+
+/// Encodes a pax extended header record (`<len> <key>=<value>\n`).
+fn pax_record(key: &str, value: &str) -> Vec<u8> {
+    let body_len = key.len() + value.len() + 3;
+    let mut len = body_len + 1;
+    while len.to_string().len() + body_len != len {
+        len = len.to_string().len() + body_len;
+    }
+    format!("{} {}={}\n", len, key, value).into_bytes()
+}
+
+/// Writes data followed by zero padding up to the next tar block.
+fn write_padded(out: &mut impl Write, data: &[u8]) -> Result<()> {
+    out.write_all(data)?;
+    let padding = (TAR_BLOCK - data.len() % TAR_BLOCK) % TAR_BLOCK;
+    out.write_all(&vec![0u8; padding])?;
+    Ok(())
+}
+
+/// Central directory record kept until the archive is finished.
+struct ZipRecord {
+    name: Vec<u8>,
+    method: u16,
+    crc: u32,
+    compressed_size: u64,
+    size: u64,
+    offset: u64,
+    unix_mode: u32,
+}
+
+impl ZipRecord {
+    /// Zip64 extra field holding the values that overflow 32 bits.
+    ///
+    /// Values appear in the order the specification fixes (size,
+    /// compressed size, local header offset); local headers never carry
+    /// the offset. Empty when everything fits the regular fields.
+    fn zip64_extra(&self, with_offset: bool) -> Vec<u8> {
+        let mut values = Vec::new();
+        if self.size >= ZIP64_LIMIT || self.compressed_size >= ZIP64_LIMIT {
+            values.extend(self.size.to_le_bytes());
+            values.extend(self.compressed_size.to_le_bytes());
+        }
+        if with_offset && self.offset >= ZIP64_LIMIT {
+            values.extend(self.offset.to_le_bytes());
+        }
+        if values.is_empty() {
+            return values;
+        }
+
+        let mut extra = Vec::with_capacity(4 + values.len());
+        extra.extend(0x0001u16.to_le_bytes());
+        extra.extend((values.len() as u16).to_le_bytes());
+        extra.extend(values);
+        extra
+    }
+}
+
+/// Clamps a value to a 32 bit zip field, marking overflow with all ones.
+fn zip32(value: u64) -> u32 {
+    value.min(ZIP64_LIMIT) as u32
+}
+
+/// Writes a zip archive, deflating entries that benefit from it.
+///
+/// Archives with more than 65535 entries or beyond 4 GiB use the zip64
+/// extensions, like `git archive` does.
+struct ZipSink<W: Write> {
+    out: W,
+    offset: u64,
+    dos_time: u16,
+    dos_date: u16,
+    comment: String,
+    records: Vec<ZipRecord>,
+}
+
+impl<W: Write> ZipSink<W> {
+    /// Starts an archive whose comment records the commit.
+    fn new(out: W, mtime: i64, commit_id: &str) -> Self {
+        let (dos_time, dos_date) = dos_timestamp(mtime);
+        Self {
+            out,
+            offset: 0,
+            dos_time,
+            dos_date,
+            comment: commit_id.to_string(),
+            records: Vec::new(),
+        }
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> Result<()> {
+        self.out.write_all(bytes)?;
+        self.offset += bytes.len() as u64;
+        Ok(())
+    }
+}
+
+impl<W: Write> ArchiveSink for ZipSink<W> {
+    fn add(&mut self, path: &str, kind: EntryKind, data: &[u8]) -> Result<()> {
+        let mut crc = flate2::Crc::new();
+        crc.update(data);
+
+        // Empty files cannot shrink, so they skip the encoder setup
+        let deflated = if data.is_empty() {
+            Vec::new()
+        } else {
+            let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(data)?;
+            encoder.finish()?
+        };
+        let (method, stored) = if deflated.len() < data.len() {
+            (8u16, deflated.as_slice())
+        } else {
+            (0u16, data)
+        };
+
+        let record = ZipRecord {
+            name: path.as_bytes().to_vec(),
+            method,
+            crc: crc.sum(),
+            compressed_size: stored.len() as u64,
+            size: data.len() as u64,
+            offset: self.offset,
+            unix_mode: kind.unix_mode(),
+        };
+        let extra = record.zip64_extra(false);
+        let version: u16 = if extra.is_empty() { 20 } else { ZIP64_VERSION };
+
+        let mut header = Vec::with_capacity(30 + record.name.len() + extra.len());
+        header.extend(0x04034b50u32.to_le_bytes());
+        header.extend(version.to_le_bytes());
+        // Bit 11: file names are UTF-8
+        header.extend(0x0800u16.to_le_bytes());
+        header.extend(record.method.to_le_bytes());
+        header.extend(self.dos_time.to_le_bytes());
+        header.extend(self.dos_date.to_le_bytes());
+        header.extend(record.crc.to_le_bytes());
+        // Both sizes move to the extra field together
+        let sizes = if extra.is_empty() {
+            [record.compressed_size as u32, record.size as u32]
+        } else {
+            [u32::MAX; 2]
+        };
+        header.extend(sizes[0].to_le_bytes());
+        header.extend(sizes[1].to_le_bytes());
+        header.extend((record.name.len() as u16).to_le_bytes());
+        header.extend((extra.len() as u16).to_le_bytes());
+        header.extend(&record.name);
+        header.extend(&extra);
+
+        self.write(&header)?;
+        self.write(stored)?;
+        self.records.push(record);
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<()> {
+        let directory_offset = self.offset;
+        let mut directory = Vec::new();
+        for record in &self.records {
+            let extra = record.zip64_extra(true);
+            let version: u16 = if extra.is_empty() { 20 } else { ZIP64_VERSION };
+            let sizes = if record.size >= ZIP64_LIMIT || record.compressed_size >= ZIP64_LIMIT {
+                [u32::MAX; 2]
+            } else {
+                [record.compressed_size as u32, record.size as u32]
+            };
+
+            directory.extend(0x02014b50u32.to_le_bytes());
+            // Made by Unix (3) with the spec version the entry needs
+            directory.extend((0x0300 | version).to_le_bytes());
+            directory.extend(version.to_le_bytes());
+            directory.extend(0x0800u16.to_le_bytes());
+            directory.extend(record.method.to_le_bytes());
+            directory.extend(self.dos_time.to_le_bytes());
+            directory.extend(self.dos_date.to_le_bytes());
+            directory.extend(record.crc.to_le_bytes());
+            directory.extend(sizes[0].to_le_bytes());
+            directory.extend(sizes[1].to_le_bytes());
+            directory.extend((record.name.len() as u16).to_le_bytes());
+            directory.extend((extra.len() as u16).to_le_bytes());
+            directory.extend(0u16.to_le_bytes());
+            directory.extend(0u16.to_le_bytes());
+            directory.extend(0u16.to_le_bytes());
+            directory.extend((record.unix_mode << 16).to_le_bytes());
+            directory.extend(zip32(record.offset).to_le_bytes());
+            directory.extend(&record.name);
+            directory.extend(&extra);
+        }
+
+        let count = self.records.len();
+        let directory_size = directory.len() as u64;
+        self.write(&directory)?;
+
+        let mut end = Vec::new();
+        if count >= ZIP64_COUNT_LIMIT
+            || directory_size >= ZIP64_LIMIT
+            || directory_offset >= ZIP64_LIMIT
+        {
+            let zip64_end_offset = self.offset;
+            end.extend(0x06064b50u32.to_le_bytes());
+            // Size of the record after this field
+            end.extend(44u64.to_le_bytes());
+            end.extend((0x0300 | ZIP64_VERSION).to_le_bytes());
+            end.extend(ZIP64_VERSION.to_le_bytes());
+            end.extend(0u32.to_le_bytes());
+            end.extend(0u32.to_le_bytes());
+            end.extend((count as u64).to_le_bytes());
+            end.extend((count as u64).to_le_bytes());
+            end.extend(directory_size.to_le_bytes());
+            end.extend(directory_offset.to_le_bytes());
+
+            end.extend(0x07064b50u32.to_le_bytes());
+            end.extend(0u32.to_le_bytes());
+            end.extend(zip64_end_offset.to_le_bytes());
+            end.extend(1u32.to_le_bytes());
+        }
+
+        let count = count.min(ZIP64_COUNT_LIMIT) as u16;
+        end.extend(0x06054b50u32.to_le_bytes());
+        end.extend(0u16.to_le_bytes());
+        end.extend(0u16.to_le_bytes());
+        end.extend(count.to_le_bytes());
+        end.extend(count.to_le_bytes());
+        end.extend(zip32(directory_size).to_le_bytes());
+        end.extend(zip32(directory_offset).to_le_bytes());
+        end.extend((self.comment.len() as u16).to_le_bytes());
+        end.extend(self.comment.as_bytes());
+
+        self.write(&end)?;
+        self.out.flush().context("Failed to write archive")
+    }
+}
+
+/// Converts a Unix timestamp to MS-DOS time and date fields (UTC).
+///
+/// Dates before 1980, the earliest DOS date, are clamped.
+fn dos_timestamp(seconds: i64) -> (u16, u16) {
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+    if year < 1980 {
+        return (0, (1 << 5) | 1);
+    }
+    let secs = seconds.rem_euclid(86_400);
+    let time = ((secs / 3600) << 11) | (((secs % 3600) / 60) << 5) | ((secs % 60) / 2);
+    let date = ((year.min(2107) - 1980) << 9) | ((month as i64) << 5) | day as i64;
+    (time as u16, date as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::{DeflateDecoder, GzDecoder};
+    use std::io::Read;
+    use std::process::Command;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("Failed to run git");
+        assert!(output.status.success(), "git {:?} failed", args);
+    }
+
+    fn archive_repo() -> tempfile::TempDir {
+        let td = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let repo = td.path();
+        git(repo, &["init"]);
+        git(repo, &["config", "user.name", "Test"]);
+        git(repo, &["config", "user.email", "test@test.com"]);
+
+        let write = |path: &str, content: &str| {
+            let path = repo.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        };
+        write("README.md", "# Project\n");
+        write("src/main.rs", "fn main() {}\n");
+        write("tests/it.rs", "#[test]\nfn it() {}\n");
+        write("notes.draft", "draft\n");
+        write("docs/keep.md", "keep\n");
+        write("docs/skip.tmp", "skip\n");
+        write(
+            ".gitattributes",
+            "tests export-ignore\n*.draft export-ignore\n.gitattributes export-ignore\n",
+        );
+        write("docs/.gitattributes", "*.tmp export-ignore\n");
+        write(&format!("{}/long.txt", "deep/".repeat(30)), "long\n");
+        std::os::unix::fs::symlink("README.md", repo.join("link")).unwrap();
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-m", "Initial commit"]);
+        git(repo, &["tag", "v1.0.0"]);
+        td
+    }
+
+    /// Reads (name, typeflag, content) entries from an uncompressed tarball.
+    fn read_tar(data: &[u8]) -> Vec<(String, u8, Vec<u8>)> {
+        let mut entries = Vec::new();
+        let mut pos = 0;
+        let mut long_name = None;
+        while pos + TAR_BLOCK <= data.len() && data[pos] != 0 {
+            let header = &data[pos..pos + TAR_BLOCK];
+            let field = |range: std::ops::Range<usize>| {
+                let bytes = &header[range];
+                let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+                String::from_utf8_lossy(&bytes[..end]).to_string()
+            };
+            let size = u64::from_str_radix(field(124..135).trim(), 8).unwrap() as usize;
+            let typeflag = header[156];
+            let body = data[pos + TAR_BLOCK..pos + TAR_BLOCK + size].to_vec();
+            pos += TAR_BLOCK + size.div_ceil(TAR_BLOCK) * TAR_BLOCK;
+
+            match typeflag {
+                b'x' => {
+                    let text = String::from_utf8(body).unwrap();
+                    long_name = text
+                        .split_once(" path=")
+                        .map(|(_, rest)| rest.trim_end().to_string());
+                }
+                b'g' => entries.push((field(0..100), typeflag, body)),
+                _ => {
+                    let prefix = field(345..500);
+                    let name = long_name.take().unwrap_or_else(|| {
+                        if prefix.is_empty() {
+                            field(0..100)
+                        } else {
+                            format!("{}/{}", prefix, field(0..100))
+                        }
+                    });
+                    let content = if typeflag == b'2' {
+                        field(157..257).into_bytes()
+                    } else {
+                        body
+                    };
+                    entries.push((name, typeflag, content));
+                }
+            }
+        }
+        entries
+    }
+
+    /// Reads (name, content) entries from a zip archive via its central directory.
+    fn read_zip(data: &[u8]) -> (Vec<(String, Vec<u8>)>, String) {
+        let u16_at = |pos: usize| u16::from_le_bytes([data[pos], data[pos + 1]]) as usize;
+        let u32_at = |pos: usize| {
+            u32::from_le_bytes([data[pos], data[pos + 1], data[pos + 2], data[pos + 3]]) as usize
+        };
+
+        let end = (0..data.len() - 21)
+            .rev()
+            .find(|&pos| u32_at(pos) == 0x06054b50)
+            .expect("Should have end record");
+        let count = u16_at(end + 10);
+        let comment =
+            String::from_utf8(data[end + 22..end + 22 + u16_at(end + 20)].to_vec()).unwrap();
+
+        let mut pos = u32_at(end + 16);
+        let mut entries = Vec::new();
+        for _ in 0..count {
+            assert_eq!(u32_at(pos), 0x02014b50);
+            let method = u16_at(pos + 10);
+            let crc = u32_at(pos + 16) as u32;
+            let compressed = u32_at(pos + 20);
+            let name_len = u16_at(pos + 28);
+            let local = u32_at(pos + 42);
+            let name = String::from_utf8(data[pos + 46..pos + 46 + name_len].to_vec()).unwrap();
+
+            let start = local + 30 + u16_at(local + 26) + u16_at(local + 28);
+            let raw = &data[start..start + compressed];
+            let content = if method == 8 {
+                let mut out = Vec::new();
+                DeflateDecoder::new(raw).read_to_end(&mut out).unwrap();
+                out
+            } else {
+                raw.to_vec()
+            };
+            let mut check = flate2::Crc::new();
+            check.update(&content);
+            assert_eq!(check.sum(), crc, "CRC mismatch for {}", name);
+
+            entries.push((name, content));
+            pos += 46 + name_len + u16_at(pos + 30) + u16_at(pos + 32);
+        }
+        (entries, comment)
+    }
+
+    #[test]
+    fn test_archive_name() {
+        assert_eq!(archive_name("gitkyl", "v1.0.0"), "gitkyl-v1.0.0");
+        assert_eq!(archive_name("repo.git", "feature/x"), "repo-feature-x");
+    }
+
+    #[test]
+    fn test_tar_gz_archive_contents() {
+        // Arrange
+        let td = archive_repo();
+        let mut output = Vec::new();
+
+        // Act
+        write_archive(
+            td.path(),
+            "v1.0.0",
+            ArchiveFormat::TarGz,
+            "proj-v1.0.0",
+            &mut output,
+        )
+        .expect("Should write archive");
+
+        // Assert
+        let mut tar = Vec::new();
+        GzDecoder::new(output.as_slice())
+            .read_to_end(&mut tar)
+            .expect("Should be valid gzip");
+        let entries = read_tar(&tar);
+
+        let (global, flag, comment) = &entries[0];
+        assert_eq!((global.as_str(), *flag), ("pax_global_header", b'g'));
+        assert!(String::from_utf8_lossy(comment).starts_with("52 comment="));
+
+        let names: Vec<&str> = entries[1..].iter().map(|(n, _, _)| n.as_str()).collect();
+        let long_path = format!("proj-v1.0.0/{}long.txt", "deep/".repeat(30));
+        assert_eq!(
+            names,
+            vec![
+                "proj-v1.0.0/README.md",
+                long_path.as_str(),
+                "proj-v1.0.0/docs/keep.md",
+                "proj-v1.0.0/link",
+                "proj-v1.0.0/src/main.rs",
+            ]
+        );
+
+        let link = entries
+            .iter()
+            .find(|(n, _, _)| n.ends_with("/link"))
+            .unwrap();
+        assert_eq!((link.1, link.2.as_slice()), (b'2', b"README.md".as_slice()));
+        let main = entries
+            .iter()
+            .find(|(n, _, _)| n.ends_with("main.rs"))
+            .unwrap();
+        assert_eq!(main.2, b"fn main() {}\n");
+    }
+
+    #[test]
+    fn test_zip_archive_contents() {
+        // Arrange
+        let td = archive_repo();
+        let mut output = Vec::new();
+
+        // Act
+        write_archive(td.path(), "v1.0.0", ArchiveFormat::Zip, "proj", &mut output)
+            .expect("Should write archive");
+
+        // Assert
+        let (entries, comment) = read_zip(&output);
+        let names: Vec<&str> = entries.iter().map(|(n, _)| n.as_str()).collect();
+        assert!(names.contains(&"proj/README.md"));
+        assert!(names.contains(&"proj/docs/keep.md"));
+        assert!(!names.iter().any(|n| n.contains("tests/")));
+        assert!(
+            !names
+                .iter()
+                .any(|n| n.ends_with(".draft") || n.ends_with(".tmp"))
+        );
+        assert_eq!(comment.len(), 40, "Comment should hold the commit hash");
+
+        let readme = entries.iter().find(|(n, _)| n == "proj/README.md").unwrap();
+        assert_eq!(readme.1, b"# Project\n");
+    }
+
+    #[test]
+    fn test_export_rules() {
+        let mut rules = parse_rules(
+            "",
+            "/build/ export-ignore\nvendor/** export-ignore\n",
+            EXPORT_IGNORE,
+        );
+        rules.extend(parse_rules(
+            "lib/",
+            "*.log export-ignore\nkeep.log -export-ignore\n",
+            EXPORT_IGNORE,
+        ));
+
+        assert!(is_set_on_path("build/out.o", &rules));
+        assert!(!is_set_on_path("src/build", &rules), "Dir-only pattern");
+        assert!(is_set_on_path("vendor/a/b.rs", &rules));
+        assert!(is_set_on_path("lib/x/debug.log", &rules));
+        assert!(!is_set_on_path("lib/keep.log", &rules), "Later rule unsets");
+        assert!(!is_set_on_path("debug.log", &rules), "Rule scoped to lib/");
+    }
+
+    #[test]
+    fn test_pax_record_length() {
+        let record = pax_record("comment", &"a".repeat(40));
+        assert_eq!(record.len(), 52);
+        assert!(record.starts_with(b"52 comment="));
+
+        let record = pax_record("path", &"p".repeat(93));
+        let len: usize = String::from_utf8_lossy(&record)
+            .split(' ')
+            .next()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(len, record.len(), "Length prefix includes its own digits");
+    }
+
+    #[test]
+    fn test_dos_timestamp() {
+        // 2024-02-29 13:45:30 UTC
+        let (time, date) = dos_timestamp(1_709_214_330);
+        assert_eq!(date, ((2024 - 1980) << 9) | (2 << 5) | 29);
+        assert_eq!(time, (13 << 11) | (45 << 5) | 15);
+        assert_eq!(dos_timestamp(0), (0, (1 << 5) | 1));
+    }
+
+    #[test]
+    fn test_zip64_end_record_for_many_entries() {
+        // Arrange
+        let mut output = Vec::new();
+        let mut sink = ZipSink::new(&mut output, 0, "commit");
+
+        // Act
+        for idx in 0..=ZIP64_COUNT_LIMIT {
+            sink.add(&format!("f{}", idx), EntryKind::File, b"")
+                .expect("Should add entry");
+        }
+        Box::new(sink).finish().expect("Should finish archive");
+
+        // Assert
+        let end = output.len() - 22 - "commit".len();
+        let locator = end - 20;
+        let record = locator - 56;
+        assert_eq!(&output[end..end + 4], &0x06054b50u32.to_le_bytes());
+        assert_eq!(&output[end + 10..end + 12], &[0xff, 0xff]);
+        assert_eq!(&output[locator..locator + 4], &0x07064b50u32.to_le_bytes());
+        assert_eq!(&output[record..record + 4], &0x06064b50u32.to_le_bytes());
+        let total = u64::from_le_bytes(output[record + 32..record + 40].try_into().unwrap());
+        assert_eq!(total, ZIP64_COUNT_LIMIT as u64 + 1);
+    }
+
+    #[test]
+    fn test_zip64_extra_field_for_large_offset() {
+        // Arrange: pretend 4 GiB were already written
+        let mut output = Vec::new();
+        let mut sink = ZipSink::new(&mut output, 0, "");
+        sink.offset = ZIP64_LIMIT + 1;
+
+        // Act
+        sink.add("big", EntryKind::File, b"data")
+            .expect("Should add entry");
+        Box::new(sink).finish().expect("Should finish archive");
+
+        // Assert
+        let local_len = 30 + "big".len() + 4;
+        let central = &output[local_len..];
+        assert_eq!(&central[..4], &0x02014b50u32.to_le_bytes());
+        assert_eq!(u16::from_le_bytes([central[6], central[7]]), ZIP64_VERSION);
+        assert_eq!(&central[42..46], &[0xff; 4]);
+        assert_eq!(u16::from_le_bytes([central[30], central[31]]), 12);
+        let extra = &central[46 + 3..46 + 3 + 12];
+        assert_eq!(&extra[..4], &[0x01, 0x00, 0x08, 0x00]);
+        assert_eq!(
+            u64::from_le_bytes(extra[4..12].try_into().unwrap()),
+            ZIP64_LIMIT + 1
+        );
+    }
+}
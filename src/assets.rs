@@ -7,12 +7,19 @@ const BASE: &str = include_str!("../assets/base.css");
 const LAYOUT: &str = include_str!("../assets/components/layout.css");
 const NAV: &str = include_str!("../assets/components/nav.css");
 const FILE_LIST: &str = include_str!("../assets/components/file-list.css");
+const ACTIVITY: &str = include_str!("../assets/components/activity.css");
+const LANGUAGES: &str = include_str!("../assets/components/languages.css");
 
 const INDEX_PAGE: &str = include_str!("../assets/page-index.css");
 const TREE_PAGE: &str = include_str!("../assets/page-tree.css");
 const BLOB_PAGE: &str = include_str!("../assets/page-blob.css");
+const BLAME_PAGE: &str = include_str!("../assets/page-blame.css");
 const COMMITS_PAGE: &str = include_str!("../assets/page-commits.css");
+const COMMIT_PAGE: &str = include_str!("../assets/page-commit.css");
 const TAGS_PAGE: &str = include_str!("../assets/page-tags.css");
+const CONTRIBUTORS_PAGE: &str = include_str!("../assets/page-contributors.css");
+const ACTIVITY_PAGE: &str = include_str!("../assets/page-activity.css");
+const REPOS_PAGE: &str = include_str!("../assets/page-repos.css");
 const MARKDOWN: &str = include_str!("../assets/markdown.css");
 
 /// Writes all bundled CSS assets to output directory
@@ -20,7 +27,7 @@ pub fn write_css_assets(assets_dir: &Path) -> Result<()> {
     write_bundled(
         assets_dir,
         "index.css",
-        &[BASE, LAYOUT, FILE_LIST, INDEX_PAGE],
+        &[BASE, LAYOUT, FILE_LIST, ACTIVITY, LANGUAGES, INDEX_PAGE],
     )?;
     write_bundled(
         assets_dir,
@@ -28,12 +35,39 @@ pub fn write_css_assets(assets_dir: &Path) -> Result<()> {
         &[BASE, LAYOUT, NAV, FILE_LIST, TREE_PAGE],
     )?;
     write_bundled(assets_dir, "blob.css", &[BASE, LAYOUT, NAV, BLOB_PAGE])?;
+    write_bundled(
+        assets_dir,
+        "blame.css",
+        &[BASE, LAYOUT, NAV, BLOB_PAGE, BLAME_PAGE],
+    )?;
     write_bundled(
         assets_dir,
         "commits.css",
         &[BASE, LAYOUT, NAV, COMMITS_PAGE],
     )?;
+    write_bundled(assets_dir, "commit.css", &[BASE, LAYOUT, NAV, COMMIT_PAGE])?;
     write_bundled(assets_dir, "tags.css", &[BASE, LAYOUT, NAV, TAGS_PAGE])?;
+    write_bundled(
+        assets_dir,
+        "contributors.css",
+        &[BASE, LAYOUT, NAV, TAGS_PAGE, CONTRIBUTORS_PAGE],
+    )?;
+    write_bundled(
+        assets_dir,
+        "activity.css",
+        &[BASE, LAYOUT, NAV, TAGS_PAGE, ACTIVITY, ACTIVITY_PAGE],
+    )?;
+    write_bundled(
+        assets_dir,
+        "languages.css",
+        &[BASE, LAYOUT, NAV, TAGS_PAGE, LANGUAGES],
+    )?;
+    write_bundled(
+        assets_dir,
+        "author.css",
+        &[BASE, LAYOUT, NAV, COMMITS_PAGE, CONTRIBUTORS_PAGE],
+    )?;
+    write_bundled(assets_dir, "repos.css", &[BASE, LAYOUT, REPOS_PAGE])?;
     write_bundled(assets_dir, "markdown.css", &[MARKDOWN])?;
     Ok(())
 }
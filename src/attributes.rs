@@ -0,0 +1,230 @@
+//! `.gitattributes` rules.
+//!
+//! Parses the rules assigning one attribute and evaluates them the way git
+//! does: patterns are relative to the directory of their attributes file,
+//! patterns without a slash match the file name at any depth, patterns
+//! ending in a slash only match directories, and the last matching rule
+//! wins, with deeper attribute files taking precedence.
+
+use crate::glob::glob_match;
+
+/// Name of the files holding attribute rules.
+const ATTRIBUTES_FILE: &str = ".gitattributes";
+
+/// Attribute state assigned by a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum AttributeState {
+    /// Attribute named alone (`export-ignore`)
+    Set,
+    /// Attribute prefixed with `-` or `!` (`-export-ignore`)
+    Unset,
+    /// Attribute assigned a value (`linguist-language=Rust`)
+    Value(String),
+}
+
+impl AttributeState {
+    /// Returns true if the attribute is set, or holds a value other than
+    /// `false`.
+    pub(crate) fn is_set(&self) -> bool {
+        match self {
+            Self::Set => true,
+            Self::Unset => false,
+            Self::Value(value) => value != "false",
+        }
+    }
+}
+
+/// Rule assigning one attribute to matching paths.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct AttributeRule {
+    /// Directory of the attributes file, empty or ending in `/`
+    base: String,
+    /// Pattern relative to `base`, without leading or trailing slash
+    pattern: String,
+    /// Pattern contains a slash and matches the full relative path
+    anchored: bool,
+    /// Pattern ended with a slash and only matches directories
+    dir_only: bool,
+    /// State assigned to matching paths
+    state: AttributeState,
+}
+
+/// Directory of an attributes file, empty or ending in `/`.
+///
+/// # Returns
+///
+/// None if the path does not name a `.gitattributes` file
+pub(crate) fn attributes_base(path: &str) -> Option<&str> {
+    if path == ATTRIBUTES_FILE {
+        Some("")
+    } else {
+        path.strip_suffix(ATTRIBUTES_FILE)
+            .filter(|base| base.ends_with('/'))
+    }
+}
+
+/// Parses the rules for one attribute from a `.gitattributes` file.
+///
+/// Lines without the attribute, comments and negated patterns (which
+/// gitattributes does not allow) are skipped.
+///
+/// # Arguments
+///
+/// * `base`: Directory of the attributes file, see `attributes_base`
+/// * `content`: File content
+/// * `attribute`: Attribute name (e.g. "export-ignore")
+pub(crate) fn parse_rules(base: &str, content: &str, attribute: &str) -> Vec<AttributeRule> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let pattern = fields.next()?;
+            if pattern.starts_with('#') || pattern.starts_with('!') {
+                return None;
+            }
+
+            let state = fields.fold(None, |state, field| {
+                if field == attribute {
+                    Some(AttributeState::Set)
+                } else if field.strip_prefix(['-', '!']) == Some(attribute) {
+                    Some(AttributeState::Unset)
+                } else if let Some(value) = field
+                    .strip_prefix(attribute)
+                    .and_then(|rest| rest.strip_prefix('='))
+                {
+                    Some(AttributeState::Value(value.to_string()))
+                } else {
+                    state
+                }
+            })?;
+
+            let dir_only = pattern.ends_with('/');
+            let trimmed = pattern.trim_end_matches('/');
+            let anchored = trimmed.contains('/');
+
+            Some(AttributeRule {
+                base: base.to_string(),
+                pattern: trimmed.trim_start_matches('/').to_string(),
+                anchored,
+                dir_only,
+                state,
+            })
+        })
+        .collect()
+}
+
+/// Orders rules so those of deeper attribute files take precedence.
+pub(crate) fn sort_rules(rules: &mut [AttributeRule]) {
+    rules.sort_by_key(|rule| rule.base.matches('/').count());
+}
+
+/// Evaluates an attribute for one path; the last matching rule wins.
+///
+/// # Arguments
+///
+/// * `path`: Path relative to the repository root
+/// * `is_dir`: Whether the path names a directory
+/// * `rules`: Rules ordered by `sort_rules`
+pub(crate) fn attribute_state<'a>(
+    path: &str,
+    is_dir: bool,
+    rules: &'a [AttributeRule],
+) -> Option<&'a AttributeState> {
+    rules
+        .iter()
+        .rev()
+        .find(|rule| {
+            if rule.dir_only && !is_dir {
+                return false;
+            }
+            let Some(relative) = path.strip_prefix(rule.base.as_str()) else {
+                return false;
+            };
+            if rule.anchored {
+                glob_match(&rule.pattern, relative)
+            } else {
+                let name = relative.rsplit('/').next().unwrap_or(relative);
+                glob_match(&rule.pattern, name)
+            }
+        })
+        .map(|rule| &rule.state)
+}
+
+/// Checks whether an attribute is set on a file.
+pub(crate) fn is_set(path: &str, rules: &[AttributeRule]) -> bool {
+    attribute_state(path, false, rules).is_some_and(AttributeState::is_set)
+}
+
+/// Checks whether an attribute is set on a file or any of its parent
+/// directories, as `git archive` does for `export-ignore`.
+pub(crate) fn is_set_on_path(path: &str, rules: &[AttributeRule]) -> bool {
+    let is_set = |path: &str, is_dir: bool| {
+        attribute_state(path, is_dir, rules).is_some_and(AttributeState::is_set)
+    };
+    let mut dirs = path.match_indices('/').map(|(idx, _)| &path[..idx]);
+    dirs.any(|dir| is_set(dir, true)) || is_set(path, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attributes_base() {
+        assert_eq!(attributes_base(".gitattributes"), Some(""));
+        assert_eq!(attributes_base("docs/.gitattributes"), Some("docs/"));
+        assert_eq!(attributes_base("docs/x.gitattributes"), None);
+        assert_eq!(attributes_base("README.md"), None);
+    }
+
+    #[test]
+    fn test_parse_rules_reads_states() {
+        // Arrange
+        let content = "# comment\n*.h linguist-language=C++ text\nvendor/** linguist-vendored\n\
+                       vendor/own/** -linguist-vendored\n*.rs diff\n";
+
+        // Act
+        let language = parse_rules("", content, "linguist-language");
+        let vendored = parse_rules("", content, "linguist-vendored");
+
+        // Assert
+        assert_eq!(language.len(), 1);
+        assert_eq!(
+            attribute_state("src/a.h", false, &language),
+            Some(&AttributeState::Value("C++".to_string()))
+        );
+        assert_eq!(vendored.len(), 2);
+        assert!(is_set("vendor/lib/a.c", &vendored));
+        assert!(!is_set("vendor/own/a.c", &vendored));
+        assert!(!is_set("src/a.c", &vendored));
+    }
+
+    #[test]
+    fn test_deeper_files_take_precedence() {
+        // Arrange
+        let mut rules = parse_rules("lib/", "*.js -linguist-vendored\n", "linguist-vendored");
+        rules.extend(parse_rules(
+            "",
+            "*.js linguist-vendored\n",
+            "linguist-vendored",
+        ));
+
+        // Act
+        sort_rules(&mut rules);
+
+        // Assert
+        assert!(is_set("app.js", &rules));
+        assert!(!is_set("lib/app.js", &rules));
+    }
+
+    #[test]
+    fn test_false_value_is_unset() {
+        let rules = parse_rules(
+            "",
+            "*.min.js linguist-vendored=false\n",
+            "linguist-vendored",
+        );
+
+        assert!(!is_set("app.min.js", &rules));
+    }
+}
@@ -0,0 +1,463 @@
+//! Line attribution (blame) for files in repository history.
+
+use anyhow::{Context, Result};
+use gix::bstr::ByteSlice;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::path::Path;
+
+use crate::diff::{Edit, diff_lines};
+use crate::git::{CommitInfo, CommitOrder, extract_commit_info};
+use crate::mailmap::Mailmap;
+
+/// Consecutive lines last changed by the same commit.
+#[derive(Debug, Clone)]
+pub struct BlameHunk {
+    /// Commit that introduced the lines
+    pub commit: CommitInfo,
+    /// First line of the hunk in the current file (1-indexed)
+    pub start_line: usize,
+    /// Number of lines in the hunk
+    pub line_count: usize,
+}
+
+/// Lines still looking for their origin at a given commit.
+struct Pending {
+    /// Blob holding the file content at this commit
+    blob: gix::ObjectId,
+    /// Pairs of (line in final file, line in this commit's version)
+    lines: Vec<(usize, usize)>,
+}
+
+/// Attributes every line of a file to the commit that last changed it.
+///
+/// Follows the ref's history in the given topological order, visiting
+/// commits children first. At each commit the file is diffed against its
+/// parents: lines unchanged in a parent are passed on to that parent, and
+/// lines no parent explains are attributed to the commit itself. When a
+/// parent holds an identical blob all lines pass through without diffing.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `order`: History of the ref, shared by all files of the ref
+/// * `file_path`: Path to file within repository tree
+/// * `mailmap`: Mailmap applied to commit identities
+///
+/// # Returns
+///
+/// Hunks covering every line of the file in order
+///
+/// # Errors
+///
+/// Returns error if:
+/// - Repository cannot be opened
+/// - Order holds no commits
+/// - File does not exist or is not a blob
+/// - Commit history cannot be traversed
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::{CommitOrder, Mailmap, blame_file};
+/// use std::path::Path;
+///
+/// let mailmap = Mailmap::default();
+/// let order = CommitOrder::load(Path::new("."), Some("main"))?;
+/// let hunks = blame_file(Path::new("."), &order, Path::new("src/lib.rs"), &mailmap)?;
+/// for hunk in hunks {
+///     println!("{}: {} lines", hunk.commit.short_oid(), hunk.line_count);
+/// }
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn blame_file(
+    repo_path: impl AsRef<Path>,
+    order: &CommitOrder,
+    file_path: impl AsRef<Path>,
+    mailmap: &Mailmap,
+) -> Result<Vec<BlameHunk>> {
+    let repo = gix::open(repo_path.as_ref()).with_context(|| {
+        format!(
+            "Failed to open repository at {}",
+            repo_path.as_ref().display()
+        )
+    })?;
+
+    let file_path = file_path.as_ref();
+    let tip = order
+        .tip()
+        .ok_or_else(|| anyhow::anyhow!("No commits to blame {}", file_path.display()))?;
+    let tip = repo
+        .find_object(tip)
+        .context("Failed to find commit object")?
+        .try_into_commit()
+        .map_err(|_| anyhow::anyhow!("Object is not a commit"))?;
+    let tip_blob = blob_at(&tip, file_path)?
+        .ok_or_else(|| anyhow::anyhow!("File not found in tree: {}", file_path.display()))?;
+
+    let mut contents: HashMap<gix::ObjectId, String> = HashMap::new();
+    let total_lines = load(&repo, &mut contents, tip_blob)?.lines().count();
+    if total_lines == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut pending: HashMap<gix::ObjectId, Pending> = HashMap::new();
+    pending.insert(
+        tip.id,
+        Pending {
+            blob: tip_blob,
+            lines: (0..total_lines).map(|idx| (idx, idx)).collect(),
+        },
+    );
+
+    let mut origins: Vec<Option<gix::ObjectId>> = vec![None; total_lines];
+    let mut remaining = total_lines;
+
+    for (commit_id, parent_ids) in order.commits() {
+        if remaining == 0 {
+            break;
+        }
+        let Some(Pending { blob, mut lines }) = pending.remove(commit_id) else {
+            continue;
+        };
+
+        for &parent_id in parent_ids {
+            if lines.is_empty() {
+                break;
+            }
+
+            let parent = repo
+                .find_object(parent_id)
+                .context("Failed to find parent object")?
+                .try_into_commit()
+                .map_err(|_| anyhow::anyhow!("Parent object is not a commit"))?;
+            let Some(parent_blob) = blob_at(&parent, file_path)? else {
+                continue;
+            };
+
+            if parent_blob == blob {
+                // Unchanged in this parent: everything passes through
+                pending
+                    .entry(parent_id)
+                    .or_insert_with(|| Pending {
+                        blob: parent_blob,
+                        lines: Vec::new(),
+                    })
+                    .lines
+                    .append(&mut lines);
+                break;
+            }
+
+            let mapping = line_mapping(&repo, &mut contents, parent_blob, blob)?;
+            let (passed, kept): (Vec<_>, Vec<_>) = lines
+                .into_iter()
+                .partition(|(_, current)| mapping[*current].is_some());
+            lines = kept;
+
+            if !passed.is_empty() {
+                let entry = pending.entry(parent_id).or_insert_with(|| Pending {
+                    blob: parent_blob,
+                    lines: Vec::new(),
+                });
+                entry.lines.extend(
+                    passed
+                        .into_iter()
+                        .filter_map(|(line, current)| Some((line, mapping[current]?))),
+                );
+            }
+        }
+
+        // Lines no parent accounts for were introduced by this commit
+        for (line, _) in lines {
+            origins[line] = Some(*commit_id);
+            remaining -= 1;
+        }
+    }
+
+    let mut commits: HashMap<gix::ObjectId, CommitInfo> = HashMap::new();
+    let mut hunks: Vec<BlameHunk> = Vec::new();
+    let mut previous = None;
+
+    for (idx, origin) in origins.into_iter().enumerate() {
+        let origin =
+            origin.ok_or_else(|| anyhow::anyhow!("Failed to attribute line {}", idx + 1))?;
+
+        if previous == Some(origin)
+            && let Some(last) = hunks.last_mut()
+        {
+            last.line_count += 1;
+            continue;
+        }
+        previous = Some(origin);
+
+        let commit = match commits.get(&origin) {
+            Some(info) => info.clone(),
+            None => {
+                let object = repo
+                    .find_object(origin)
+                    .context("Failed to find commit object")?
+                    .try_into_commit()
+                    .map_err(|_| anyhow::anyhow!("Object is not a commit"))?;
+                let info = extract_commit_info(&object, mailmap)?;
+                commits.insert(origin, info.clone());
+                info
+            }
+        };
+
+        hunks.push(BlameHunk {
+            commit,
+            start_line: idx + 1,
+            line_count: 1,
+        });
+    }
+
+    Ok(hunks)
+}
+
+/// Looks up the blob at a path in a commit tree.
+///
+/// Returns None when the path is missing or is not a regular file.
+fn blob_at(commit: &gix::Commit<'_>, path: &Path) -> Result<Option<gix::ObjectId>> {
+    let mut tree = commit.tree().context("Failed to read commit tree")?;
+    let entry = tree
+        .peel_to_entry_by_path(path)
+        .context("Failed to traverse tree to path")?;
+
+    Ok(entry
+        .filter(|e| e.mode().is_blob_or_symlink())
+        .map(|e| e.object_id()))
+}
+
+/// Reads blob content once and caches it by object ID.
+fn load<'c>(
+    repo: &gix::Repository,
+    cache: &'c mut HashMap<gix::ObjectId, String>,
+    oid: gix::ObjectId,
+) -> Result<&'c str> {
+    let content = match cache.entry(oid) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        Entry::Vacant(entry) => {
+            let blob = repo
+                .find_object(oid)
+                .with_context(|| format!("Failed to find blob {}", oid))?;
+            entry.insert(blob.data.to_str_lossy().into_owned())
+        }
+    };
+    Ok(content.as_str())
+}
+
+/// Maps each line of `current` to its unchanged counterpart in `parent`.
+fn line_mapping(
+    repo: &gix::Repository,
+    cache: &mut HashMap<gix::ObjectId, String>,
+    parent: gix::ObjectId,
+    current: gix::ObjectId,
+) -> Result<Vec<Option<usize>>> {
+    load(repo, cache, parent)?;
+    load(repo, cache, current)?;
+
+    let parent_lines: Vec<&str> = cache[&parent].lines().collect();
+    let current_lines: Vec<&str> = cache[&current].lines().collect();
+
+    let mut mapping = vec![None; current_lines.len()];
+    for edit in diff_lines(&parent_lines, &current_lines) {
+        if let Edit::Equal { old, new } = edit {
+            mapping[new] = Some(old);
+        }
+    }
+
+    Ok(mapping)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::process::Command;
+
+    fn temp_repo() -> tempfile::TempDir {
+        let td = tempfile::TempDir::with_prefix("gitkyl-blame-").unwrap();
+        for args in [
+            vec!["init"],
+            vec!["config", "user.name", "Test User"],
+            vec!["config", "user.email", "test@example.com"],
+        ] {
+            Command::new("git")
+                .args(&args)
+                .current_dir(td.path())
+                .output()
+                .unwrap();
+        }
+        td
+    }
+
+    fn commit_file(repo: &Path, path: &str, content: &str, message: &str, author: &str) -> String {
+        std::fs::write(repo.join(path), content).unwrap();
+        Command::new("git")
+            .args(["add", "."])
+            .current_dir(repo)
+            .output()
+            .unwrap();
+        let output = Command::new("git")
+            .args([
+                "commit",
+                "-m",
+                message,
+                "--author",
+                &format!("{} <{}@example.com>", author, author.to_lowercase()),
+            ])
+            .current_dir(repo)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let head = Command::new("git")
+            .args(["rev-parse", "HEAD"])
+            .current_dir(repo)
+            .output()
+            .unwrap();
+        String::from_utf8(head.stdout).unwrap().trim().to_string()
+    }
+
+    fn order(repo: &Path) -> CommitOrder {
+        CommitOrder::load(repo, None).expect("Should walk history")
+    }
+
+    fn git(repo: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(repo)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {:?} failed", args);
+    }
+
+    #[test]
+    fn test_blame_single_commit() {
+        // Arrange
+        let td = temp_repo();
+        let oid = commit_file(td.path(), "a.txt", "one\ntwo\nthree\n", "Add a", "Alice");
+
+        // Act
+        let hunks = blame_file(td.path(), &order(td.path()), "a.txt", &Mailmap::default())
+            .expect("Should blame file");
+
+        // Assert
+        assert_eq!(hunks.len(), 1);
+        assert_eq!(hunks[0].commit.oid(), oid);
+        assert_eq!(hunks[0].start_line, 1);
+        assert_eq!(hunks[0].line_count, 3);
+    }
+
+    #[test]
+    fn test_blame_attributes_modified_lines() {
+        // Arrange
+        let td = temp_repo();
+        let first = commit_file(td.path(), "a.txt", "one\ntwo\nthree\n", "Add a", "Alice");
+        commit_file(td.path(), "b.txt", "unrelated\n", "Add b", "Carol");
+        let second = commit_file(
+            td.path(),
+            "a.txt",
+            "one\nTWO\nthree\nfour\n",
+            "Edit a",
+            "Bob",
+        );
+
+        // Act
+        let hunks = blame_file(td.path(), &order(td.path()), "a.txt", &Mailmap::default())
+            .expect("Should blame file");
+
+        // Assert
+        let owners: Vec<(&str, usize, usize)> = hunks
+            .iter()
+            .map(|h| (h.commit.oid(), h.start_line, h.line_count))
+            .collect();
+        assert_eq!(
+            owners,
+            vec![
+                (first.as_str(), 1, 1),
+                (second.as_str(), 2, 1),
+                (first.as_str(), 3, 1),
+                (second.as_str(), 4, 1),
+            ]
+        );
+        assert_eq!(hunks[1].commit.author(), "Bob");
+    }
+
+    #[test]
+    fn test_blame_through_merge() {
+        // Arrange: line edited on a side branch and merged back
+        let td = temp_repo();
+        let base = commit_file(td.path(), "a.txt", "one\ntwo\n", "Base", "Alice");
+        git(td.path(), &["checkout", "-b", "side"]);
+        let side = commit_file(td.path(), "a.txt", "one\nside two\n", "Side edit", "Bob");
+        git(td.path(), &["checkout", "-"]);
+        commit_file(td.path(), "other.txt", "x\n", "Main edit", "Carol");
+        git(td.path(), &["merge", "--no-ff", "side", "-m", "Merge side"]);
+
+        // Act
+        let hunks = blame_file(td.path(), &order(td.path()), "a.txt", &Mailmap::default())
+            .expect("Should blame file");
+
+        // Assert: merge commit itself owns no lines
+        assert_eq!(hunks.len(), 2);
+        assert_eq!(hunks[0].commit.oid(), base);
+        assert_eq!(hunks[1].commit.oid(), side);
+    }
+
+    #[test]
+    fn test_blame_empty_file() {
+        // Arrange
+        let td = temp_repo();
+        commit_file(td.path(), "empty.txt", "", "Add empty", "Alice");
+
+        // Act
+        let hunks = blame_file(
+            td.path(),
+            &order(td.path()),
+            "empty.txt",
+            &Mailmap::default(),
+        )
+        .expect("Should blame file");
+
+        // Assert
+        assert!(hunks.is_empty());
+    }
+
+    #[test]
+    fn test_blame_missing_file() {
+        // Arrange
+        let repo_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+        // Act
+        let result = blame_file(
+            &repo_path,
+            &order(&repo_path),
+            "does/not/exist.rs",
+            &Mailmap::default(),
+        );
+
+        // Assert
+        assert!(result.is_err(), "Missing file should fail");
+    }
+
+    #[test]
+    fn test_blame_covers_all_lines() {
+        // Arrange
+        let repo_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let content = crate::git::read_blob(&repo_path, None, "Cargo.toml").unwrap();
+        let expected = String::from_utf8_lossy(&content).lines().count();
+
+        // Act
+        let hunks = blame_file(
+            &repo_path,
+            &order(&repo_path),
+            "Cargo.toml",
+            &Mailmap::default(),
+        )
+        .expect("Should blame file");
+
+        // Assert
+        let covered: usize = hunks.iter().map(|h| h.line_count).sum();
+        assert_eq!(covered, expected, "Every line should be attributed");
+    }
+}
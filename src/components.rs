@@ -5,10 +5,13 @@
 //! elements with consistent styling and behavior, eliminating duplication
 //! across generator functions.
 
+pub mod activity;
 pub mod commit;
 pub mod file_list;
 pub mod footer;
+pub mod graph;
 pub mod icons;
+pub mod languages;
 pub mod layout;
 pub mod metadata;
 pub mod nav;
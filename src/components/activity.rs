@@ -0,0 +1,264 @@
+//! Commit activity display components
+
+use maud::{Markup, html};
+
+use crate::activity::{Activity, weekday};
+use crate::util::{civil_from_days, format_date};
+
+/// Weeks shown by the full contribution calendar, one year.
+pub const CALENDAR_WEEKS: usize = 53;
+
+/// Side length of a calendar day in pixels.
+const CELL: usize = 10;
+
+/// Distance between neighbouring calendar days in pixels.
+const STEP: usize = 12;
+
+/// Width reserved for weekday labels in pixels.
+const LABEL_WIDTH: usize = 30;
+
+/// Height reserved for month or hour labels in pixels.
+const LABEL_HEIGHT: usize = 16;
+
+/// Distance between punch card columns and rows in pixels.
+const PUNCH_STEP: usize = 26;
+
+/// Radius of the largest punch card circle in pixels.
+const PUNCH_RADIUS: f64 = 11.0;
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+/// Renders a contribution calendar as inline SVG.
+///
+/// Columns are weeks from Sunday to Saturday, ending with the week of
+/// today. Days are shaded in four levels relative to the busiest day shown.
+///
+/// # Arguments
+///
+/// * `activity`: Commit activity to draw
+/// * `weeks`: Number of weeks to show
+/// * `labeled`: Whether to draw month and weekday labels
+pub fn calendar(activity: &Activity, weeks: usize, labeled: bool) -> Markup {
+    let today = activity.today();
+    let first = today - weekday(today) as i64 - 7 * (weeks.max(1) as i64 - 1);
+    let busiest = (first..=today)
+        .map(|day| activity.commits_on(day))
+        .max()
+        .unwrap_or(0);
+
+    let (x0, y0) = if labeled {
+        (LABEL_WIDTH, LABEL_HEIGHT)
+    } else {
+        (0, 0)
+    };
+    let width = x0 + weeks * STEP - (STEP - CELL);
+    let height = y0 + 7 * STEP - (STEP - CELL);
+
+    html! {
+        svg class="activity-calendar" width=(width) height=(height)
+            viewBox=(format!("0 0 {} {}", width, height)) role="img" aria-label="Commit calendar" {
+            @if labeled {
+                @for week in 1..weeks {
+                    @let start = first + 7 * week as i64;
+                    @let month = civil_from_days(start).1;
+                    @if month != civil_from_days(start - 7).1 {
+                        text class="activity-label" x=(x0 + week * STEP) y=(LABEL_HEIGHT - 6) {
+                            (MONTHS[month as usize - 1])
+                        }
+                    }
+                }
+                @for row in [1, 3, 5] {
+                    text class="activity-label" x="0" y=(y0 + row * STEP + CELL - 1) { (WEEKDAYS[row]) }
+                }
+            }
+            @for week in 0..weeks {
+                @for row in 0..7 {
+                    @let day = first + (7 * week + row) as i64;
+                    @if day <= today {
+                        @let count = activity.commits_on(day);
+                        rect class=(format!("activity-cell level-{}", level(count, busiest)))
+                            x=(x0 + week * STEP) y=(y0 + row * STEP)
+                            width=(CELL) height=(CELL) rx="2" {
+                            title { (commits_label(count)) " on " (format_date(day * 86_400)) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Renders the legend of calendar shading levels.
+pub fn calendar_legend() -> Markup {
+    html! {
+        div class="activity-legend" {
+            "Less"
+            svg width=(5 * STEP) height=(CELL) aria-hidden="true" {
+                @for level in 0..5 {
+                    rect class=(format!("activity-cell level-{}", level))
+                        x=(level * STEP) y="0" width=(CELL) height=(CELL) rx="2" {}
+                }
+            }
+            "More"
+        }
+    }
+}
+
+/// Renders a weekday by hour punch card as inline SVG.
+///
+/// Circle areas are proportional to the number of commits made in each
+/// hour of the week, in the authors' local time.
+///
+/// # Arguments
+///
+/// * `activity`: Commit activity to draw
+pub fn punch_card(activity: &Activity) -> Markup {
+    let busiest = activity
+        .punch_card()
+        .iter()
+        .flatten()
+        .copied()
+        .max()
+        .unwrap_or(0);
+
+    let width = LABEL_WIDTH + 24 * PUNCH_STEP;
+    let height = 7 * PUNCH_STEP + LABEL_HEIGHT;
+
+    html! {
+        svg class="activity-punch-card" width=(width) height=(height)
+            viewBox=(format!("0 0 {} {}", width, height)) role="img" aria-label="Punch card" {
+            @for (row, hours) in activity.punch_card().iter().enumerate() {
+                @let cy = row * PUNCH_STEP + PUNCH_STEP / 2;
+                text class="activity-label" x="0" y=(cy + 4) { (WEEKDAYS[row]) }
+                @for (hour, &count) in hours.iter().enumerate() {
+                    @let cx = LABEL_WIDTH + hour * PUNCH_STEP + PUNCH_STEP / 2;
+                    @if count == 0 {
+                        circle class="punch-empty" cx=(cx) cy=(cy) r="1.5" {}
+                    } @else {
+                        circle class="punch-dot" cx=(cx) cy=(cy)
+                            r=(format!("{:.1}", punch_radius(count, busiest))) {
+                            title {
+                                (commits_label(count)) ", " (WEEKDAYS[row]) " "
+                                (format!("{:02}:00", hour))
+                            }
+                        }
+                    }
+                }
+            }
+            @for hour in (0..24).step_by(3) {
+                text class="activity-label" x=(LABEL_WIDTH + hour * PUNCH_STEP + PUNCH_STEP / 2)
+                    y=(7 * PUNCH_STEP + LABEL_HEIGHT - 4) text-anchor="middle" {
+                    (format!("{:02}", hour))
+                }
+            }
+        }
+    }
+}
+
+/// Shading level from 0 (no commits) to 4 (busiest).
+fn level(count: usize, busiest: usize) -> usize {
+    if count == 0 || busiest == 0 {
+        0
+    } else {
+        (4 * count).div_ceil(busiest).clamp(1, 4)
+    }
+}
+
+/// Punch card circle radius, scaling area with the commit count.
+fn punch_radius(count: usize, busiest: usize) -> f64 {
+    (count as f64 / busiest.max(1) as f64).sqrt() * PUNCH_RADIUS
+}
+
+/// Commit count with its noun, e.g. "1 commit" or "3 commits".
+pub(crate) fn commits_label(count: usize) -> String {
+    if count == 1 {
+        "1 commit".to_string()
+    } else {
+        format!("{} commits", count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::CommitInfo;
+
+    fn activity(dates: &[i64], now: i64) -> Activity {
+        let commits: Vec<CommitInfo> = dates
+            .iter()
+            .map(|&date| {
+                CommitInfo::new(
+                    format!("{:040x}", date),
+                    "Change".to_string(),
+                    "Change".to_string(),
+                    "Alice".to_string(),
+                    date,
+                )
+            })
+            .collect();
+        Activity::from_commits(&commits, now)
+    }
+
+    #[test]
+    fn test_level_scales_to_busiest_day() {
+        assert_eq!(level(0, 8), 0);
+        assert_eq!(level(1, 8), 1);
+        assert_eq!(level(4, 8), 2);
+        assert_eq!(level(8, 8), 4);
+        assert_eq!(level(3, 0), 0);
+    }
+
+    #[test]
+    fn test_calendar_ends_on_today() {
+        // Arrange: today is Tuesday 2023-11-14, with two commits on it
+        let now = 1_700_000_000;
+        let activity = activity(&[now - 60, now - 120], now);
+
+        // Act
+        let html = calendar(&activity, 2, false).into_string();
+
+        // Assert
+        assert_eq!(
+            html.matches("<rect").count(),
+            7 + 3,
+            "Full previous week and Sunday to Tuesday"
+        );
+        assert!(html.contains("<title>2 commits on 2023-11-14</title>"));
+        assert!(html.contains(r#"class="activity-cell level-4""#));
+        assert!(!html.contains("activity-label"));
+    }
+
+    #[test]
+    fn test_calendar_labels_months_and_weekdays() {
+        // Arrange
+        let activity = activity(&[], 1_700_000_000);
+
+        // Act
+        let html = calendar(&activity, CALENDAR_WEEKS, true).into_string();
+
+        // Assert
+        assert!(html.contains(">Dec</text>"));
+        assert!(html.contains(">Mon</text>"));
+        assert!(html.contains("<title>0 commits on 2023-11-14</title>"));
+    }
+
+    #[test]
+    fn test_punch_card_sizes_circles() {
+        // Arrange: Tuesday 22:13 UTC twice, Tuesday 21:13 UTC once
+        let now = 1_700_000_000;
+        let activity = activity(&[now, now - 60, now - 3600], now);
+
+        // Act
+        let html = punch_card(&activity).into_string();
+
+        // Assert
+        assert_eq!(html.matches("punch-dot").count(), 2);
+        assert!(html.contains("<title>2 commits, Tue 22:00</title>"));
+        assert!(html.contains(r#"r="11.0""#), "Busiest hour is largest");
+        assert_eq!(html.matches("punch-empty").count(), 7 * 24 - 2);
+    }
+}
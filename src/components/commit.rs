@@ -1,42 +1,64 @@
 //! Commit attribution display components
 
-use maud::{Markup, html};
+use maud::{Markup, PreEscaped, html};
 
-use crate::git::CommitInfo;
+use crate::avatar;
+use crate::contributors::author_slug;
+use crate::git::{ChangeKind, CommitInfo};
+use crate::markdown::MarkdownRenderer;
+use crate::notes::Note;
+use crate::signature::{Signature, Verification};
+use crate::trailers::{CO_AUTHORED_BY, Trailer};
 
 /// Renders commit attribution with all authors visible and committer indicator.
 ///
-/// Shows author and co-authors inline. When committer differs from author,
-/// displays a dagger (†) with CSS tooltip showing committer name.
-pub fn attribution(commit: &CommitInfo) -> Markup {
-    let has_co_authors = !commit.co_authors().is_empty();
+/// Shows author and co-authors inline, each linking to their contributor
+/// page. When committer differs from author, displays a dagger (†) with CSS
+/// tooltip showing committer name.
+///
+/// # Arguments
+///
+/// * `commit`: Commit to attribute
+/// * `root`: Relative prefix from the current page to the site root
+pub fn attribution(commit: &CommitInfo, root: &str) -> Markup {
+    let mut authors = vec![commit.author()];
+    authors.extend(commit.co_authors().iter().map(String::as_str));
     let has_different_committer = commit.author() != commit.committer();
 
-    if !has_co_authors && !has_different_committer {
-        return html! {
-            span class="attribution" { (commit.author()) }
-        };
+    html! {
+        span class="attribution" {
+            @for (idx, name) in authors.iter().enumerate() {
+                @if idx > 0 { ", " }
+                (author_link(name, root))
+            }
+            @if has_different_committer {
+                span class="attribution-dagger" data-tooltip=(format!("Committer: {}", commit.committer())) { "†" }
+            }
+        }
     }
+}
 
-    let mut authors = vec![commit.author().to_string()];
-    authors.extend(commit.co_authors().iter().cloned());
-    let author_list = authors.join(", ");
+/// Renders an author name linking to the author's contributor page.
+pub fn author_link(name: &str, root: &str) -> Markup {
+    html! {
+        a class="author-link" href=(contributor_href(name, root)) { (name) }
+    }
+}
 
-    if has_different_committer {
-        let tooltip = format!("Committer: {}", commit.committer());
-        html! {
-            span class="attribution" {
-                (author_list)
-                span class="attribution-dagger" data-tooltip=(tooltip) { "†" }
-            }
-        }
-    } else {
-        html! {
-            span class="attribution" { (author_list) }
+/// Renders an author avatar linking to the author's contributor page.
+pub fn author_avatar(name: &str, size: u32, root: &str) -> Markup {
+    html! {
+        a class="author-avatar" href=(contributor_href(name, root)) title=(name) {
+            (avatar::render(name, size))
         }
     }
 }
 
+/// Path of an author's contributor page relative to the current page.
+pub(crate) fn contributor_href(name: &str, root: &str) -> String {
+    format!("{}contributors/{}/page-1.html", root, author_slug(name))
+}
+
 /// Renders commit hash with CSS tooltip showing full hash.
 pub fn commit_hash(hash: &str) -> Markup {
     let short = if hash.len() >= 7 { &hash[..7] } else { hash };
@@ -44,3 +66,112 @@ pub fn commit_hash(hash: &str) -> Markup {
         code class="commit-hash" data-full=(hash) { (short) }
     }
 }
+
+/// Renders the icon of a change kind in a changed file list.
+pub fn change_icon(kind: ChangeKind) -> Markup {
+    match kind {
+        ChangeKind::Added => html! { i class="ph ph-plus-square change-added" title="Added" {} },
+        ChangeKind::Deleted => {
+            html! { i class="ph ph-minus-square change-deleted" title="Deleted" {} }
+        }
+        ChangeKind::Modified => {
+            html! { i class="ph ph-pencil-simple change-modified" title="Modified" {} }
+        }
+    }
+}
+
+/// Renders a signature state badge with CSS tooltip showing the signing key.
+///
+/// Shows "Verified" or "Unverified" once the signature has been checked
+/// against allowed signers, and "Signed" otherwise.
+pub fn signature_badge(signature: &Signature) -> Markup {
+    let (state, icon, label) = match signature.verification() {
+        Verification::Verified(_) => ("verified", "ph-seal-check", "Verified"),
+        Verification::Unverified => ("unverified", "ph-seal-warning", "Unverified"),
+        Verification::Unchecked => ("signed", "ph-seal", "Signed"),
+    };
+
+    let mut tooltip = format!(
+        "{} key {}",
+        signature.kind().label(),
+        signature.fingerprint().unwrap_or("unknown")
+    );
+    if let Verification::Verified(principal) = signature.verification() {
+        tooltip.push_str(&format!(" · {}", principal));
+    }
+
+    html! {
+        span class=(format!("signature-badge {}", state)) data-tooltip=(tooltip) {
+            i class=(format!("ph {}", icon)) {}
+            (label)
+        }
+    }
+}
+
+/// Renders commit message trailers as labeled metadata chips.
+///
+/// Trailers naming a person (`Signed-off-by: Name <email>`) show the
+/// person's avatar and name; other values are shown as written.
+/// Co-authored-by trailers are skipped since the attribution already
+/// lists co-authors.
+///
+/// # Arguments
+///
+/// * `trailers`: Trailers of a commit message
+pub fn trailer_chips(trailers: &[Trailer]) -> Markup {
+    let shown: Vec<&Trailer> = trailers
+        .iter()
+        .filter(|trailer| !trailer.is(CO_AUTHORED_BY))
+        .collect();
+    if shown.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        div class="trailer-list" {
+            @for trailer in shown {
+                span class="trailer-chip" title=(format!("{}: {}", trailer.key(), trailer.value())) {
+                    span class="trailer-key" { (trailer.key()) }
+                    @if let Some(name) = trailer.name() {
+                        (avatar::render(name, 16))
+                        span class="trailer-value" { (name) }
+                    } @else {
+                        span class="trailer-value" { (trailer.value()) }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Renders git notes as collapsible blocks labeled with their notes ref.
+///
+/// Note text goes through the markdown renderer; text that fails to render
+/// is shown preformatted instead.
+///
+/// # Arguments
+///
+/// * `notes`: Notes attached to a commit
+/// * `renderer`: Markdown renderer for note text
+/// * `open`: Whether the blocks start expanded
+pub fn commit_notes(notes: &[Note], renderer: &MarkdownRenderer, open: bool) -> Markup {
+    html! {
+        @for note in notes {
+            details class="commit-note" open[open] {
+                summary class="commit-note-summary" {
+                    i class="ph ph-note" {}
+                    "Notes"
+                    span class="commit-note-ref" { (note.notes_ref()) }
+                }
+                @match renderer.render(note.text()) {
+                    Ok(rendered) => {
+                        div class="commit-note-body markdown-content latte" { (PreEscaped(rendered)) }
+                    }
+                    Err(_) => {
+                        pre class="commit-note-body" { (note.text()) }
+                    }
+                }
+            }
+        }
+    }
+}
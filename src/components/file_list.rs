@@ -2,7 +2,10 @@
 
 use maud::{Markup, html};
 
+use crate::components::icons::{submodule_icon, symlink_icon};
 use crate::git::CommitInfo;
+use crate::tree::SymlinkTarget;
+use crate::util::web_url;
 
 /// Wraps file rows in table container
 ///
@@ -64,6 +67,7 @@ fn format_tooltip(commit: &CommitInfo) -> String {
 /// * `href`: Link target for row click
 /// * `icon`: Icon markup (from icons module)
 /// * `name`: File or directory name to display
+/// * `executable`: Whether to mark the file as executable
 /// * `commit`: Optional commit information for tooltip and message display
 /// * `formatted_date`: Pre-formatted timestamp string
 ///
@@ -74,6 +78,7 @@ pub fn file_row(
     href: &str,
     icon: Markup,
     name: &str,
+    executable: bool,
     commit: Option<&CommitInfo>,
     formatted_date: &str,
 ) -> Markup {
@@ -87,6 +92,11 @@ pub fn file_row(
             div class="file-name-cell" {
                 (icon)
                 span { (name) }
+                @if executable {
+                    span class="file-mode executable" title="Executable file" {
+                        i class="ph ph-terminal-window" {}
+                    }
+                }
             }
             @if !tooltip.is_empty() {
                 div class="commit-message" title=(tooltip) {
@@ -101,3 +111,171 @@ pub fn file_row(
         }
     }
 }
+
+/// Renders symlink row in table
+///
+/// Shows the link name followed by its target. The row links to whatever
+/// `href` points at, normally the target's page when it resolves inside the
+/// repository and the symlink's own blob page otherwise.
+///
+/// # Arguments
+///
+/// * `href`: Link target for row click
+/// * `name`: Symlink name to display
+/// * `target`: Link target as stored in the repository
+/// * `commit`: Optional commit that last changed the symlink
+/// * `formatted_date`: Pre-formatted timestamp string
+///
+/// # Returns
+///
+/// File row for the symlink
+pub fn symlink_row(
+    href: &str,
+    name: &str,
+    target: &str,
+    commit: Option<&CommitInfo>,
+    formatted_date: &str,
+) -> Markup {
+    let (tooltip, message) = match commit {
+        Some(c) => (format_tooltip(c), c.message().to_string()),
+        None => (String::new(), String::new()),
+    };
+
+    html! {
+        a href=(href) class="file-row symlink-row" {
+            div class="file-name-cell" {
+                (symlink_icon())
+                span { (name) }
+                span class="symlink-target" { "→ " (target) }
+            }
+            @if !tooltip.is_empty() {
+                div class="commit-message" title=(tooltip) {
+                    (message)
+                }
+            } @else {
+                div class="commit-message" {}
+            }
+            div class="commit-date" {
+                (formatted_date)
+            }
+        }
+    }
+}
+
+/// Resolves the link target of a symlink row
+///
+/// Links to the blob or tree page of the resolved target, falling back to
+/// the symlink's own blob page when the target lies outside the repository.
+///
+/// # Arguments
+///
+/// * `root`: Relative prefix from the current page to the site root
+/// * `ref_name`: Reference name (branch/tag) being rendered
+/// * `link_path`: Symlink path relative to repository root
+/// * `resolved`: Resolved target, if any
+///
+/// # Returns
+///
+/// Link target for the symlink row
+pub fn symlink_href(
+    root: &str,
+    ref_name: &str,
+    link_path: &str,
+    resolved: Option<&SymlinkTarget>,
+) -> String {
+    match resolved {
+        Some(SymlinkTarget::File(path)) => format!("{}blob/{}/{}.html", root, ref_name, path),
+        Some(SymlinkTarget::Directory(path)) if path.is_empty() => format!("{}index.html", root),
+        Some(SymlinkTarget::Directory(path)) => format!("{}tree/{}/{}.html", root, ref_name, path),
+        None => format!("{}blob/{}/{}.html", root, ref_name, link_path),
+    }
+}
+
+/// Renders submodule row in table
+///
+/// Shows the submodule name with its pinned commit next to it. The row links
+/// to the submodule's nested site or remote when one is known, and is left
+/// unlinked otherwise.
+///
+/// # Arguments
+///
+/// * `href`: Link target, if the submodule can be browsed
+/// * `name`: Submodule directory name to display
+/// * `oid`: Full hash of the pinned commit
+/// * `commit`: Optional commit that last updated the submodule
+/// * `formatted_date`: Pre-formatted timestamp string
+///
+/// # Returns
+///
+/// File row for the submodule
+pub fn submodule_row(
+    href: Option<&str>,
+    name: &str,
+    oid: &str,
+    commit: Option<&CommitInfo>,
+    formatted_date: &str,
+) -> Markup {
+    let (tooltip, message) = match commit {
+        Some(c) => (format_tooltip(c), c.message().to_string()),
+        None => (String::new(), String::new()),
+    };
+    let short_oid = oid.get(..7).unwrap_or(oid);
+
+    let cells = html! {
+        div class="file-name-cell" {
+            (submodule_icon())
+            span { (name) }
+            span class="submodule-oid" title=(oid) { "@ " (short_oid) }
+        }
+        @if !tooltip.is_empty() {
+            div class="commit-message" title=(tooltip) {
+                (message)
+            }
+        } @else {
+            div class="commit-message" {}
+        }
+        div class="commit-date" {
+            (formatted_date)
+        }
+    };
+
+    html! {
+        @if let Some(href) = href {
+            a href=(href) class="file-row submodule-row" { (cells) }
+        } @else {
+            div class="file-row submodule-row" { (cells) }
+        }
+    }
+}
+
+/// Resolves the link target of a submodule row
+///
+/// A nested site generated for the pinned commit takes precedence over the
+/// remote, which is only linked when it has a known web location.
+///
+/// # Arguments
+///
+/// * `root`: Relative prefix from the current page to the site root
+/// * `full_path`: Submodule path relative to repository root
+/// * `oid`: Commit the submodule is pinned to
+/// * `url`: Remote URL from `.gitmodules`
+/// * `has_site`: Whether a nested site exists for the pinned commit
+///
+/// # Returns
+///
+/// Link target, or None if the submodule cannot be browsed
+pub fn submodule_href(
+    root: &str,
+    full_path: &str,
+    oid: &str,
+    url: Option<&str>,
+    has_site: bool,
+) -> Option<String> {
+    if has_site {
+        return Some(format!(
+            "{}submodules/{}/{}/index.html",
+            root, full_path, oid
+        ));
+    }
+    url.and_then(web_url)
+}
@@ -0,0 +1,136 @@
+//! Commit graph display components
+
+use maud::{Markup, html};
+
+use crate::graph::{Edge, GraphRow, RefLabel};
+
+/// Horizontal distance between lanes in pixels.
+pub const LANE_WIDTH: usize = 14;
+
+/// Vertical position of commit nodes, level with the commit message.
+const NODE_Y: usize = 27;
+
+/// Vertical distance over which edges change lanes.
+const BEND: usize = 14;
+
+/// Number of lane colors defined in the stylesheet.
+const LANE_COLORS: usize = 6;
+
+/// Width in pixels of a graph column holding the given number of lanes.
+pub fn graph_width(lanes: usize) -> usize {
+    lanes.max(1) * LANE_WIDTH
+}
+
+/// Renders the graph segment of one commit as inline SVG.
+///
+/// The SVG spans the full height of the commit entry: edges reach the top
+/// and bottom borders so segments of neighbouring entries join up whatever
+/// their height, while the node stays level with the commit message.
+///
+/// # Arguments
+///
+/// * `row`: Graph layout of the commit
+/// * `lanes`: Lanes drawn across the page, for a constant column width
+pub fn graph_cell(row: &GraphRow, lanes: usize) -> Markup {
+    let node_x = lane_x(row.column);
+    let bend_y = NODE_Y + BEND;
+
+    html! {
+        svg class="commit-graph" width=(graph_width(lanes)) height="100%" aria-hidden="true" {
+            @for edge in &row.edges {
+                @match *edge {
+                    Edge::Through(lane) => {
+                        line class=(lane_class(lane)) x1=(lane_x(lane)) y1="0" x2=(lane_x(lane)) y2="100%" {}
+                    }
+                    Edge::Into(lane) => {
+                        line class=(lane_class(lane)) x1=(lane_x(lane)) y1="0" x2=(node_x) y2=(NODE_Y) {}
+                    }
+                    Edge::OutOf(lane) => {
+                        line class=(lane_class(lane)) x1=(node_x) y1=(NODE_Y) x2=(lane_x(lane)) y2=(bend_y) {}
+                        line class=(lane_class(lane)) x1=(lane_x(lane)) y1=(bend_y) x2=(lane_x(lane)) y2="100%" {}
+                    }
+                }
+            }
+            circle class=(format!("graph-node {}", lane_class(row.column))) cx=(node_x) cy=(NODE_Y) r="4" data-merge[row.is_merge] {}
+        }
+    }
+}
+
+/// Renders branch and tag labels of a commit.
+pub fn ref_badges(labels: &[RefLabel]) -> Markup {
+    html! {
+        @for label in labels {
+            @match label {
+                RefLabel::Branch(name) => {
+                    span class="ref-label branch" {
+                        i class="ph ph-git-branch" {}
+                        (name)
+                    }
+                }
+                RefLabel::Tag(name) => {
+                    span class="ref-label tag" {
+                        i class="ph ph-tag" {}
+                        (name)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Horizontal center of a lane.
+fn lane_x(lane: usize) -> usize {
+    lane * LANE_WIDTH + LANE_WIDTH / 2
+}
+
+/// Color class of a lane.
+fn lane_class(lane: usize) -> String {
+    format!("lane-{}", lane % LANE_COLORS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_graph_cell_draws_edges_and_node() {
+        // Arrange
+        let row = GraphRow {
+            column: 1,
+            width: 2,
+            edges: vec![Edge::Through(0), Edge::Into(1), Edge::OutOf(0)],
+            is_merge: false,
+            labels: vec![],
+        };
+
+        // Act
+        let html = graph_cell(&row, 3).into_string();
+
+        // Assert
+        assert!(
+            html.contains(r#"width="42""#),
+            "Width should cover page lanes"
+        );
+        assert!(html.contains(r#"<line class="lane-0" x1="7" y1="0" x2="7" y2="100%">"#));
+        assert!(html.contains(r#"<line class="lane-1" x1="21" y1="0" x2="21" y2="27">"#));
+        assert!(html.contains(r#"<line class="lane-0" x1="21" y1="27" x2="7" y2="41">"#));
+        assert!(html.contains(r#"<circle class="graph-node lane-1" cx="21" cy="27""#));
+        assert!(!html.contains("data-merge"));
+    }
+
+    #[test]
+    fn test_ref_badges_marks_branches_and_tags() {
+        // Act
+        let html = ref_badges(&[
+            RefLabel::Branch("main".to_string()),
+            RefLabel::Tag("v1.0".to_string()),
+        ])
+        .into_string();
+
+        // Assert
+        assert!(html.contains(r#"<span class="ref-label branch">"#));
+        assert!(
+            html.contains(r#"<span class="ref-label tag"><i class="ph ph-tag"></i>v1.0</span>"#)
+        );
+    }
+}
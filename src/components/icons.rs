@@ -29,6 +29,35 @@ pub fn file_icon(path: &str) -> Markup {
     }
 }
 
+/// Renders submodule icon
+///
+/// Submodules get a distinct folder glyph and color so they stand apart
+/// from regular directories in file listings.
+///
+/// # Returns
+///
+/// Icon markup for a submodule entry
+pub fn submodule_icon() -> Markup {
+    html! {
+        div class="icon-box" {
+            i class="ph-fill ph-folder-notch icon-submodule" {}
+        }
+    }
+}
+
+/// Renders symlink icon
+///
+/// # Returns
+///
+/// Icon markup for a symbolic link entry
+pub fn symlink_icon() -> Markup {
+    html! {
+        div class="icon-box" {
+            i class="ph ph-link icon-symlink" {}
+        }
+    }
+}
+
 /// Returns Phosphor icon classes for file type
 ///
 /// Matches file paths to appropriate icon classes based on extension
@@ -0,0 +1,121 @@
+//! Language breakdown display components
+
+use maud::{Markup, html};
+
+use crate::languages::{Language, LanguageBreakdown};
+
+/// Languages named in the bar legend; smaller ones are summed as "Other".
+const LEGEND_LANGUAGES: usize = 6;
+
+/// Renders a colored language bar with a legend of the largest languages.
+///
+/// # Arguments
+///
+/// * `breakdown`: Languages of the tree, largest first
+/// * `href`: Link to the full language breakdown page
+pub fn language_bar(breakdown: &LanguageBreakdown, href: &str) -> Markup {
+    let languages = breakdown.languages();
+    let shown = &languages[..languages.len().min(LEGEND_LANGUAGES)];
+    let other: f64 = languages[shown.len()..]
+        .iter()
+        .map(|language| breakdown.percent(language))
+        .sum();
+
+    html! {
+        div.language-summary {
+            a.language-bar href=(href) aria-label="Languages" {
+                @for language in languages {
+                    (segment(breakdown, language))
+                }
+            }
+            ul.language-legend {
+                @for language in shown {
+                    li {
+                        span.language-dot style=(format!("background-color: {}", language.color())) {}
+                        span.language-name { (language.name()) }
+                        span.language-percent { (format_percent(breakdown.percent(language))) }
+                    }
+                }
+                @if other > 0.0 {
+                    li {
+                        span.language-dot.language-other {}
+                        span.language-name { "Other" }
+                        span.language-percent { (format_percent(other)) }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Renders one language's share of a language bar.
+pub(crate) fn segment(breakdown: &LanguageBreakdown, language: &Language) -> Markup {
+    let percent = breakdown.percent(language);
+    html! {
+        span.language-segment
+            style=(format!("width: {:.3}%; background-color: {}", percent, language.color()))
+            title=(format!("{} {}", language.name(), format_percent(percent))) {}
+    }
+}
+
+/// Percentage with one decimal, e.g. "42.5%", or "<0.1%" for tiny shares.
+pub(crate) fn format_percent(percent: f64) -> String {
+    if percent > 0.0 && percent < 0.1 {
+        "<0.1%".to_string()
+    } else {
+        format!("{:.1}%", percent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn breakdown(bytes: &[(&str, u64)]) -> LanguageBreakdown {
+        LanguageBreakdown::from_bytes(
+            bytes
+                .iter()
+                .map(|&(name, bytes)| (name.to_string(), bytes))
+                .collect::<HashMap<_, _>>(),
+        )
+    }
+
+    #[test]
+    fn test_language_bar_sums_small_languages() {
+        // Arrange
+        let breakdown = breakdown(&[
+            ("Rust", 900),
+            ("CSS", 40),
+            ("HTML", 20),
+            ("Python", 10),
+            ("C", 10),
+            ("Go", 10),
+            ("Lua", 5),
+            ("Perl", 5),
+        ]);
+
+        // Act
+        let html = language_bar(&breakdown, "languages/index.html").into_string();
+
+        // Assert
+        assert!(html.contains(r#"href="languages/index.html""#));
+        assert_eq!(html.matches("language-segment").count(), 8);
+        assert!(html.contains("width: 90.000%; background-color: #dea584"));
+        assert!(html.contains(
+            "<span class=\"language-name\">Rust</span><span class=\"language-percent\">90.0%</span>"
+        ));
+        assert!(html.contains(
+            "<span class=\"language-name\">Other</span><span class=\"language-percent\">1.0%</span>"
+        ));
+        assert!(!html.contains(">Perl<"));
+    }
+
+    #[test]
+    fn test_format_percent() {
+        assert_eq!(format_percent(42.46), "42.5%");
+        assert_eq!(format_percent(100.0), "100.0%");
+        assert_eq!(format_percent(0.04), "<0.1%");
+        assert_eq!(format_percent(0.0), "0.0%");
+    }
+}
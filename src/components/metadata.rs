@@ -2,19 +2,32 @@
 
 use maud::{Markup, html};
 
+use crate::archive::ArchiveFormat;
+use crate::git::TagInfo;
+use crate::util::format_timestamp;
+
 /// Data for repository header rendering
 pub struct RepoHeaderData<'a> {
     pub name: &'a str,
     pub owner: Option<&'a str>,
     pub tag_count: usize,
     pub tags_href: Option<&'a str>,
+    pub contributor_count: usize,
+    pub contributors_href: Option<&'a str>,
+    /// Source archive href without extension, linked in each format
+    pub archive_href: Option<&'a str>,
+    /// Tag shown as the latest release
+    pub latest_release: Option<&'a TagInfo>,
+    pub latest_release_href: Option<&'a str>,
 }
 
-/// Renders repository header with name, owner, and tag count
+/// Renders repository header with name, owner, tag and contributor counts,
+/// downloads and the latest release
 ///
 /// # Arguments
 ///
-/// * `data`: Header data containing name, owner, and tag info
+/// * `data`: Header data containing name, owner, tag, contributor,
+///   archive and release info
 ///
 /// # Returns
 ///
@@ -36,6 +49,50 @@ pub fn repo_header(data: RepoHeaderData<'_>) -> Markup {
                     }
                 }
             }
+            @if let (Some(tag), Some(href)) = (data.latest_release, data.latest_release_href) {
+                a href=(href) class="repo-release-card" {
+                    i class="ph ph-rocket-launch" {}
+                    span class="repo-release-label" { "Latest release" }
+                    span class="repo-release-name" { (tag.name) }
+                    @if let Some(date) = tag.date {
+                        span class="repo-release-date" { (format_timestamp(date)) }
+                    }
+                }
+            }
+            @if data.contributor_count > 0 {
+                @if let Some(href) = data.contributors_href {
+                    a href=(href) class="repo-contributors-link" title="Contributors" {
+                        i class="ph ph-users" {}
+                        (data.contributor_count)
+                    }
+                }
+            }
+            @if let Some(href) = data.archive_href {
+                @for format in ArchiveFormat::ALL {
+                    a href=(format!("{}.{}", href, format.extension())) class="repo-archive-link" download {
+                        i class="ph ph-download-simple" {}
+                        (format.extension())
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Renders the command cloning the repository over dumb HTTP
+///
+/// # Arguments
+///
+/// * `url`: Clone URL of the published repository
+///
+/// # Returns
+///
+/// Clone command markup, selected whole on click
+pub fn clone_url(url: &str) -> Markup {
+    html! {
+        div class="clone-url" title="Clone with git" {
+            i class="ph ph-terminal-window" {}
+            code class="clone-command" { "git clone " (url) }
         }
     }
 }
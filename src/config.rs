@@ -31,6 +31,84 @@ pub struct Config {
     /// Disable automatic opening of index.html in browser
     #[arg(long, default_value_t = false)]
     pub no_open: bool,
+
+    /// Follow file renames when building file history pages
+    #[arg(long, default_value_t = false)]
+    pub follow_renames: bool,
+
+    /// Regenerate every page, ignoring the build manifest
+    #[arg(long, default_value_t = false)]
+    pub force: bool,
+
+    /// Number of parallel generation jobs (defaults to available cores)
+    #[arg(short, long)]
+    pub jobs: Option<usize>,
+
+    /// Mailmap file applied on top of the repository's .mailmap
+    #[arg(long)]
+    pub mailmap: Option<PathBuf>,
+
+    /// SSH allowed_signers file used to verify commit and tag signatures
+    #[arg(long)]
+    pub allowed_signers: Option<PathBuf>,
+
+    /// Notes refs shown with commits (repeatable, globs like `refs/notes/*`)
+    #[arg(
+        long = "notes-ref",
+        value_name = "REF",
+        default_value = "refs/notes/commits"
+    )]
+    pub notes_refs: Vec<String>,
+
+    /// Treat the repository path as a directory of repositories and build
+    /// a site for each under the output directory, plus a landing page
+    #[arg(long, default_value_t = false)]
+    pub multi: bool,
+
+    /// Also generate source archives for every branch (tags always get them)
+    #[arg(long, default_value_t = false)]
+    pub branch_archives: bool,
+
+    /// Publish only branches matching this glob (repeatable, default all)
+    #[arg(long = "branch", value_name = "GLOB")]
+    pub include_branches: Vec<String>,
+
+    /// Skip branches matching this glob (repeatable)
+    #[arg(long = "exclude-branch", value_name = "GLOB")]
+    pub exclude_branches: Vec<String>,
+
+    /// Publish only tags matching this glob (repeatable, default all)
+    #[arg(long = "tag", value_name = "GLOB")]
+    pub include_tags: Vec<String>,
+
+    /// Skip tags matching this glob (repeatable)
+    #[arg(long = "exclude-tag", value_name = "GLOB")]
+    pub exclude_tags: Vec<String>,
+
+    /// Publish only the N most recent of the matching tags
+    #[arg(long, value_name = "N")]
+    pub max_tags: Option<usize>,
+
+    /// List at most N commits on the commits pages of each ref
+    #[arg(long, value_name = "N")]
+    pub max_commits: Option<usize>,
+
+    /// Leave commits made before this date (YYYY-MM-DD) off commits pages
+    #[arg(long, value_name = "DATE", value_parser = parse_since)]
+    pub since: Option<i64>,
+
+    /// End the commits pages of other refs where they join the default branch
+    #[arg(long, default_value_t = false)]
+    pub stop_at_default_branch: bool,
+
+    /// Also write `<name>.git`, a copy of the published branches and tags
+    /// clonable over git's dumb HTTP protocol
+    #[arg(long, default_value_t = false)]
+    pub dumb_http: bool,
+
+    /// Public URL of the output directory, used to show the clone URL
+    #[arg(long, value_name = "URL")]
+    pub base_url: Option<String>,
 }
 
 impl Config {
@@ -43,15 +121,65 @@ impl Config {
     ///
     /// # Errors
     ///
-    /// Returns error if repository path does not exist.
+    /// Returns error if repository path, mailmap file or allowed signers file
+    /// does not exist, the repository path is not a directory in multi mode,
+    /// or job count is zero.
     pub fn validate(&self) -> Result<()> {
         if !self.repo.exists() {
             bail!("Repository path does not exist: {}", self.repo.display());
         }
 
+        if self.multi && !self.repo.is_dir() {
+            bail!(
+                "Repository path must be a directory in multi mode: {}",
+                self.repo.display()
+            );
+        }
+
+        if let Some(mailmap) = &self.mailmap
+            && !mailmap.is_file()
+        {
+            bail!("Mailmap file does not exist: {}", mailmap.display());
+        }
+
+        if let Some(allowed_signers) = &self.allowed_signers
+            && !allowed_signers.is_file()
+        {
+            bail!(
+                "Allowed signers file does not exist: {}",
+                allowed_signers.display()
+            );
+        }
+
+        if self.jobs == Some(0) {
+            bail!("Job count must be at least 1");
+        }
+
         Ok(())
     }
 
+    /// Returns number of worker threads for page generation.
+    ///
+    /// Uses `--jobs` when given, otherwise the available parallelism.
+    pub fn job_count(&self) -> usize {
+        self.jobs.unwrap_or_else(crate::pool::default_jobs).max(1)
+    }
+
+    /// Returns the rules selecting published branches and tags.
+    pub fn ref_filter(&self) -> crate::ref_filter::RefFilter {
+        crate::ref_filter::RefFilter::default()
+            .with_branches(self.include_branches.clone(), self.exclude_branches.clone())
+            .with_tags(self.include_tags.clone(), self.exclude_tags.clone())
+            .with_max_tags(self.max_tags)
+    }
+
+    /// Returns the limits on the history listed by commits pages.
+    pub fn history_limit(&self) -> crate::history_limit::HistoryLimit {
+        crate::history_limit::HistoryLimit::default()
+            .with_max_commits(self.max_commits)
+            .with_since(self.since)
+    }
+
     /// Returns project name from configuration or repository directory.
     ///
     /// # Errors
@@ -74,10 +202,37 @@ impl Config {
     }
 }
 
+impl Default for Config {
+    /// Configuration with every option at its command line default.
+    fn default() -> Self {
+        <Self as Parser>::parse_from(["gitkyl"])
+    }
+}
+
+/// Parses the `--since` date as a Unix timestamp.
+fn parse_since(value: &str) -> std::result::Result<i64, String> {
+    crate::util::parse_date(value)
+        .ok_or_else(|| format!("Invalid date, expected YYYY-MM-DD: {}", value))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_config_default_matches_command_line() {
+        // Act
+        let config = Config::default();
+
+        // Assert
+        assert_eq!(config.repo, PathBuf::from("."));
+        assert_eq!(config.output, PathBuf::from("dist"));
+        assert_eq!(config.theme, "Catppuccin-Latte");
+        assert!(config.mailmap.is_none());
+        assert!(config.include_branches.is_empty());
+        assert!(config.max_commits.is_none());
+    }
+
     #[test]
     fn test_config_project_name_with_explicit_name() {
         // Arrange
@@ -88,6 +243,24 @@ mod tests {
             owner: None,
             theme: "Catppuccin-Latte".to_string(),
             no_open: false,
+            follow_renames: false,
+            force: false,
+            jobs: None,
+            mailmap: None,
+            allowed_signers: None,
+            notes_refs: Vec::new(),
+            multi: false,
+            branch_archives: false,
+            include_branches: vec![],
+            exclude_branches: vec![],
+            include_tags: vec![],
+            exclude_tags: vec![],
+            max_tags: None,
+            max_commits: None,
+            since: None,
+            stop_at_default_branch: false,
+            dumb_http: false,
+            base_url: None,
         };
 
         // Act
@@ -108,6 +281,24 @@ mod tests {
             owner: Some("owner".to_string()),
             theme: "Catppuccin-Mocha".to_string(),
             no_open: false,
+            follow_renames: false,
+            force: false,
+            jobs: None,
+            mailmap: None,
+            allowed_signers: None,
+            notes_refs: Vec::new(),
+            multi: false,
+            branch_archives: false,
+            include_branches: vec![],
+            exclude_branches: vec![],
+            include_tags: vec![],
+            exclude_tags: vec![],
+            max_tags: None,
+            max_commits: None,
+            since: None,
+            stop_at_default_branch: false,
+            dumb_http: false,
+            base_url: None,
         };
 
         // Act
@@ -132,6 +323,24 @@ mod tests {
             owner: None,
             theme: "base16-ocean.light".to_string(),
             no_open: false,
+            follow_renames: false,
+            force: false,
+            jobs: None,
+            mailmap: None,
+            allowed_signers: None,
+            notes_refs: Vec::new(),
+            multi: false,
+            branch_archives: false,
+            include_branches: vec![],
+            exclude_branches: vec![],
+            include_tags: vec![],
+            exclude_tags: vec![],
+            max_tags: None,
+            max_commits: None,
+            since: None,
+            stop_at_default_branch: false,
+            dumb_http: false,
+            base_url: None,
         };
 
         // Act
@@ -152,6 +361,24 @@ mod tests {
             owner: None,
             theme: "Catppuccin-Latte".to_string(),
             no_open: false,
+            follow_renames: false,
+            force: false,
+            jobs: None,
+            mailmap: None,
+            allowed_signers: None,
+            notes_refs: Vec::new(),
+            multi: false,
+            branch_archives: false,
+            include_branches: vec![],
+            exclude_branches: vec![],
+            include_tags: vec![],
+            exclude_tags: vec![],
+            max_tags: None,
+            max_commits: None,
+            since: None,
+            stop_at_default_branch: false,
+            dumb_http: false,
+            base_url: None,
         };
 
         // Act
@@ -171,6 +398,24 @@ mod tests {
             owner: None,
             theme: "Catppuccin-Latte".to_string(),
             no_open: false,
+            follow_renames: false,
+            force: false,
+            jobs: None,
+            mailmap: None,
+            allowed_signers: None,
+            notes_refs: Vec::new(),
+            multi: false,
+            branch_archives: false,
+            include_branches: vec![],
+            exclude_branches: vec![],
+            include_tags: vec![],
+            exclude_tags: vec![],
+            max_tags: None,
+            max_commits: None,
+            since: None,
+            stop_at_default_branch: false,
+            dumb_http: false,
+            base_url: None,
         };
 
         // Assert
@@ -190,6 +435,24 @@ mod tests {
             owner: None,
             theme: "Catppuccin-Latte".to_string(),
             no_open: false,
+            follow_renames: false,
+            force: false,
+            jobs: None,
+            mailmap: None,
+            allowed_signers: None,
+            notes_refs: Vec::new(),
+            multi: false,
+            branch_archives: false,
+            include_branches: vec![],
+            exclude_branches: vec![],
+            include_tags: vec![],
+            exclude_tags: vec![],
+            max_tags: None,
+            max_commits: None,
+            since: None,
+            stop_at_default_branch: false,
+            dumb_http: false,
+            base_url: None,
         };
 
         // Act
@@ -210,6 +473,24 @@ mod tests {
             owner: None,
             theme: "Catppuccin-Latte".to_string(),
             no_open: false,
+            follow_renames: false,
+            force: false,
+            jobs: None,
+            mailmap: None,
+            allowed_signers: None,
+            notes_refs: Vec::new(),
+            multi: false,
+            branch_archives: false,
+            include_branches: vec![],
+            exclude_branches: vec![],
+            include_tags: vec![],
+            exclude_tags: vec![],
+            max_tags: None,
+            max_commits: None,
+            since: None,
+            stop_at_default_branch: false,
+            dumb_http: false,
+            base_url: None,
         };
 
         // Act
@@ -234,6 +515,24 @@ mod tests {
             owner: None,
             theme: "Catppuccin-Latte".to_string(),
             no_open: false,
+            follow_renames: false,
+            force: false,
+            jobs: None,
+            mailmap: None,
+            allowed_signers: None,
+            notes_refs: Vec::new(),
+            multi: false,
+            branch_archives: false,
+            include_branches: vec![],
+            exclude_branches: vec![],
+            include_tags: vec![],
+            exclude_tags: vec![],
+            max_tags: None,
+            max_commits: None,
+            since: None,
+            stop_at_default_branch: false,
+            dumb_http: false,
+            base_url: None,
         };
 
         // Act
@@ -244,4 +543,231 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), "test_gitkyl_repo");
     }
+
+    #[test]
+    fn test_job_count() {
+        // Arrange
+        let mut config = Config {
+            repo: PathBuf::from("."),
+            output: PathBuf::from("dist"),
+            name: None,
+            owner: None,
+            theme: "Catppuccin-Latte".to_string(),
+            no_open: false,
+            follow_renames: false,
+            force: false,
+            jobs: Some(3),
+            mailmap: None,
+            allowed_signers: None,
+            notes_refs: Vec::new(),
+            multi: false,
+            branch_archives: false,
+            include_branches: vec![],
+            exclude_branches: vec![],
+            include_tags: vec![],
+            exclude_tags: vec![],
+            max_tags: None,
+            max_commits: None,
+            since: None,
+            stop_at_default_branch: false,
+            dumb_http: false,
+            base_url: None,
+        };
+
+        // Act
+        let explicit = config.job_count();
+        config.jobs = None;
+        let default = config.job_count();
+
+        // Assert
+        assert_eq!(explicit, 3);
+        assert!(default >= 1);
+    }
+
+    #[test]
+    fn test_ref_filter_from_arguments() {
+        // Arrange
+        let config = <Config as Parser>::try_parse_from([
+            "gitkyl",
+            "--branch",
+            "release/*",
+            "--exclude-tag",
+            "nightly-*",
+            "--max-tags",
+            "5",
+        ])
+        .expect("Should parse arguments");
+
+        // Act
+        let filter = config.ref_filter();
+
+        // Assert
+        assert!(filter.keeps_branch("release/1.0"));
+        assert!(!filter.keeps_branch("feature/login"));
+        assert!(filter.keeps_tag("v1.0"));
+        assert!(!filter.keeps_tag("nightly-42"));
+    }
+
+    #[test]
+    fn test_since_parses_date() {
+        let valid = <Config as Parser>::try_parse_from(["gitkyl", "--since", "2023-11-14"]);
+        let invalid = <Config as Parser>::try_parse_from(["gitkyl", "--since", "last year"]);
+
+        assert_eq!(valid.expect("Should parse date").since, Some(1_699_920_000));
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn test_validate_zero_jobs() {
+        // Arrange
+        let config = Config {
+            repo: PathBuf::from("."),
+            output: PathBuf::from("dist"),
+            name: None,
+            owner: None,
+            theme: "Catppuccin-Latte".to_string(),
+            no_open: false,
+            follow_renames: false,
+            force: false,
+            jobs: Some(0),
+            mailmap: None,
+            allowed_signers: None,
+            notes_refs: Vec::new(),
+            multi: false,
+            branch_archives: false,
+            include_branches: vec![],
+            exclude_branches: vec![],
+            include_tags: vec![],
+            exclude_tags: vec![],
+            max_tags: None,
+            max_commits: None,
+            since: None,
+            stop_at_default_branch: false,
+            dumb_http: false,
+            base_url: None,
+        };
+
+        // Act
+        let result = config.validate();
+
+        // Assert
+        assert!(result.is_err(), "Zero jobs should be rejected");
+    }
+
+    #[test]
+    fn test_validate_missing_mailmap() {
+        // Arrange
+        let config = Config {
+            repo: PathBuf::from("."),
+            output: PathBuf::from("dist"),
+            name: None,
+            owner: None,
+            theme: "Catppuccin-Latte".to_string(),
+            no_open: false,
+            follow_renames: false,
+            force: false,
+            jobs: None,
+            mailmap: Some(PathBuf::from("/nonexistent/.mailmap")),
+            allowed_signers: None,
+            notes_refs: Vec::new(),
+            multi: false,
+            branch_archives: false,
+            include_branches: vec![],
+            exclude_branches: vec![],
+            include_tags: vec![],
+            exclude_tags: vec![],
+            max_tags: None,
+            max_commits: None,
+            since: None,
+            stop_at_default_branch: false,
+            dumb_http: false,
+            base_url: None,
+        };
+
+        // Act
+        let result = config.validate();
+
+        // Assert
+        assert!(result.is_err(), "Missing mailmap file should be rejected");
+    }
+
+    #[test]
+    fn test_validate_missing_allowed_signers() {
+        // Arrange
+        let config = Config {
+            repo: PathBuf::from("."),
+            output: PathBuf::from("dist"),
+            name: None,
+            owner: None,
+            theme: "Catppuccin-Latte".to_string(),
+            no_open: false,
+            follow_renames: false,
+            force: false,
+            jobs: None,
+            mailmap: None,
+            allowed_signers: Some(PathBuf::from("/nonexistent/allowed_signers")),
+            notes_refs: Vec::new(),
+            multi: false,
+            branch_archives: false,
+            include_branches: vec![],
+            exclude_branches: vec![],
+            include_tags: vec![],
+            exclude_tags: vec![],
+            max_tags: None,
+            max_commits: None,
+            since: None,
+            stop_at_default_branch: false,
+            dumb_http: false,
+            base_url: None,
+        };
+
+        // Act
+        let result = config.validate();
+
+        // Assert
+        assert!(
+            result.is_err(),
+            "Missing allowed signers file should be rejected"
+        );
+    }
+
+    #[test]
+    fn test_validate_multi_requires_directory() {
+        // Arrange
+        let config = Config {
+            repo: PathBuf::from("Cargo.toml"),
+            output: PathBuf::from("dist"),
+            name: None,
+            owner: None,
+            theme: "Catppuccin-Latte".to_string(),
+            no_open: false,
+            follow_renames: false,
+            force: false,
+            jobs: None,
+            mailmap: None,
+            allowed_signers: None,
+            notes_refs: Vec::new(),
+            multi: true,
+            branch_archives: false,
+            include_branches: vec![],
+            exclude_branches: vec![],
+            include_tags: vec![],
+            exclude_tags: vec![],
+            max_tags: None,
+            max_commits: None,
+            since: None,
+            stop_at_default_branch: false,
+            dumb_http: false,
+            base_url: None,
+        };
+
+        // Act
+        let result = config.validate();
+
+        // Assert
+        assert!(
+            result.is_err(),
+            "Multi mode should reject a file as repository directory"
+        );
+    }
 }
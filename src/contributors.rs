@@ -0,0 +1,357 @@
+//! Contributor statistics aggregated from commit history.
+//!
+//! Authors and co-authors are credited with every commit they appear on,
+//! like `git shortlog` with co-author trailers taken into account. People
+//! are grouped by author slug, so the same name always maps to one page.
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::diff::{DiffStat, diff_lines};
+use crate::filetype::{FileType, detect_file_type};
+use crate::git::{CommitInfo, FileChange, get_commit_detail};
+
+/// Commit author or co-author with aggregated statistics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contributor {
+    name: String,
+    slug: String,
+    commits: Vec<String>,
+    first_date: i64,
+    last_date: i64,
+    lines_added: usize,
+    lines_removed: usize,
+}
+
+impl Contributor {
+    /// Display name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Page slug, see `author_slug`.
+    pub fn slug(&self) -> &str {
+        &self.slug
+    }
+
+    /// Hashes of the credited commits, newest first.
+    pub fn commits(&self) -> &[String] {
+        &self.commits
+    }
+
+    /// Number of credited commits.
+    pub fn commit_count(&self) -> usize {
+        self.commits.len()
+    }
+
+    /// Timestamp of the earliest credited commit (Unix seconds).
+    pub fn first_date(&self) -> i64 {
+        self.first_date
+    }
+
+    /// Timestamp of the latest credited commit (Unix seconds).
+    pub fn last_date(&self) -> i64 {
+        self.last_date
+    }
+
+    /// Lines added by the credited commits.
+    pub fn lines_added(&self) -> usize {
+        self.lines_added
+    }
+
+    /// Lines removed by the credited commits.
+    pub fn lines_removed(&self) -> usize {
+        self.lines_removed
+    }
+}
+
+/// Derives the page slug of an author name.
+///
+/// Letters and digits are kept in lowercase and every other run of
+/// characters becomes a single dash, so `"Ada Lovelace"` maps to
+/// `"ada-lovelace"`. Names without letters or digits map to `"unknown"`.
+///
+/// # Examples
+///
+/// ```
+/// use gitkyl::author_slug;
+///
+/// assert_eq!(author_slug("Ada Lovelace"), "ada-lovelace");
+/// assert_eq!(author_slug("J. Doe <jd>"), "j-doe-jd");
+/// ```
+pub fn author_slug(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    while slug.ends_with('-') {
+        slug.pop();
+    }
+    if slug.is_empty() {
+        "unknown".to_string()
+    } else {
+        slug
+    }
+}
+
+/// Counts lines added and removed by a commit.
+///
+/// Compares text files with the first parent; binary files count as
+/// unchanged. Merge commits count as zero, since their changes were made
+/// on the merged branches.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `commit`: Commit to measure
+///
+/// # Returns
+///
+/// Added and removed line counts
+///
+/// # Errors
+///
+/// Returns error if the commit or its file contents cannot be read
+pub fn commit_line_stats(
+    repo_path: impl AsRef<Path>,
+    commit: &CommitInfo,
+) -> Result<(usize, usize)> {
+    if commit.parents().len() > 1 {
+        return Ok((0, 0));
+    }
+
+    let detail = get_commit_detail(repo_path, commit.oid())
+        .with_context(|| format!("Failed to read commit {}", commit.oid()))?;
+
+    let mut total = DiffStat::default();
+    for stat in detail.changes.iter().filter_map(change_stat) {
+        total.added += stat.added;
+        total.removed += stat.removed;
+    }
+
+    Ok((total.added, total.removed))
+}
+
+/// Counts lines added and removed in a changed file.
+///
+/// # Returns
+///
+/// Line counts, or None when either side is binary
+pub(crate) fn change_stat(change: &FileChange) -> Option<DiffStat> {
+    let path = Path::new(&change.path);
+    let text = |content: &Option<Vec<u8>>| -> Option<String> {
+        match content {
+            None => Some(String::new()),
+            Some(bytes) => match detect_file_type(bytes, path) {
+                FileType::Text | FileType::LfsPointer => {
+                    std::str::from_utf8(bytes).ok().map(str::to_string)
+                }
+                _ => None,
+            },
+        }
+    };
+    let (old, new) = (text(&change.old_content)?, text(&change.new_content)?);
+
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    Some(DiffStat::from_edits(&diff_lines(&old_lines, &new_lines)))
+}
+
+/// Aggregates contributors from commit history.
+///
+/// # Arguments
+///
+/// * `commits`: Commits to credit, each listed once
+/// * `line_stats`: Added and removed lines by commit hash; commits missing
+///   from the map count as zero
+///
+/// # Returns
+///
+/// Contributors ranked by commit count, then lines changed, then name
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::{collect_contributors, list_commits};
+/// use std::collections::HashMap;
+/// use std::path::Path;
+///
+/// let commits = list_commits(Path::new("."), None, None)?;
+/// for contributor in collect_contributors(&commits, &HashMap::new()) {
+///     println!("{:>5} {}", contributor.commit_count(), contributor.name());
+/// }
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn collect_contributors(
+    commits: &[CommitInfo],
+    line_stats: &HashMap<String, (usize, usize)>,
+) -> Vec<Contributor> {
+    let mut by_slug: HashMap<String, Contributor> = HashMap::new();
+    let mut dated: HashMap<String, Vec<(i64, &str)>> = HashMap::new();
+
+    for commit in commits {
+        let (added, removed) = line_stats.get(commit.oid()).copied().unwrap_or((0, 0));
+
+        let mut people: Vec<&str> = vec![commit.author()];
+        people.extend(commit.co_authors().iter().map(String::as_str));
+        let mut credited: Vec<String> = Vec::new();
+
+        for name in people {
+            let slug = author_slug(name);
+            if credited.contains(&slug) {
+                continue;
+            }
+            let contributor = by_slug.entry(slug.clone()).or_insert_with(|| Contributor {
+                name: name.to_string(),
+                slug: slug.clone(),
+                commits: Vec::new(),
+                first_date: commit.date(),
+                last_date: commit.date(),
+                lines_added: 0,
+                lines_removed: 0,
+            });
+            contributor.first_date = contributor.first_date.min(commit.date());
+            contributor.last_date = contributor.last_date.max(commit.date());
+            contributor.lines_added += added;
+            contributor.lines_removed += removed;
+            dated
+                .entry(slug.clone())
+                .or_default()
+                .push((commit.date(), commit.oid()));
+            credited.push(slug);
+        }
+    }
+
+    let mut contributors: Vec<Contributor> = by_slug
+        .into_values()
+        .map(|mut contributor| {
+            let mut commits = dated.remove(&contributor.slug).unwrap_or_default();
+            commits.sort_by_key(|(date, _)| std::cmp::Reverse(*date));
+            contributor.commits = commits
+                .into_iter()
+                .map(|(_, oid)| oid.to_string())
+                .collect();
+            contributor
+        })
+        .collect();
+
+    contributors.sort_by(|a, b| {
+        b.commit_count()
+            .cmp(&a.commit_count())
+            .then_with(|| (b.lines_added + b.lines_removed).cmp(&(a.lines_added + a.lines_removed)))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    contributors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commit(oid: &str, message: &str, author: &str, date: i64) -> CommitInfo {
+        CommitInfo::new(
+            oid.to_string(),
+            message.lines().next().unwrap_or("").to_string(),
+            message.to_string(),
+            author.to_string(),
+            date,
+        )
+    }
+
+    #[test]
+    fn test_author_slug() {
+        assert_eq!(author_slug("Ada Lovelace"), "ada-lovelace");
+        assert_eq!(author_slug("  Grace  Hopper! "), "grace-hopper");
+        assert_eq!(author_slug("Zoë"), "zoë");
+        assert_eq!(author_slug("***"), "unknown");
+    }
+
+    #[test]
+    fn test_collect_contributors_ranks_and_aggregates() {
+        // Arrange
+        let commits = vec![
+            commit("c3", "Third", "Alice", 300),
+            commit("c2", "Second", "Bob", 200),
+            commit("c1", "First", "Alice", 100),
+        ];
+        let stats = HashMap::from([
+            ("c3".to_string(), (10, 2)),
+            ("c2".to_string(), (50, 0)),
+            ("c1".to_string(), (5, 1)),
+        ]);
+
+        // Act
+        let contributors = collect_contributors(&commits, &stats);
+
+        // Assert
+        assert_eq!(contributors.len(), 2);
+        let alice = &contributors[0];
+        assert_eq!(alice.name(), "Alice");
+        assert_eq!(alice.commits(), ["c3", "c1"]);
+        assert_eq!((alice.first_date(), alice.last_date()), (100, 300));
+        assert_eq!((alice.lines_added(), alice.lines_removed()), (15, 3));
+        assert_eq!(contributors[1].slug(), "bob");
+    }
+
+    #[test]
+    fn test_collect_contributors_credits_co_authors_once() {
+        // Arrange
+        let commits = vec![
+            commit(
+                "c2",
+                "Pair\n\nCo-authored-by: Bob <bob@example.com>\nCo-authored-by: bob <b@x.com>",
+                "Alice",
+                200,
+            ),
+            commit("c1", "Solo", "Carol", 100),
+        ];
+
+        // Act
+        let contributors = collect_contributors(&commits, &HashMap::new());
+
+        // Assert
+        let names: Vec<&str> = contributors.iter().map(Contributor::name).collect();
+        assert_eq!(names, vec!["Alice", "Bob", "Carol"]);
+        assert_eq!(contributors[1].commits(), ["c2"]);
+    }
+
+    #[test]
+    fn test_commit_line_stats_counts_text_changes() {
+        // Arrange
+        let td = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let repo = td.path();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(repo)
+                .output()
+                .expect("Failed to run git");
+            assert!(output.status.success(), "git {:?} failed", args);
+        };
+        git(&["init"]);
+        git(&["config", "user.name", "Test"]);
+        git(&["config", "user.email", "test@test.com"]);
+        std::fs::write(repo.join("a.txt"), "one\ntwo\n").unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-m", "Add"]);
+        std::fs::write(repo.join("a.txt"), "one\n2\nthree\n").unwrap();
+        std::fs::write(repo.join("b.bin"), [0u8, 1, 2, 0]).unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-m", "Change"]);
+        let commits = crate::git::list_commits(repo, None, Some(2)).expect("Should list commits");
+
+        // Act
+        let latest = commit_line_stats(repo, &commits[0]).expect("Should count");
+        let root = commit_line_stats(repo, &commits[1]).expect("Should count");
+
+        // Assert
+        assert_eq!(latest, (2, 1));
+        assert_eq!(root, (2, 0));
+    }
+}
@@ -0,0 +1,546 @@
+//! Line based diffing for commit pages.
+//!
+//! Implements the Myers O(ND) difference algorithm over interned lines and
+//! groups the resulting edit script into unified diff hunks with context.
+
+use std::collections::HashMap;
+
+/// Number of unchanged lines shown around each change.
+pub const CONTEXT_LINES: usize = 3;
+
+/// Upper bound on edit distance before falling back to a full replacement.
+///
+/// Myers keeps one frontier per edit step, so memory grows quadratically with
+/// the edit distance. Files rewritten beyond this bound are shown as removed
+/// and re-added, which is what a reader would see anyway.
+const MAX_EDIT_DISTANCE: usize = 2048;
+
+/// Single edit operation between two line sequences.
+///
+/// Indices are zero based positions into the old and new line slices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edit {
+    /// Line present in both versions
+    Equal { old: usize, new: usize },
+    /// Line only present in the old version
+    Delete { old: usize },
+    /// Line only present in the new version
+    Insert { new: usize },
+}
+
+/// Line within a unified diff hunk.
+///
+/// Line numbers are one based, matching the gutter of the rendered diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLine {
+    /// Unchanged line shown for context
+    Context { old: usize, new: usize },
+    /// Line added in the new version
+    Added { new: usize },
+    /// Line removed from the old version
+    Removed { old: usize },
+}
+
+/// Contiguous group of changes with surrounding context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hunk {
+    /// First old line covered by the hunk (one based, 0 when empty)
+    pub old_start: usize,
+    /// Number of old lines covered by the hunk
+    pub old_len: usize,
+    /// First new line covered by the hunk (one based, 0 when empty)
+    pub new_start: usize,
+    /// Number of new lines covered by the hunk
+    pub new_len: usize,
+    /// Lines in display order
+    pub lines: Vec<DiffLine>,
+}
+
+impl Hunk {
+    /// Formats the hunk header (e.g., "@@ -1,4 +1,5 @@").
+    pub fn header(&self) -> String {
+        format!(
+            "@@ -{},{} +{},{} @@",
+            self.old_start, self.old_len, self.new_start, self.new_len
+        )
+    }
+}
+
+/// Added and removed line counts for a diff.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiffStat {
+    /// Lines added
+    pub added: usize,
+    /// Lines removed
+    pub removed: usize,
+}
+
+impl DiffStat {
+    /// Counts added and removed lines in an edit script.
+    pub fn from_edits(edits: &[Edit]) -> Self {
+        edits.iter().fold(Self::default(), |mut stat, edit| {
+            match edit {
+                Edit::Insert { .. } => stat.added += 1,
+                Edit::Delete { .. } => stat.removed += 1,
+                Edit::Equal { .. } => {}
+            }
+            stat
+        })
+    }
+}
+
+/// Computes the shortest edit script transforming `old` into `new`.
+///
+/// Common prefix and suffix are stripped before running Myers on the
+/// remaining middle section, which keeps typical commit diffs cheap.
+///
+/// # Arguments
+///
+/// * `old`: Lines of the previous version
+/// * `new`: Lines of the current version
+///
+/// # Returns
+///
+/// Edit operations in order, covering every line of both inputs
+pub fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<Edit> {
+    let prefix = old
+        .iter()
+        .zip(new.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut edits = Vec::with_capacity(old.len().max(new.len()));
+    for i in 0..prefix {
+        edits.push(Edit::Equal { old: i, new: i });
+    }
+
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    // Intern lines so the inner loop compares integers instead of strings
+    let mut ids: HashMap<&str, u32> = HashMap::new();
+    let mut intern = |lines: &[&'a str]| -> Vec<u32> {
+        lines
+            .iter()
+            .map(|line| {
+                let next = ids.len() as u32;
+                *ids.entry(line).or_insert(next)
+            })
+            .collect()
+    };
+    let a = intern(old_mid);
+    let b = intern(new_mid);
+
+    for edit in myers(&a, &b) {
+        edits.push(match edit {
+            Edit::Equal { old, new } => Edit::Equal {
+                old: old + prefix,
+                new: new + prefix,
+            },
+            Edit::Delete { old } => Edit::Delete { old: old + prefix },
+            Edit::Insert { new } => Edit::Insert { new: new + prefix },
+        });
+    }
+
+    for i in 0..suffix {
+        edits.push(Edit::Equal {
+            old: old.len() - suffix + i,
+            new: new.len() - suffix + i,
+        });
+    }
+
+    edits
+}
+
+/// Runs the greedy Myers algorithm and backtracks the edit path.
+fn myers(a: &[u32], b: &[u32]) -> Vec<Edit> {
+    let n = a.len();
+    let m = b.len();
+
+    if n == 0 || m == 0 {
+        return replace_all(n, m);
+    }
+
+    let max = n + m;
+    let offset = max as isize;
+    let mut v = vec![0usize; 2 * max + 2];
+    let mut trace: Vec<Vec<usize>> = Vec::new();
+
+    'search: {
+        for d in 0..=max.min(MAX_EDIT_DISTANCE) {
+            // Only diagonals -d..=d can be reached within d edits
+            trace.push(v[offset as usize - d..=offset as usize + d].to_vec());
+            let d = d as isize;
+            let mut k = -d;
+            while k <= d {
+                let idx = (k + offset) as usize;
+                let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                    v[idx + 1]
+                } else {
+                    v[idx - 1] + 1
+                };
+                let mut y = (x as isize - k) as usize;
+                while x < n && y < m && a[x] == b[y] {
+                    x += 1;
+                    y += 1;
+                }
+                v[idx] = x;
+                if x >= n && y >= m {
+                    break 'search;
+                }
+                k += 2;
+            }
+        }
+        return replace_all(n, m);
+    }
+
+    // Walk the recorded frontiers backwards to recover the path
+    let mut edits = Vec::with_capacity(n + m);
+    let (mut x, mut y) = (n as isize, m as isize);
+    for (d, v) in trace.iter().enumerate().skip(1).rev() {
+        let d = d as isize;
+        let k = x - y;
+        let idx = |k: isize| (k + d) as usize;
+        let prev_k = if k == -d || (k != d && v[idx(k - 1)] < v[idx(k + 1)]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = v[idx(prev_k)] as isize;
+        let prev_y = prev_x - prev_k;
+
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            edits.push(Edit::Equal {
+                old: x as usize,
+                new: y as usize,
+            });
+        }
+
+        if x == prev_x {
+            y -= 1;
+            edits.push(Edit::Insert { new: y as usize });
+        } else {
+            x -= 1;
+            edits.push(Edit::Delete { old: x as usize });
+        }
+    }
+
+    // Remaining snake from the origin
+    while x > 0 && y > 0 {
+        x -= 1;
+        y -= 1;
+        edits.push(Edit::Equal {
+            old: x as usize,
+            new: y as usize,
+        });
+    }
+
+    edits.reverse();
+    edits
+}
+
+/// Edit script that removes every old line and inserts every new line.
+fn replace_all(old_len: usize, new_len: usize) -> Vec<Edit> {
+    (0..old_len)
+        .map(|old| Edit::Delete { old })
+        .chain((0..new_len).map(|new| Edit::Insert { new }))
+        .collect()
+}
+
+/// Groups an edit script into unified diff hunks.
+///
+/// Changes separated by at most twice the context size are merged into a
+/// single hunk, mirroring `git diff` output.
+///
+/// # Arguments
+///
+/// * `edits`: Edit script from [`diff_lines`]
+/// * `context`: Number of unchanged lines to keep around each change
+///
+/// # Returns
+///
+/// Hunks in file order, empty when both versions are identical
+pub fn hunks(edits: &[Edit], context: usize) -> Vec<Hunk> {
+    let changed: Vec<usize> = edits
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| !matches!(e, Edit::Equal { .. }))
+        .map(|(idx, _)| idx)
+        .collect();
+
+    let Some(&first) = changed.first() else {
+        return Vec::new();
+    };
+
+    // Ranges of edit indices that belong to the same hunk
+    let mut ranges = Vec::new();
+    let mut start = first.saturating_sub(context);
+    let mut end = first;
+    for &idx in &changed[1..] {
+        if idx - end > 2 * context {
+            ranges.push((start, (end + context).min(edits.len() - 1)));
+            start = idx - context;
+        }
+        end = idx;
+    }
+    ranges.push((start, (end + context).min(edits.len() - 1)));
+
+    ranges
+        .into_iter()
+        .map(|(start, end)| build_hunk(edits, start, end))
+        .collect()
+}
+
+fn build_hunk(edits: &[Edit], start: usize, end: usize) -> Hunk {
+    // Lines consumed on each side before the hunk begins
+    let old_before = edits[..start]
+        .iter()
+        .filter(|e| !matches!(e, Edit::Insert { .. }))
+        .count();
+    let new_before = edits[..start]
+        .iter()
+        .filter(|e| !matches!(e, Edit::Delete { .. }))
+        .count();
+
+    let mut lines = Vec::with_capacity(end - start + 1);
+    let mut old_len = 0;
+    let mut new_len = 0;
+
+    for edit in &edits[start..=end] {
+        match *edit {
+            Edit::Equal { old, new } => {
+                old_len += 1;
+                new_len += 1;
+                lines.push(DiffLine::Context {
+                    old: old + 1,
+                    new: new + 1,
+                });
+            }
+            Edit::Delete { old } => {
+                old_len += 1;
+                lines.push(DiffLine::Removed { old: old + 1 });
+            }
+            Edit::Insert { new } => {
+                new_len += 1;
+                lines.push(DiffLine::Added { new: new + 1 });
+            }
+        }
+    }
+
+    // Empty sides point at the line preceding the change, as git does
+    Hunk {
+        old_start: if old_len == 0 {
+            old_before
+        } else {
+            old_before + 1
+        },
+        old_len,
+        new_start: if new_len == 0 {
+            new_before
+        } else {
+            new_before + 1
+        },
+        new_len,
+        lines,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<&str> {
+        text.lines().collect()
+    }
+
+    /// Applies an edit script to reconstruct the new version.
+    fn apply<'a>(old: &[&'a str], new: &[&'a str], edits: &[Edit]) -> Vec<&'a str> {
+        edits
+            .iter()
+            .filter_map(|e| match *e {
+                Edit::Equal { old: o, .. } => Some(old[o]),
+                Edit::Insert { new: n } => Some(new[n]),
+                Edit::Delete { .. } => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_diff_identical() {
+        // Arrange
+        let text = lines("a\nb\nc");
+
+        // Act
+        let edits = diff_lines(&text, &text);
+
+        // Assert
+        assert!(edits.iter().all(|e| matches!(e, Edit::Equal { .. })));
+        assert!(hunks(&edits, CONTEXT_LINES).is_empty());
+    }
+
+    #[test]
+    fn test_diff_single_modification() {
+        // Arrange
+        let old = lines("a\nb\nc");
+        let new = lines("a\nx\nc");
+
+        // Act
+        let edits = diff_lines(&old, &new);
+        let stat = DiffStat::from_edits(&edits);
+
+        // Assert
+        assert_eq!(
+            stat,
+            DiffStat {
+                added: 1,
+                removed: 1
+            }
+        );
+        assert_eq!(apply(&old, &new, &edits), new);
+    }
+
+    #[test]
+    fn test_diff_minimal_edit_script() {
+        // Arrange: classic example from the Myers paper
+        let old = lines("a\nb\nc\na\nb\nb\na");
+        let new = lines("c\nb\na\nb\na\nc");
+
+        // Act
+        let edits = diff_lines(&old, &new);
+        let stat = DiffStat::from_edits(&edits);
+
+        // Assert: shortest edit script has five operations
+        assert_eq!(stat.added + stat.removed, 5);
+        assert_eq!(apply(&old, &new, &edits), new);
+    }
+
+    #[test]
+    fn test_diff_from_empty() {
+        // Arrange
+        let old: Vec<&str> = vec![];
+        let new = lines("one\ntwo");
+
+        // Act
+        let edits = diff_lines(&old, &new);
+        let hunks = hunks(&edits, CONTEXT_LINES);
+
+        // Assert
+        assert_eq!(hunks.len(), 1);
+        assert_eq!(hunks[0].header(), "@@ -0,0 +1,2 @@");
+    }
+
+    #[test]
+    fn test_diff_to_empty() {
+        // Arrange
+        let old = lines("one\ntwo\nthree");
+        let new: Vec<&str> = vec![];
+
+        // Act
+        let edits = diff_lines(&old, &new);
+        let hunks = hunks(&edits, CONTEXT_LINES);
+
+        // Assert
+        assert_eq!(hunks.len(), 1);
+        assert_eq!(hunks[0].header(), "@@ -1,3 +0,0 @@");
+        assert!(
+            hunks[0]
+                .lines
+                .iter()
+                .all(|l| matches!(l, DiffLine::Removed { .. }))
+        );
+    }
+
+    #[test]
+    fn test_hunks_split_distant_changes() {
+        // Arrange: changes at line 1 and line 20 are far apart
+        let old: Vec<String> = (1..=20).map(|i| format!("line {}", i)).collect();
+        let mut new = old.clone();
+        new[0] = "changed first".to_string();
+        new[19] = "changed last".to_string();
+        let old_refs: Vec<&str> = old.iter().map(|s| s.as_str()).collect();
+        let new_refs: Vec<&str> = new.iter().map(|s| s.as_str()).collect();
+
+        // Act
+        let edits = diff_lines(&old_refs, &new_refs);
+        let hunks = hunks(&edits, CONTEXT_LINES);
+
+        // Assert
+        assert_eq!(hunks.len(), 2, "Distant changes should form two hunks");
+        assert_eq!(hunks[0].header(), "@@ -1,4 +1,4 @@");
+        assert_eq!(hunks[1].header(), "@@ -17,4 +17,4 @@");
+    }
+
+    #[test]
+    fn test_hunks_merge_nearby_changes() {
+        // Arrange: changes four lines apart share context
+        let old = lines("1\n2\n3\n4\n5\n6\n7\n8\n9");
+        let new = lines("1\nx\n3\n4\n5\n6\ny\n8\n9");
+
+        // Act
+        let edits = diff_lines(&old, &new);
+        let hunks = hunks(&edits, CONTEXT_LINES);
+
+        // Assert
+        assert_eq!(hunks.len(), 1, "Nearby changes should merge");
+        assert_eq!(hunks[0].header(), "@@ -1,9 +1,9 @@");
+    }
+
+    #[test]
+    fn test_hunk_pure_insertion_anchors_to_previous_line() {
+        // Arrange
+        let old = lines("a\nb\nc\nd\ne\nf\ng\nh");
+        let new = lines("a\nb\nc\nd\ne\nf\ng\nh\ni");
+
+        // Act
+        let edits = diff_lines(&old, &new);
+        let hunks = hunks(&edits, 0);
+
+        // Assert
+        assert_eq!(hunks[0].header(), "@@ -8,0 +9,1 @@");
+    }
+
+    #[test]
+    fn test_diff_pseudo_random_inputs_are_minimal() {
+        // Arrange: deterministic LCG so failures are reproducible
+        let mut seed: u64 = 42;
+        let mut next = move |bound: u64| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (seed >> 33) % bound
+        };
+        let alphabet = ["a", "b", "c", "d"];
+
+        for _ in 0..200 {
+            let old: Vec<&str> = (0..next(12)).map(|_| alphabet[next(4) as usize]).collect();
+            let new: Vec<&str> = (0..next(12)).map(|_| alphabet[next(4) as usize]).collect();
+
+            // Act
+            let edits = diff_lines(&old, &new);
+
+            // Assert: script rebuilds the new side and keeps the LCS
+            assert_eq!(apply(&old, &new, &edits), new);
+            let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+            for i in (0..old.len()).rev() {
+                for j in (0..new.len()).rev() {
+                    lcs[i][j] = if old[i] == new[j] {
+                        lcs[i + 1][j + 1] + 1
+                    } else {
+                        lcs[i + 1][j].max(lcs[i][j + 1])
+                    };
+                }
+            }
+            let kept = edits
+                .iter()
+                .filter(|e| matches!(e, Edit::Equal { .. }))
+                .count();
+            assert_eq!(kept, lcs[0][0], "Edit script should be minimal");
+        }
+    }
+}
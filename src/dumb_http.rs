@@ -0,0 +1,476 @@
+//! Repository copies clonable over git's dumb HTTP protocol.
+//!
+//! A dumb HTTP client needs no server side code: it reads `info/refs` for
+//! the advertised refs, `HEAD` for the default branch and
+//! `objects/info/packs` for the packs holding the objects, then downloads
+//! those as plain files. Everything reachable from the published refs is
+//! written into a single pack by `git pack-objects`, so unpublished refs
+//! never leak.
+
+use anyhow::{Context, Result, bail};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Length of the magic number and version preceding a pack index fanout.
+const IDX_HEADER_LEN: usize = 8;
+
+/// Reference advertised in `info/refs`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PublishedRef {
+    /// Full reference name (e.g. "refs/heads/main")
+    name: String,
+    /// Object the reference points at
+    oid: gix::ObjectId,
+    /// Commit an annotated tag points at, advertised as `name^{}`
+    peeled: Option<gix::ObjectId>,
+}
+
+/// Branches and tags published in a dumb HTTP repository.
+#[derive(Debug, Clone, Default)]
+pub struct PublishedRefs {
+    refs: Vec<PublishedRef>,
+    head: String,
+    fingerprint: String,
+}
+
+impl PublishedRefs {
+    /// Resolves the branches and tags to publish.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_path`: Path to git repository
+    /// * `branches`: Branch names, without `refs/heads/`
+    /// * `tags`: Tag names, without `refs/tags/`
+    /// * `default_branch`: Branch the clone checks out
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the repository cannot be opened or a ref cannot be
+    /// resolved
+    pub fn resolve(
+        repo_path: impl AsRef<Path>,
+        branches: &[&str],
+        tags: &[&str],
+        default_branch: &str,
+    ) -> Result<Self> {
+        let repo = gix::open(repo_path.as_ref()).with_context(|| {
+            format!(
+                "Failed to open repository at {}",
+                repo_path.as_ref().display()
+            )
+        })?;
+
+        let names = branches
+            .iter()
+            .map(|branch| format!("refs/heads/{}", branch))
+            .chain(tags.iter().map(|tag| format!("refs/tags/{}", tag)));
+
+        let mut refs = Vec::new();
+        for name in names {
+            let mut reference = repo
+                .find_reference(name.as_str())
+                .with_context(|| format!("Failed to find reference: {}", name))?;
+            let direct = reference.inner.target.try_id().map(ToOwned::to_owned);
+            let peeled = reference
+                .peel_to_id_in_place()
+                .with_context(|| format!("Failed to peel reference '{}'", name))?
+                .detach();
+            let oid = direct.unwrap_or(peeled);
+            refs.push(PublishedRef {
+                name,
+                oid,
+                peeled: (peeled != oid).then_some(peeled),
+            });
+        }
+        refs.sort_by(|a, b| a.name.cmp(&b.name));
+        refs.dedup_by(|a, b| a.name == b.name);
+
+        let mut published = Self {
+            refs,
+            head: format!("refs/heads/{}", default_branch),
+            fingerprint: String::new(),
+        };
+        published.fingerprint = gix::objs::compute_hash(
+            gix::hash::Kind::Sha1,
+            gix::objs::Kind::Blob,
+            format!("{}\n{}", published.head, published.info_refs()).as_bytes(),
+        )
+        .to_hex_with_len(12)
+        .to_string();
+
+        Ok(published)
+    }
+
+    /// Returns true if there is nothing to publish.
+    pub fn is_empty(&self) -> bool {
+        self.refs.is_empty()
+    }
+
+    /// Content of `info/refs`, one `<oid>\t<ref>` line per reference.
+    pub fn info_refs(&self) -> String {
+        let mut content = String::new();
+        for reference in &self.refs {
+            content.push_str(&format!("{}\t{}\n", reference.oid, reference.name));
+            if let Some(peeled) = reference.peeled {
+                content.push_str(&format!("{}\t{}^{{}}\n", peeled, reference.name));
+            }
+        }
+        content
+    }
+
+    /// Short hash of the default branch and all published refs.
+    pub fn fingerprint(&self) -> &str {
+        &self.fingerprint
+    }
+}
+
+/// Directory name of the dumb HTTP repository of a project.
+///
+/// Characters other than ASCII letters, digits, `.`, `_` and `-` become
+/// dashes, so the name is a single safe URL path segment.
+///
+/// # Examples
+///
+/// ```
+/// use gitkyl::clone_dir_name;
+///
+/// assert_eq!(clone_dir_name("gitkyl"), "gitkyl.git");
+/// assert_eq!(clone_dir_name("My Project"), "My-Project.git");
+/// ```
+pub fn clone_dir_name(project_name: &str) -> String {
+    let name: String = project_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let name = name.trim_matches(['.', '-']);
+    if name.is_empty() {
+        "repo.git".to_string()
+    } else {
+        format!("{}.git", name)
+    }
+}
+
+/// Writes a repository clonable over dumb HTTP.
+///
+/// Writes `HEAD`, `info/refs`, `objects/info/packs` and one pack with its
+/// index holding every object reachable from the published refs. The pack
+/// is built by `git pack-objects`, so objects are stored as deltas the same
+/// way `git gc` would. Nothing is rewritten when the published refs and the
+/// pack they list are already in place. Packs of earlier runs are removed
+/// once the new pack is listed.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `refs`: References to publish
+/// * `dir`: Output directory, typically `<output>/<name>.git`
+///
+/// # Returns
+///
+/// Number of objects in the pack, or None when the repository was current
+///
+/// # Errors
+///
+/// Returns error if `git pack-objects` fails or a file cannot be written
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::{PublishedRefs, write_dumb_repository};
+/// use std::path::Path;
+///
+/// let refs = PublishedRefs::resolve(Path::new("."), &["main"], &[], "main")?;
+/// write_dumb_repository(Path::new("."), &refs, Path::new("dist/repo.git"))?;
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn write_dumb_repository(
+    repo_path: impl AsRef<Path>,
+    refs: &PublishedRefs,
+    dir: &Path,
+) -> Result<Option<usize>> {
+    let head = format!("ref: {}\n", refs.head);
+    let info_refs = refs.info_refs();
+    if is_current(dir, &head, &info_refs) {
+        return Ok(None);
+    }
+
+    let pack_dir = dir.join("objects/pack");
+    let info_dir = dir.join("info");
+    let objects_info_dir = dir.join("objects/info");
+    for path in [&pack_dir, &info_dir, &objects_info_dir] {
+        fs::create_dir_all(path)
+            .with_context(|| format!("Failed to create directory {}", path.display()))?;
+    }
+
+    let pack_name = write_pack(repo_path.as_ref(), refs, &pack_dir)?;
+    let index_path = pack_dir.join(format!("{}.idx", pack_name));
+    let count = pack_object_count(&index_path)?;
+
+    // Clients may fetch while the site updates, so the new pack is listed
+    // before the old ones go away
+    let files = [
+        (dir.join("HEAD"), head),
+        (info_dir.join("refs"), info_refs),
+        (
+            objects_info_dir.join("packs"),
+            format!("P {}.pack\n\n", pack_name),
+        ),
+    ];
+    for (path, content) in files {
+        fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+
+    for entry in fs::read_dir(&pack_dir)
+        .with_context(|| format!("Failed to read directory {}", pack_dir.display()))?
+    {
+        let path = entry?.path();
+        let is_stale = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .is_some_and(|stem| stem.starts_with("pack-") && stem != pack_name);
+        if is_stale {
+            fs::remove_file(&path)
+                .with_context(|| format!("Failed to remove stale pack {}", path.display()))?;
+        }
+    }
+
+    Ok(Some(count))
+}
+
+/// Checks whether a dumb repository already publishes the given refs.
+///
+/// The listed pack and its index must exist as well, so an interrupted
+/// run is written again.
+fn is_current(dir: &Path, head: &str, info_refs: &str) -> bool {
+    let read = |path: &str| fs::read_to_string(dir.join(path)).ok();
+    if read("HEAD").as_deref() != Some(head) || read("info/refs").as_deref() != Some(info_refs) {
+        return false;
+    }
+
+    read("objects/info/packs").is_some_and(|packs| {
+        let mut listed = packs
+            .lines()
+            .filter_map(|line| line.strip_prefix("P "))
+            .filter_map(|pack| pack.strip_suffix(".pack"))
+            .peekable();
+        listed.peek().is_some()
+            && listed.all(|name| {
+                let pack_dir = dir.join("objects/pack");
+                pack_dir.join(format!("{}.pack", name)).is_file()
+                    && pack_dir.join(format!("{}.idx", name)).is_file()
+            })
+    })
+}
+
+/// Packs every object reachable from the published refs.
+///
+/// Gitlink entries of submodules are skipped by git, as their commits live
+/// in another repository. `git pack-objects` writes the pack and index
+/// under temporary names and renames them into place; the reverse index
+/// newer git versions add is left out, as dumb clients never read it.
+///
+/// # Returns
+///
+/// Name of the written pack, `pack-<checksum>`
+fn write_pack(repo_path: &Path, refs: &PublishedRefs, pack_dir: &Path) -> Result<String> {
+    let mut child = Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .args(["-c", "pack.writeReverseIndex=false"])
+        .args(["pack-objects", "--revs", "--delta-base-offset", "-q"])
+        .arg(pack_dir.join("pack"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to run git pack-objects")?;
+
+    let revs: String = refs
+        .refs
+        .iter()
+        .map(|reference| format!("{}\n", reference.oid))
+        .collect();
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(revs.as_bytes())
+            .context("Failed to pass published refs to git pack-objects")?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .context("Failed to wait for git pack-objects")?;
+    if !output.status.success() {
+        bail!(
+            "git pack-objects failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let checksum = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if gix::ObjectId::from_hex(checksum.as_bytes()).is_err() {
+        bail!("Unexpected git pack-objects output: {}", checksum);
+    }
+    Ok(format!("pack-{}", checksum))
+}
+
+/// Reads the number of objects from a version 2 pack index.
+///
+/// The last entry of the fanout table, which follows the 8 byte header,
+/// counts all objects.
+fn pack_object_count(index_path: &Path) -> Result<usize> {
+    let index = fs::read(index_path)
+        .with_context(|| format!("Failed to read pack index {}", index_path.display()))?;
+    let count = index
+        .get(IDX_HEADER_LEN + 255 * 4..IDX_HEADER_LEN + 256 * 4)
+        .filter(|_| index.starts_with(b"\xfftOc"))
+        .with_context(|| format!("Invalid pack index {}", index_path.display()))?;
+    Ok(u32::from_be_bytes([count[0], count[1], count[2], count[3]]) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn git(repo: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(repo)
+            .output()
+            .expect("Failed to run git");
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    fn init_repo() -> tempfile::TempDir {
+        let td = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let repo = td.path();
+        git(repo, &["init", "-b", "main"]);
+        git(repo, &["config", "user.name", "Test"]);
+        git(repo, &["config", "user.email", "test@test.com"]);
+        fs::write(repo.join("README.md"), "# Test\n").unwrap();
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-m", "Initial"]);
+        git(repo, &["tag", "-a", "v1.0", "-m", "Release"]);
+        git(repo, &["checkout", "-b", "secret"]);
+        fs::write(repo.join("secret.txt"), "hidden\n").unwrap();
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-m", "Secret"]);
+        git(repo, &["checkout", "main"]);
+        td
+    }
+
+    #[test]
+    fn test_clone_dir_name() {
+        assert_eq!(clone_dir_name("gitkyl"), "gitkyl.git");
+        assert_eq!(clone_dir_name("a/b c"), "a-b-c.git");
+        assert_eq!(clone_dir_name("..."), "repo.git");
+    }
+
+    #[test]
+    fn test_info_refs_lists_peeled_tags() {
+        // Arrange
+        let td = init_repo();
+        let repo = td.path();
+
+        // Act
+        let refs = PublishedRefs::resolve(repo, &["main"], &["v1.0"], "main")
+            .expect("Should resolve refs");
+
+        // Assert
+        let main = git(repo, &["rev-parse", "main"]);
+        let tag = git(repo, &["rev-parse", "v1.0"]);
+        assert_eq!(
+            refs.info_refs(),
+            format!(
+                "{main}\trefs/heads/main\n{tag}\trefs/tags/v1.0\n{main}\trefs/tags/v1.0^{{}}\n"
+            )
+        );
+        assert_eq!(refs.fingerprint().len(), 12);
+    }
+
+    #[test]
+    fn test_write_dumb_repository_packs_published_objects() {
+        // Arrange
+        let td = init_repo();
+        let repo = td.path();
+        let out = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let dir = out.path().join("test.git");
+        let refs = PublishedRefs::resolve(repo, &["main"], &["v1.0"], "main")
+            .expect("Should resolve refs");
+
+        // Act
+        let count = write_dumb_repository(repo, &refs, &dir).expect("Should write repository");
+
+        // Assert: commit, tree, blob and tag, but nothing from `secret`
+        assert_eq!(count, Some(4));
+        assert_eq!(
+            fs::read_to_string(dir.join("HEAD")).unwrap(),
+            "ref: refs/heads/main\n"
+        );
+        let packs = fs::read_to_string(dir.join("objects/info/packs")).unwrap();
+        let pack_name = packs
+            .strip_prefix("P ")
+            .and_then(|rest| rest.strip_suffix(".pack\n\n"))
+            .expect("Should list one pack");
+        let index = dir.join(format!("objects/pack/{}.idx", pack_name));
+        let verified = git(repo, &["verify-pack", "-v", index.to_str().unwrap()]);
+        assert!(verified.contains(&git(repo, &["rev-parse", "main^{tree}"])));
+        assert!(!verified.contains(&git(repo, &["rev-parse", "secret"])));
+    }
+
+    #[test]
+    fn test_write_dumb_repository_replaces_old_packs() {
+        // Arrange
+        let td = init_repo();
+        let repo = td.path();
+        let out = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let dir = out.path().join("test.git");
+        let old = PublishedRefs::resolve(repo, &["main"], &[], "main").unwrap();
+        let new = PublishedRefs::resolve(repo, &["main", "secret"], &[], "main").unwrap();
+
+        // Act
+        write_dumb_repository(repo, &old, &dir).unwrap();
+        write_dumb_repository(repo, &new, &dir).unwrap();
+
+        // Assert
+        let packs: Vec<_> = fs::read_dir(dir.join("objects/pack"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(packs.len(), 2, "One pack and its index: {:?}", packs);
+    }
+
+    #[test]
+    fn test_write_dumb_repository_skips_unchanged_refs() {
+        // Arrange
+        let td = init_repo();
+        let repo = td.path();
+        let out = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let dir = out.path().join("test.git");
+        let refs = PublishedRefs::resolve(repo, &["main"], &["v1.0"], "main").unwrap();
+        write_dumb_repository(repo, &refs, &dir).unwrap();
+
+        // Act
+        let unchanged = write_dumb_repository(repo, &refs, &dir).unwrap();
+        fs::remove_dir_all(dir.join("objects/pack")).unwrap();
+        let repaired = write_dumb_repository(repo, &refs, &dir).unwrap();
+
+        // Assert
+        assert_eq!(unchanged, None);
+        assert_eq!(repaired, Some(4));
+    }
+}
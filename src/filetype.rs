@@ -1,6 +1,7 @@
 //! File type detection for blob rendering.
 //!
-//! Provides four-phase file type classification:
+//! Provides four-phase file type classification, after first recognising
+//! Git LFS pointer files:
 //! 1. Image extension detection (fast path for known image types)
 //! 2. Image magic byte detection (reliable for extensionless files)
 //! 3. NUL byte heuristic (git's binary detection approach)
@@ -11,6 +12,15 @@ use std::path::Path;
 /// Maximum bytes to check for NUL byte heuristic (git uses 8KB).
 const BINARY_CHECK_LEN: usize = 8192;
 
+/// Git LFS pointer files are always smaller than 1KB.
+pub(crate) const LFS_POINTER_MAX_LEN: usize = 1024;
+
+/// Version lines accepted by git-lfs, including the pre-release spec URL.
+const LFS_VERSION_PREFIXES: [&str; 2] = [
+    "version https://git-lfs.github.com/spec/",
+    "version https://hawser.github.com/spec/",
+];
+
 /// File type classification for blob rendering
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FileType {
@@ -20,6 +30,95 @@ pub enum FileType {
     Image(ImageFormat),
     /// Binary file not suitable for text display
     Binary,
+    /// Git LFS pointer standing in for content stored outside the repository
+    LfsPointer,
+}
+
+/// Parsed Git LFS pointer file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LfsPointer {
+    oid: String,
+    size: u64,
+}
+
+impl LfsPointer {
+    /// Parses a Git LFS pointer from blob content.
+    ///
+    /// Accepts the pointer format written by git-lfs: a version line
+    /// followed by `oid sha256:<hex>` and `size <bytes>` lines. Content
+    /// larger than 1KB is never a pointer.
+    ///
+    /// # Returns
+    ///
+    /// Parsed pointer, or None if the content is not a valid LFS pointer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gitkyl::LfsPointer;
+    ///
+    /// let pointer = LfsPointer::parse(
+    ///     b"version https://git-lfs.github.com/spec/v1\n\
+    ///       oid sha256:4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393\n\
+    ///       size 12345\n",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(pointer.size(), 12345);
+    /// ```
+    pub fn parse(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() > LFS_POINTER_MAX_LEN {
+            return None;
+        }
+
+        let text = std::str::from_utf8(bytes).ok()?;
+        let mut lines = text.lines().map(str::trim);
+
+        let version = lines.next()?;
+        if !LFS_VERSION_PREFIXES
+            .iter()
+            .any(|prefix| version.starts_with(prefix))
+        {
+            return None;
+        }
+
+        let mut oid = None;
+        let mut size = None;
+        for line in lines {
+            if let Some(hash) = line.strip_prefix("oid sha256:") {
+                if hash.len() != 64 || !hash.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return None;
+                }
+                oid = Some(hash.to_ascii_lowercase());
+            } else if let Some(value) = line.strip_prefix("size ") {
+                size = Some(value.parse().ok()?);
+            }
+        }
+
+        Some(Self {
+            oid: oid?,
+            size: size?,
+        })
+    }
+
+    /// SHA-256 object ID as lowercase hex
+    pub fn oid(&self) -> &str {
+        &self.oid
+    }
+
+    /// Size of the real object in bytes
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Location of the object relative to the LFS object store.
+    ///
+    /// git-lfs shards objects by the first two byte pairs of the OID,
+    /// e.g. `4d/7a/4d7a2146...`.
+    pub fn object_path(&self) -> std::path::PathBuf {
+        Path::new(&self.oid[0..2])
+            .join(&self.oid[2..4])
+            .join(&self.oid)
+    }
 }
 
 /// Supported image formats for inline display
@@ -71,7 +170,9 @@ impl ImageFormat {
 
 /// Detects file type from path and content.
 ///
-/// Uses four-phase detection for reliability:
+/// Git LFS pointers are recognised first, so a pointer committed under an
+/// image extension is not mistaken for the image itself. Everything else
+/// uses four-phase detection for reliability:
 /// 1. Image extension lookup (fast path)
 /// 2. Image magic byte detection (handles extensionless files)
 /// 3. NUL byte heuristic (git's binary detection approach)
@@ -84,7 +185,7 @@ impl ImageFormat {
 ///
 /// # Returns
 ///
-/// Classified file type (Text, Image, or Binary)
+/// Classified file type (Text, Image, Binary, or LfsPointer)
 ///
 /// # Examples
 ///
@@ -102,6 +203,10 @@ impl ImageFormat {
 /// assert_eq!(detect_file_type(text, Path::new("test.txt")), FileType::Text);
 /// ```
 pub fn detect_file_type(bytes: &[u8], path: &Path) -> FileType {
+    if LfsPointer::parse(bytes).is_some() {
+        return FileType::LfsPointer;
+    }
+
     // Phase 1: Image detection by extension (fast path)
     if let Some(format) = detect_image_by_extension(path) {
         return FileType::Image(format);
@@ -650,6 +755,74 @@ mod tests {
         assert!(!is_svg_root_element(b"not xml at all"));
     }
 
+    const LFS_OID: &str = "4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393";
+
+    fn lfs_pointer_bytes(oid: &str, size: u64) -> Vec<u8> {
+        format!(
+            "version https://git-lfs.github.com/spec/v1\noid sha256:{}\nsize {}\n",
+            oid, size
+        )
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_detect_lfs_pointer() {
+        let pointer = lfs_pointer_bytes(LFS_OID, 12345);
+        assert_eq!(
+            detect_file_type(&pointer, Path::new("data.csv")),
+            FileType::LfsPointer
+        );
+    }
+
+    #[test]
+    fn test_detect_lfs_pointer_before_image_extension() {
+        let pointer = lfs_pointer_bytes(LFS_OID, 12345);
+        assert_eq!(
+            detect_file_type(&pointer, Path::new("logo.png")),
+            FileType::LfsPointer
+        );
+    }
+
+    #[test]
+    fn test_lfs_pointer_parse_fields() {
+        let pointer = LfsPointer::parse(&lfs_pointer_bytes(LFS_OID, 12345)).unwrap();
+        assert_eq!(pointer.oid(), LFS_OID);
+        assert_eq!(pointer.size(), 12345);
+        assert_eq!(
+            pointer.object_path(),
+            Path::new("4d").join("7a").join(LFS_OID)
+        );
+    }
+
+    #[test]
+    fn test_lfs_pointer_parse_legacy_version() {
+        let pointer = format!(
+            "version https://hawser.github.com/spec/v1\noid sha256:{}\nsize 1\n",
+            LFS_OID
+        );
+        assert!(LfsPointer::parse(pointer.as_bytes()).is_some());
+    }
+
+    #[test]
+    fn test_lfs_pointer_parse_rejects_invalid() {
+        assert!(LfsPointer::parse(b"version https://git-lfs.github.com/spec/v1\n").is_none());
+        assert!(LfsPointer::parse(&lfs_pointer_bytes("abc123", 1)).is_none());
+        assert!(
+            LfsPointer::parse(format!("oid sha256:{}\nsize 1\n", LFS_OID).as_bytes()).is_none()
+        );
+        assert!(
+            LfsPointer::parse(b"version https://git-lfs.github.com/spec/v1\noid sha256:\nsize x\n")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_lfs_pointer_parse_rejects_large_content() {
+        let mut content = lfs_pointer_bytes(LFS_OID, 1);
+        content.extend(std::iter::repeat_n(b'x', LFS_POINTER_MAX_LEN));
+        assert!(LfsPointer::parse(&content).is_none());
+    }
+
     #[test]
     fn test_image_format_mime_types() {
         assert_eq!(ImageFormat::Png.mime_type(), "image/png");
@@ -4,14 +4,43 @@ use anyhow::{Context, Result};
 use gix::bstr::{BString, ByteSlice};
 use std::path::Path;
 
+use crate::filetype::{LFS_POINTER_MAX_LEN, LfsPointer};
+use crate::graph::{GraphRow, RefLabels};
+use crate::history_limit::Truncation;
+use crate::mailmap::Mailmap;
+use crate::notes::{Note, Notes};
+use crate::semver::{Version, compare_tags};
+use crate::signature::{Signature, SignatureVerifier, split_tag_signature};
+use crate::trailers::{CO_AUTHORED_BY, Trailer, parse_trailers, split_message};
+
 /// File entry in repository tree.
 #[derive(Debug, Clone)]
 pub struct FileEntry {
     path: BString,
     oid: gix::ObjectId,
+    mode: gix::object::tree::EntryMode,
 }
 
 impl FileEntry {
+    /// Creates a file entry from its tree path, object ID and mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `path`: File path relative to repository root
+    /// * `oid`: Blob object ID
+    /// * `mode`: Git tree entry mode (regular, executable or symlink)
+    pub fn new(
+        path: impl Into<BString>,
+        oid: gix::ObjectId,
+        mode: gix::object::tree::EntryMode,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            oid,
+            mode,
+        }
+    }
+
     /// File path relative to repository root.
     ///
     /// Returns None if path contains platform-incompatible characters.
@@ -23,6 +52,50 @@ impl FileEntry {
     pub fn oid(&self) -> &gix::ObjectId {
         &self.oid
     }
+
+    /// Git tree entry mode.
+    pub fn mode(&self) -> gix::object::tree::EntryMode {
+        self.mode
+    }
+
+    /// Whether the entry is a symbolic link.
+    ///
+    /// The blob of a symlink holds the link target rather than file content.
+    pub fn is_symlink(&self) -> bool {
+        self.mode.is_link()
+    }
+
+    /// Whether the entry is a regular file with the executable bit set.
+    pub fn is_executable(&self) -> bool {
+        self.mode.is_executable()
+    }
+}
+
+/// Submodule (gitlink) entry in repository tree.
+#[derive(Debug, Clone)]
+pub struct SubmoduleEntry {
+    path: BString,
+    oid: gix::ObjectId,
+    url: Option<String>,
+}
+
+impl SubmoduleEntry {
+    /// Submodule path relative to repository root.
+    ///
+    /// Returns None if path contains platform-incompatible characters.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.to_path().ok()
+    }
+
+    /// Commit the submodule is pinned to.
+    pub fn oid(&self) -> &gix::ObjectId {
+        &self.oid
+    }
+
+    /// Remote URL from `.gitmodules`, if the submodule is declared there.
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
 }
 
 /// Repository metadata.
@@ -33,9 +106,43 @@ pub struct RepoInfo {
     branches: Vec<String>,
     commit_count: usize,
     owner: Option<String>,
+    mailmap: Mailmap,
+    signature_verifier: Option<SignatureVerifier>,
+    notes: Notes,
+    ref_labels: RefLabels,
 }
 
 impl RepoInfo {
+    /// Sets the mailmap used to normalize author identities.
+    pub fn with_mailmap(mut self, mailmap: Mailmap) -> Self {
+        self.mailmap = mailmap;
+        self
+    }
+
+    /// Sets the verifier used to check commit and tag signatures.
+    pub fn with_signature_verifier(mut self, verifier: SignatureVerifier) -> Self {
+        self.signature_verifier = Some(verifier);
+        self
+    }
+
+    /// Sets the git notes shown with commits.
+    pub fn with_notes(mut self, notes: Notes) -> Self {
+        self.notes = notes;
+        self
+    }
+
+    /// Sets the published branches.
+    pub fn with_branches(mut self, branches: Vec<String>) -> Self {
+        self.branches = branches;
+        self
+    }
+
+    /// Sets the branch and tag labels drawn in the commit graph.
+    pub fn with_ref_labels(mut self, ref_labels: RefLabels) -> Self {
+        self.ref_labels = ref_labels;
+        self
+    }
+
     /// Repository name.
     pub fn name(&self) -> &str {
         &self.name
@@ -60,6 +167,26 @@ impl RepoInfo {
     pub fn owner(&self) -> Option<&str> {
         self.owner.as_deref()
     }
+
+    /// Mailmap applied to author identities.
+    pub fn mailmap(&self) -> &Mailmap {
+        &self.mailmap
+    }
+
+    /// Verifier for commit and tag signatures, when allowed signers are configured.
+    pub fn signature_verifier(&self) -> Option<&SignatureVerifier> {
+        self.signature_verifier.as_ref()
+    }
+
+    /// Git notes of the repository.
+    pub fn notes(&self) -> &Notes {
+        &self.notes
+    }
+
+    /// Branch and tag labels of the repository.
+    pub fn ref_labels(&self) -> &RefLabels {
+        &self.ref_labels
+    }
 }
 
 /// Commit metadata.
@@ -71,9 +198,14 @@ pub struct CommitInfo {
     author_email: String,
     committer: String,
     co_authors: Vec<String>,
+    trailers: Vec<Trailer>,
+    parents: Vec<String>,
     date: i64,
+    utc_offset: i32,
     message: String,
     message_full: String,
+    signature: Option<Signature>,
+    notes: Vec<Note>,
 }
 
 impl CommitInfo {
@@ -105,7 +237,7 @@ impl CommitInfo {
         } else {
             oid.clone()
         };
-        let co_authors = parse_co_authors(&message_full);
+        let trailers = parse_trailers(&message_full, &Mailmap::default());
 
         Self {
             oid,
@@ -113,13 +245,38 @@ impl CommitInfo {
             author: author.clone(),
             author_email: String::new(),
             committer: author,
-            co_authors,
+            co_authors: collect_co_authors(&trailers),
+            trailers,
+            parents: Vec::new(),
             date,
+            utc_offset: 0,
             message,
             message_full,
+            signature: None,
+            notes: Vec::new(),
         }
     }
 
+    /// Sets the parent commit hashes.
+    ///
+    /// # Arguments
+    ///
+    /// * `parents`: Full parent hashes, first parent first
+    pub fn with_parents(mut self, parents: Vec<String>) -> Self {
+        self.parents = parents;
+        self
+    }
+
+    /// Sets the author's UTC offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `utc_offset`: Offset in seconds, positive east of UTC
+    pub fn with_utc_offset(mut self, utc_offset: i32) -> Self {
+        self.utc_offset = utc_offset;
+        self
+    }
+
     /// Full commit hash.
     pub fn oid(&self) -> &str {
         &self.oid
@@ -150,6 +307,11 @@ impl CommitInfo {
         self.date
     }
 
+    /// Author's UTC offset in seconds, positive east of UTC.
+    pub fn utc_offset(&self) -> i32 {
+        self.utc_offset
+    }
+
     /// First line of commit message.
     pub fn message(&self) -> &str {
         &self.message
@@ -160,31 +322,50 @@ impl CommitInfo {
         &self.message_full
     }
 
+    /// Commit message body without the subject line and trailer block.
+    pub fn body(&self) -> &str {
+        split_message(&self.message_full).0
+    }
+
     /// Co-authors from commit message trailers.
     pub fn co_authors(&self) -> &[String] {
         &self.co_authors
     }
+
+    /// Trailers of the commit message (`Signed-off-by`, `Fixes`, ...).
+    pub fn trailers(&self) -> &[Trailer] {
+        &self.trailers
+    }
+
+    /// Full parent hashes, first parent first.
+    pub fn parents(&self) -> &[String] {
+        &self.parents
+    }
+
+    /// Commit signature (signed commits only).
+    pub fn signature(&self) -> Option<&Signature> {
+        self.signature.as_ref()
+    }
+
+    /// Git notes attached to the commit.
+    pub fn notes(&self) -> &[Note] {
+        &self.notes
+    }
+
+    /// Replaces the notes attached to the commit.
+    pub(crate) fn set_notes(&mut self, notes: Vec<Note>) {
+        self.notes = notes;
+    }
 }
 
-/// Parses Co-authored-by trailers from commit message (case insensitive).
-fn parse_co_authors(message: &str) -> Vec<String> {
-    message
-        .lines()
-        .filter_map(|line| {
-            let line = line.trim();
-            let lower = line.to_lowercase();
-            if let Some(idx) = lower.find("co-authored-by:") {
-                let rest = &line[idx + "co-authored-by:".len()..];
-                let name = rest.trim();
-                if let Some(email_start) = name.find('<') {
-                    Some(name[..email_start].trim().to_string())
-                } else {
-                    Some(name.to_string())
-                }
-            } else {
-                None
-            }
-        })
+/// Collects co-author names from Co-authored-by trailers.
+///
+/// Values without an email are taken as the name itself.
+fn collect_co_authors(trailers: &[Trailer]) -> Vec<String> {
+    trailers
+        .iter()
+        .filter(|trailer| trailer.is(CO_AUTHORED_BY))
+        .map(|trailer| trailer.name().unwrap_or(trailer.value()).to_string())
         .collect()
 }
 
@@ -203,6 +384,10 @@ pub struct TagInfo {
     pub tagger: Option<String>,
     /// Tag creation date (Unix timestamp)
     pub date: Option<i64>,
+    /// Tag signature (signed annotated tags only)
+    pub signature: Option<Signature>,
+    /// Semantic version read from the tag name
+    pub version: Option<Version>,
 }
 
 impl TagInfo {
@@ -218,7 +403,7 @@ impl TagInfo {
     ///
     /// # Returns
     ///
-    /// A new TagInfo instance with derived short OID.
+    /// A new TagInfo instance with derived short OID and version.
     pub fn new(
         name: String,
         target_oid: String,
@@ -233,12 +418,14 @@ impl TagInfo {
         };
 
         Self {
+            version: Version::parse(&name),
             name,
             target_oid,
             short_oid,
             message,
             tagger,
             date,
+            signature: None,
         }
     }
 }
@@ -254,6 +441,10 @@ pub struct PaginatedCommits {
     pub per_page: usize,
     /// Whether more commits exist after this page
     pub has_more: bool,
+    /// Commit graph rows aligned with `commits`, empty when not drawn
+    pub graph: Vec<GraphRow>,
+    /// Why the history ends early, set on its last page
+    pub truncation: Option<Truncation>,
 }
 
 impl PaginatedCommits {
@@ -271,8 +462,104 @@ impl PaginatedCommits {
             page,
             per_page,
             has_more,
+            graph: Vec::new(),
+            truncation: None,
         }
     }
+
+    /// Attaches commit graph rows, one per commit on the page.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph`: Graph rows in the same order as the commits
+    pub fn with_graph(mut self, graph: Vec<GraphRow>) -> Self {
+        self.graph = graph;
+        self
+    }
+
+    /// Marks the page as the end of a truncated history.
+    pub fn with_truncation(mut self, truncation: Option<Truncation>) -> Self {
+        self.truncation = truncation;
+        self
+    }
+}
+
+/// Ancestry of a reference in topological order.
+///
+/// Walking history is the costly part of listing path commits and blaming
+/// files, so the order is computed once per ref and shared by every path.
+#[derive(Debug, Clone, Default)]
+pub struct CommitOrder {
+    commits: Vec<(gix::ObjectId, Vec<gix::ObjectId>)>,
+}
+
+impl CommitOrder {
+    /// Walks the history of a reference, children before parents.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_path`: Path to git repository
+    /// * `ref_name`: Reference name (branch/tag/commit), defaults to HEAD if None
+    ///
+    /// # Returns
+    ///
+    /// Commits reachable from the reference with their parents
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Repository cannot be opened
+    /// - Reference cannot be resolved
+    /// - Commit traversal fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gitkyl::CommitOrder;
+    /// use std::path::Path;
+    ///
+    /// let order = CommitOrder::load(Path::new("."), Some("main"))?;
+    /// println!("{} commits", order.len());
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn load(repo_path: impl AsRef<Path>, ref_name: Option<&str>) -> Result<Self> {
+        let repo = gix::open(repo_path.as_ref()).with_context(|| {
+            format!(
+                "Failed to open repository at {}",
+                repo_path.as_ref().display()
+            )
+        })?;
+
+        let tip = resolve_commit(&repo, ref_name)?.id;
+        Ok(Self {
+            commits: topological_order(&repo, tip)?,
+        })
+    }
+
+    /// Tip commit of the walk, None for an empty order.
+    pub(crate) fn tip(&self) -> Option<gix::ObjectId> {
+        self.commits.first().map(|(id, _)| *id)
+    }
+
+    /// Commits with their parents, children first.
+    pub(crate) fn commits(&self) -> &[(gix::ObjectId, Vec<gix::ObjectId>)] {
+        &self.commits
+    }
+
+    /// Full hashes of the commits, children first.
+    pub fn oids(&self) -> impl Iterator<Item = String> + '_ {
+        self.commits.iter().map(|(id, _)| id.to_string())
+    }
+
+    /// Number of commits in the walk.
+    pub fn len(&self) -> usize {
+        self.commits.len()
+    }
+
+    /// Returns true if the walk holds no commits.
+    pub fn is_empty(&self) -> bool {
+        self.commits.is_empty()
+    }
 }
 
 /// Represents an item in a directory tree view.
@@ -286,12 +573,33 @@ pub enum TreeItem {
         entry: FileEntry,
         commit: CommitInfo,
     },
+    /// Symbolic link with its last modifying commit
+    Symlink {
+        entry: FileEntry,
+        /// Link target as stored in the blob
+        target: String,
+        /// Target location when it resolves inside the repository tree
+        resolved: Option<crate::tree::SymlinkTarget>,
+        commit: CommitInfo,
+    },
     /// Directory with its most recent commit
     Directory {
         name: String,
         full_path: String,
         commit: CommitInfo,
     },
+    /// Submodule pinned to a commit of another repository
+    Submodule {
+        name: String,
+        full_path: String,
+        /// Full hash of the pinned commit
+        oid: String,
+        /// Remote URL parsed from `.gitmodules` at the same ref
+        url: Option<String>,
+        /// Whether a nested site was generated for the pinned commit
+        has_site: bool,
+        commit: CommitInfo,
+    },
 }
 
 /// Analyzes a git repository and extracts metadata.
@@ -357,11 +665,15 @@ pub fn analyze_repository(path: impl AsRef<Path>, owner: Option<String>) -> Resu
         branches,
         commit_count,
         owner,
+        mailmap: Mailmap::default(),
+        signature_verifier: None,
+        notes: Notes::default(),
+        ref_labels: RefLabels::default(),
     })
 }
 
 /// Resolves reference to commit object.
-fn resolve_commit<'a>(
+pub(crate) fn resolve_commit<'a>(
     repo: &'a gix::Repository,
     ref_name: Option<&str>,
 ) -> Result<gix::Commit<'a>> {
@@ -430,11 +742,207 @@ pub fn read_blob(
     Ok(blob.data.to_vec())
 }
 
+/// Reads a Git LFS object from the repository's local object store.
+///
+/// Objects live under `lfs/objects` in the common git directory, so linked
+/// worktrees and bare repositories share the same store. Objects whose size
+/// does not match the pointer are treated as missing, since a partial
+/// download would render as corrupt content.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `pointer`: LFS pointer read from the blob
+///
+/// # Returns
+///
+/// Object content, or None if it has not been fetched locally
+///
+/// # Errors
+///
+/// Returns error if the repository cannot be opened or the object exists
+/// but cannot be read
+pub fn read_lfs_object(
+    repo_path: impl AsRef<Path>,
+    pointer: &LfsPointer,
+) -> Result<Option<Vec<u8>>> {
+    let repo = gix::open(repo_path.as_ref()).with_context(|| {
+        format!(
+            "Failed to open repository at {}",
+            repo_path.as_ref().display()
+        )
+    })?;
+
+    let Some(object_path) = lfs_object_path(&repo, pointer) else {
+        return Ok(None);
+    };
+
+    let bytes = std::fs::read(&object_path)
+        .with_context(|| format!("Failed to read LFS object {}", object_path.display()))?;
+
+    Ok(Some(bytes))
+}
+
+/// Fingerprints the objects in the local Git LFS store.
+///
+/// The fingerprint changes whenever objects are fetched or pruned, which
+/// tells callers that pointers may have gained or lost their content
+/// without reading every blob.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+///
+/// # Returns
+///
+/// Short hash over object names and sizes, or an empty string when the
+/// store is missing or empty
+///
+/// # Errors
+///
+/// Returns error if the repository cannot be opened or the store cannot
+/// be read
+pub fn lfs_store_fingerprint(repo_path: impl AsRef<Path>) -> Result<String> {
+    let repo = gix::open(repo_path.as_ref()).with_context(|| {
+        format!(
+            "Failed to open repository at {}",
+            repo_path.as_ref().display()
+        )
+    })?;
+
+    // Objects are stored as lfs/objects/<xx>/<yy>/<oid>
+    let store = repo.common_dir().join("lfs").join("objects");
+    let mut objects = Vec::new();
+    let mut pending = vec![(store, 0)];
+    while let Some((dir, depth)) = pending.pop() {
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => {
+                return Err(e)
+                    .with_context(|| format!("Failed to read LFS store {}", dir.display()));
+            }
+        };
+        for entry in entries.flatten() {
+            let Ok(meta) = entry.metadata() else {
+                continue;
+            };
+            if depth < 2 && meta.is_dir() {
+                pending.push((entry.path(), depth + 1));
+            } else if depth == 2 && meta.is_file() {
+                objects.push(format!(
+                    "{} {}",
+                    entry.file_name().to_string_lossy(),
+                    meta.len()
+                ));
+            }
+        }
+    }
+
+    if objects.is_empty() {
+        return Ok(String::new());
+    }
+    objects.sort_unstable();
+
+    Ok(gix::objs::compute_hash(
+        gix::hash::Kind::Sha1,
+        gix::objs::Kind::Blob,
+        objects.join("\n").as_bytes(),
+    )
+    .to_hex_with_len(12)
+    .to_string())
+}
+
+/// Locates a fetched Git LFS object whose size matches its pointer.
+fn lfs_object_path(repo: &gix::Repository, pointer: &LfsPointer) -> Option<std::path::PathBuf> {
+    let object_path = repo
+        .common_dir()
+        .join("lfs")
+        .join("objects")
+        .join(pointer.object_path());
+
+    match std::fs::metadata(&object_path) {
+        Ok(meta) if meta.is_file() && meta.len() == pointer.size() => Some(object_path),
+        _ => None,
+    }
+}
+
+/// Finds the Git LFS pointers among blobs and whether their objects are local.
+///
+/// The repository is opened once for the whole batch, and blobs too large
+/// to be pointers are recognized from their object header alone. Without
+/// an LFS store no pointer can resolve, so no blob is read at all.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `blobs`: Blob object IDs to check
+///
+/// # Returns
+///
+/// Map from each pointer blob to whether `read_lfs_object` would find its
+/// object; other blobs are left out, and the map is empty when the
+/// repository has no LFS store
+///
+/// # Errors
+///
+/// Returns error if the repository cannot be opened or a blob cannot be
+/// read
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::{lfs_object_states, list_files};
+/// use std::path::Path;
+///
+/// let files = list_files(Path::new("."), None)?;
+/// let states = lfs_object_states(Path::new("."), files.iter().map(|f| f.oid().as_ref()))?;
+/// let missing = states.values().filter(|fetched| !**fetched).count();
+/// println!("{} LFS objects not fetched", missing);
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn lfs_object_states<'a>(
+    repo_path: impl AsRef<Path>,
+    blobs: impl IntoIterator<Item = &'a gix::oid>,
+) -> Result<std::collections::HashMap<gix::ObjectId, bool>> {
+    let repo = gix::open(repo_path.as_ref()).with_context(|| {
+        format!(
+            "Failed to open repository at {}",
+            repo_path.as_ref().display()
+        )
+    })?;
+
+    let mut states = std::collections::HashMap::new();
+    if !repo.common_dir().join("lfs").join("objects").is_dir() {
+        return Ok(states);
+    }
+
+    for oid in blobs {
+        let header = repo
+            .find_header(oid)
+            .with_context(|| format!("Failed to read object header {}", oid))?;
+        if header.size() > LFS_POINTER_MAX_LEN as u64 {
+            continue;
+        }
+
+        let object = repo
+            .find_object(oid)
+            .with_context(|| format!("Failed to read blob {}", oid))?;
+        if let Some(pointer) = LfsPointer::parse(&object.data) {
+            states.insert(oid.to_owned(), lfs_object_path(&repo, &pointer).is_some());
+        }
+    }
+
+    Ok(states)
+}
+
 /// Lists all files in repository at given reference.
 ///
 /// Traverses the tree at the specified reference using breadth-first order,
-/// returning all blob entries (regular files). Excludes directories, symlinks,
-/// and submodules.
+/// returning regular files, executables and symlinks. Symlinks are listed
+/// as entries of their own, with the link target as blob content, and are
+/// not followed; `FileTree::resolve_symlink` resolves targets inside the
+/// tree. Directories and submodules are excluded.
 ///
 /// # Arguments
 ///
@@ -443,7 +951,8 @@ pub fn read_blob(
 ///
 /// # Returns
 ///
-/// Vector of FileEntry containing path and object ID for each blob
+/// Vector of FileEntry containing path, object ID and mode for each file
+/// and symlink
 ///
 /// # Errors
 ///
@@ -485,56 +994,54 @@ pub fn list_files(repo_path: impl AsRef<Path>, ref_name: Option<&str>) -> Result
         .files()
         .context("Failed to traverse tree")?
         .into_iter()
-        .filter(|entry| entry.mode.is_blob())
+        .filter(|entry| entry.mode.is_blob_or_symlink())
         .map(|entry| FileEntry {
             path: entry.filepath,
             oid: entry.oid,
+            mode: entry.mode,
         })
         .collect();
 
     Ok(files)
 }
 
-/// Lists commits for a given reference in reverse chronological order.
+/// Lists submodules (gitlink entries) in repository tree at given reference.
 ///
-/// Traverses commit history from the specified reference, extracting metadata
-/// for each commit including hash, author, date, and message. Returns commits
-/// in reverse chronological order (newest first).
+/// Submodule URLs are read from the `.gitmodules` file of the same tree,
+/// matched by path. Gitlinks without a `.gitmodules` entry are still listed
+/// with no URL.
 ///
 /// # Arguments
 ///
 /// * `repo_path`: Path to git repository
 /// * `ref_name`: Reference name (branch/tag/commit), defaults to HEAD if None
-/// * `limit`: Optional limit on number of commits to retrieve
 ///
 /// # Returns
 ///
-/// Vector of CommitInfo structs with metadata for each commit
+/// Vector of SubmoduleEntry with path, pinned commit and URL
 ///
 /// # Errors
 ///
 /// Returns error if:
 /// - Repository cannot be opened
 /// - Reference cannot be resolved
-/// - Commit traversal fails
+/// - Tree cannot be traversed
 ///
 /// # Examples
 ///
 /// ```no_run
-/// use gitkyl::list_commits;
+/// use gitkyl::list_submodules;
 /// use std::path::Path;
 ///
-/// let commits = list_commits(Path::new("."), None, Some(10))?;
-/// for commit in commits {
-///     println!("{}: {}", commit.short_oid(), commit.message());
+/// for submodule in list_submodules(Path::new("."), None)? {
+///     println!("{:?} @ {}", submodule.path(), submodule.oid());
 /// }
 /// # Ok::<(), anyhow::Error>(())
 /// ```
-pub fn list_commits(
+pub fn list_submodules(
     repo_path: impl AsRef<Path>,
     ref_name: Option<&str>,
-    limit: Option<usize>,
-) -> Result<Vec<CommitInfo>> {
+) -> Result<Vec<SubmoduleEntry>> {
     let repo = gix::open(repo_path.as_ref()).with_context(|| {
         format!(
             "Failed to open repository at {}",
@@ -544,44 +1051,334 @@ pub fn list_commits(
 
     let commit = resolve_commit(&repo, ref_name)?;
 
-    let mut commits = Vec::new();
-    let walker = commit
-        .ancestors()
-        .all()
-        .context("Failed to create commit ancestor iterator")?;
-
-    for (idx, result) in walker.enumerate() {
-        if let Some(max) = limit
-            && idx >= max
-        {
-            break;
-        }
+    let mut tree = commit.tree().context("Failed to read commit tree")?;
 
-        let commit_info = result.context("Failed to traverse commit ancestor")?;
-        let commit_obj = commit_info
-            .object()
-            .context("Failed to read commit object")?;
+    let gitlinks: Vec<_> = tree
+        .traverse()
+        .breadthfirst
+        .files()
+        .context("Failed to traverse tree")?
+        .into_iter()
+        .filter(|entry| entry.mode.is_commit())
+        .collect();
 
-        let author = commit_obj.author().context("Failed to read author")?;
-        let committer = commit_obj.committer().context("Failed to read committer")?;
-        let message_bytes = commit_obj
-            .message_raw()
-            .context("Failed to read commit message")?;
-        let message_full = message_bytes.to_str_lossy().to_string();
-        let first_line = message_full.lines().next().unwrap_or("").to_string();
-        let co_authors = parse_co_authors(&message_full);
-
-        commits.push(CommitInfo {
-            oid: commit_obj.id.to_hex().to_string(),
-            short_oid: commit_obj.id.to_hex_with_len(7).to_string(),
-            author: author.name.to_str_lossy().to_string(),
-            author_email: author.email.to_str_lossy().to_string(),
-            committer: committer.name.to_str_lossy().to_string(),
-            co_authors,
-            date: author.time.seconds,
-            message: first_line,
-            message_full,
-        });
+    if gitlinks.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let urls = match tree
+        .peel_to_entry_by_path(".gitmodules")
+        .context("Failed to look up .gitmodules")?
+    {
+        Some(entry) => {
+            let blob = entry.object().context("Failed to read .gitmodules")?;
+            parse_gitmodules(&blob.data.to_str_lossy())
+        }
+        None => std::collections::HashMap::new(),
+    };
+
+    Ok(gitlinks
+        .into_iter()
+        .map(|entry| SubmoduleEntry {
+            url: urls.get(entry.filepath.to_str_lossy().as_ref()).cloned(),
+            path: entry.filepath,
+            oid: entry.oid,
+        })
+        .collect())
+}
+
+/// Maps submodule paths to URLs from `.gitmodules` content.
+///
+/// Malformed files yield an empty map rather than an error, since a broken
+/// `.gitmodules` should not prevent the tree from being listed.
+fn parse_gitmodules(content: &str) -> std::collections::HashMap<String, String> {
+    use std::str::FromStr;
+
+    let Ok(config) = gix::config::File::from_str(content) else {
+        return std::collections::HashMap::new();
+    };
+
+    config
+        .sections_by_name("submodule")
+        .into_iter()
+        .flatten()
+        .filter_map(|section| {
+            let path = section.value("path")?;
+            let url = section.value("url")?;
+            Some((
+                path.to_str_lossy().into_owned(),
+                url.to_str_lossy().into_owned(),
+            ))
+        })
+        .collect()
+}
+
+/// Creates a bare repository holding one commit of another repository.
+///
+/// The new repository borrows the source's objects through
+/// `objects/info/alternates` rather than copying them, and has a single
+/// branch named after the commit's short hash, checked out as HEAD. Sites
+/// generated from it show that commit alone, which is how submodules are
+/// rendered at the commit a superproject pins.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to the source repository
+/// * `oid`: Commit to pin
+/// * `dest`: Directory for the new repository, created if missing
+///
+/// # Returns
+///
+/// Name of the branch pointing at the commit
+///
+/// # Errors
+///
+/// Returns error if:
+/// - Source repository cannot be opened
+/// - Commit has not been fetched into the source repository
+/// - New repository or its references cannot be written
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::{list_submodules, pin_commit};
+/// use std::path::Path;
+///
+/// for submodule in list_submodules(Path::new("."), None)? {
+///     if let Some(path) = submodule.path() {
+///         let dest = Path::new("/tmp/pinned").join(path);
+///         let branch = pin_commit(path, submodule.oid(), &dest)?;
+///         println!("{} pinned as {}", dest.display(), branch);
+///     }
+/// }
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn pin_commit(
+    repo_path: impl AsRef<Path>,
+    oid: &gix::oid,
+    dest: impl AsRef<Path>,
+) -> Result<String> {
+    use gix::refs::transaction::{Change, LogChange, PreviousValue, RefEdit};
+    use gix::refs::{FullName, Target};
+
+    let repo_path = repo_path.as_ref();
+    let dest = dest.as_ref();
+    let source = gix::open(repo_path)
+        .with_context(|| format!("Failed to open repository at {}", repo_path.display()))?;
+    if !source.has_object(oid) {
+        anyhow::bail!("Commit {} is not in {}", oid, repo_path.display());
+    }
+    let objects = source
+        .common_dir()
+        .join("objects")
+        .canonicalize()
+        .context("Failed to locate source objects")?;
+
+    let pinned = gix::init_bare(dest)
+        .with_context(|| format!("Failed to create repository at {}", dest.display()))?;
+    let info_dir = pinned.git_dir().join("objects").join("info");
+    std::fs::create_dir_all(&info_dir).context("Failed to create objects info directory")?;
+    std::fs::write(
+        info_dir.join("alternates"),
+        format!("{}\n", objects.display()),
+    )
+    .context("Failed to write alternates")?;
+
+    let branch = oid.to_hex_with_len(7).to_string();
+    let branch_ref: FullName = format!("refs/heads/{}", branch)
+        .try_into()
+        .context("Invalid branch name")?;
+    let update = |name: FullName, expected, new| RefEdit {
+        change: Change::Update {
+            log: LogChange::default(),
+            expected,
+            new,
+        },
+        name,
+        deref: false,
+    };
+    pinned
+        .edit_references([
+            update(
+                branch_ref.clone(),
+                PreviousValue::MustNotExist,
+                Target::Peeled(oid.to_owned()),
+            ),
+            update(
+                "HEAD".try_into().context("Invalid HEAD name")?,
+                PreviousValue::Any,
+                Target::Symbolic(branch_ref),
+            ),
+        ])
+        .context("Failed to write pinned references")?;
+
+    Ok(branch)
+}
+
+/// Lists all commits of a reference in topological order.
+///
+/// Like `git log --topo-order`, no parent is listed before all of its
+/// children and lines of history are not intermixed, which keeps the
+/// commit graph readable.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `ref_name`: Reference name (branch/tag/commit), defaults to HEAD if None
+/// * `mailmap`: Mailmap applied to commit identities
+///
+/// # Returns
+///
+/// Vector of CommitInfo structs, children before parents
+///
+/// # Errors
+///
+/// Returns error if:
+/// - Repository cannot be opened
+/// - Reference cannot be resolved
+/// - Commit traversal fails
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::{Mailmap, list_commits_topo};
+/// use std::path::Path;
+///
+/// let commits = list_commits_topo(Path::new("."), Some("main"), &Mailmap::default())?;
+/// println!("{} commits", commits.len());
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn list_commits_topo(
+    repo_path: impl AsRef<Path>,
+    ref_name: Option<&str>,
+    mailmap: &Mailmap,
+) -> Result<Vec<CommitInfo>> {
+    let repo = gix::open(repo_path.as_ref()).with_context(|| {
+        format!(
+            "Failed to open repository at {}",
+            repo_path.as_ref().display()
+        )
+    })?;
+
+    let tip = resolve_commit(&repo, ref_name)?.id;
+
+    let mut commits = Vec::new();
+    for (id, _) in topological_order(&repo, tip)? {
+        let commit = repo
+            .find_object(id)
+            .context("Failed to read commit object")?
+            .try_into_commit()
+            .context("Object is not a commit")?;
+        commits.push(extract_commit_info(&commit, mailmap)?);
+    }
+
+    Ok(commits)
+}
+
+/// Lists commits for a given reference in reverse chronological order.
+///
+/// Traverses commit history from the specified reference, extracting metadata
+/// for each commit including hash, author, date, and message. Returns commits
+/// in reverse chronological order (newest first).
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `ref_name`: Reference name (branch/tag/commit), defaults to HEAD if None
+/// * `limit`: Optional limit on number of commits to retrieve
+///
+/// # Returns
+///
+/// Vector of CommitInfo structs with metadata for each commit
+///
+/// # Errors
+///
+/// Returns error if:
+/// - Repository cannot be opened
+/// - Reference cannot be resolved
+/// - Commit traversal fails
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::list_commits;
+/// use std::path::Path;
+///
+/// let commits = list_commits(Path::new("."), None, Some(10))?;
+/// for commit in commits {
+///     println!("{}: {}", commit.short_oid(), commit.message());
+/// }
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn list_commits(
+    repo_path: impl AsRef<Path>,
+    ref_name: Option<&str>,
+    limit: Option<usize>,
+) -> Result<Vec<CommitInfo>> {
+    list_commits_with_mailmap(repo_path, ref_name, limit, &Mailmap::default())
+}
+
+/// Same as [`list_commits`], with author and committer identities resolved
+/// through a mailmap.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `ref_name`: Reference name (branch/tag/commit), defaults to HEAD if None
+/// * `limit`: Optional limit on number of commits to retrieve
+/// * `mailmap`: Mailmap applied to commit identities
+///
+/// # Errors
+///
+/// Returns the same errors as [`list_commits`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::{Mailmap, list_commits_with_mailmap};
+/// use std::path::Path;
+///
+/// let mailmap = Mailmap::load(Path::new("."), None)?;
+/// let commits = list_commits_with_mailmap(Path::new("."), None, Some(10), &mailmap)?;
+/// for commit in commits {
+///     println!("{}: {}", commit.short_oid(), commit.message());
+/// }
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn list_commits_with_mailmap(
+    repo_path: impl AsRef<Path>,
+    ref_name: Option<&str>,
+    limit: Option<usize>,
+    mailmap: &Mailmap,
+) -> Result<Vec<CommitInfo>> {
+    let repo = gix::open(repo_path.as_ref()).with_context(|| {
+        format!(
+            "Failed to open repository at {}",
+            repo_path.as_ref().display()
+        )
+    })?;
+
+    let commit = resolve_commit(&repo, ref_name)?;
+
+    let mut commits = Vec::new();
+    let walker = commit
+        .ancestors()
+        .all()
+        .context("Failed to create commit ancestor iterator")?;
+
+    for (idx, result) in walker.enumerate() {
+        if let Some(max) = limit
+            && idx >= max
+        {
+            break;
+        }
+
+        let commit_info = result.context("Failed to traverse commit ancestor")?;
+        let commit_obj = commit_info
+            .object()
+            .context("Failed to read commit object")?;
+
+        commits.push(extract_commit_info(&commit_obj, mailmap)?);
     }
 
     Ok(commits)
@@ -629,6 +1426,45 @@ pub fn list_commits_paginated(
     ref_name: Option<&str>,
     page: usize,
     per_page: usize,
+) -> Result<PaginatedCommits> {
+    list_commits_paginated_with_mailmap(repo_path, ref_name, page, per_page, &Mailmap::default())
+}
+
+/// Same as [`list_commits_paginated`], with author and committer identities
+/// resolved through a mailmap.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `ref_name`: Reference to start from (branch/tag, None for HEAD)
+/// * `page`: Page number (1-indexed, must be >= 1)
+/// * `per_page`: Commits per page (must be >= 1)
+/// * `mailmap`: Mailmap applied to commit identities
+///
+/// # Errors
+///
+/// Returns the same errors as [`list_commits_paginated`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::{Mailmap, list_commits_paginated_with_mailmap};
+/// use std::path::Path;
+///
+/// let mailmap = Mailmap::load(Path::new("."), None)?;
+/// let page1 = list_commits_paginated_with_mailmap(Path::new("."), None, 1, 35, &mailmap)?;
+/// println!("Page {}: {} commits", page1.page, page1.commits.len());
+/// if page1.has_more {
+///     let page2 = list_commits_paginated_with_mailmap(Path::new("."), None, 2, 35, &mailmap)?;
+/// }
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn list_commits_paginated_with_mailmap(
+    repo_path: impl AsRef<Path>,
+    ref_name: Option<&str>,
+    page: usize,
+    per_page: usize,
+    mailmap: &Mailmap,
 ) -> Result<PaginatedCommits> {
     // Validate pagination parameters
     if page == 0 {
@@ -663,26 +1499,7 @@ pub fn list_commits_paginated(
             .object()
             .context("Failed to read commit object")?;
 
-        let author = commit_obj.author().context("Failed to read author")?;
-        let committer = commit_obj.committer().context("Failed to read committer")?;
-        let message_bytes = commit_obj
-            .message_raw()
-            .context("Failed to read commit message")?;
-        let message_full = message_bytes.to_str_lossy().to_string();
-        let first_line = message_full.lines().next().unwrap_or("").to_string();
-        let co_authors = parse_co_authors(&message_full);
-
-        commits.push(CommitInfo {
-            oid: commit_obj.id.to_hex().to_string(),
-            short_oid: commit_obj.id.to_hex_with_len(7).to_string(),
-            author: author.name.to_str_lossy().to_string(),
-            author_email: author.email.to_str_lossy().to_string(),
-            committer: committer.name.to_str_lossy().to_string(),
-            co_authors,
-            date: author.time.seconds,
-            message: first_line,
-            message_full,
-        });
+        commits.push(extract_commit_info(&commit_obj, mailmap)?);
     }
 
     // Detect if more pages exist
@@ -729,6 +1546,37 @@ pub fn list_commits_paginated(
 /// # Ok::<(), anyhow::Error>(())
 /// ```
 pub fn list_tags(repo_path: impl AsRef<Path>) -> Result<Vec<TagInfo>> {
+    list_tags_with_mailmap(repo_path, &Mailmap::default())
+}
+
+/// Same as [`list_tags`], with tagger identities resolved through a mailmap.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `mailmap`: Mailmap applied to tagger identities
+///
+/// # Errors
+///
+/// Returns the same errors as [`list_tags`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::{Mailmap, list_tags_with_mailmap};
+/// use std::path::Path;
+///
+/// let mailmap = Mailmap::load(Path::new("."), None)?;
+/// let tags = list_tags_with_mailmap(Path::new("."), &mailmap)?;
+/// for tag in tags {
+///     println!("{}: {}", tag.name, tag.short_oid);
+/// }
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn list_tags_with_mailmap(
+    repo_path: impl AsRef<Path>,
+    mailmap: &Mailmap,
+) -> Result<Vec<TagInfo>> {
     let repo = gix::open(repo_path.as_ref()).with_context(|| {
         format!(
             "Failed to open repository at {}",
@@ -785,116 +1633,382 @@ pub fn list_tags(repo_path: impl AsRef<Path>) -> Result<Vec<TagInfo>> {
             .context("Failed to find tag object")?;
 
         // Check if this is an annotated tag
-        let (message, tagger, date) = if let Ok(tag_obj) = tag_object.try_into_tag() {
+        let (message, tagger, date, signature) = if let Ok(tag_obj) = tag_object.try_into_tag() {
             // Annotated tag: decode the tag data
             let decoded = tag_obj.decode().context("Failed to decode tag object")?;
 
-            let tag_message = if decoded.message.is_empty() {
+            // gix only separates PGP signatures, so SSH ones are cut from the message here
+            let signature =
+                split_tag_signature(&tag_obj.data).and_then(|(_, armor)| Signature::parse(armor));
+            let message = match split_tag_signature(decoded.message) {
+                Some((message, _)) => message,
+                None => decoded.message.as_bytes(),
+            };
+
+            let tag_message = if message.trim().is_empty() {
                 None
             } else {
-                Some(decoded.message.to_str_lossy().to_string())
+                Some(message.to_str_lossy().to_string())
             };
 
-            let tagger_info = decoded
-                .tagger
-                .as_ref()
-                .map(|t| format!("{} <{}>", t.name.to_str_lossy(), t.email.to_str_lossy()));
+            let tagger_info = decoded.tagger.as_ref().map(|t| {
+                let (name, email) =
+                    mailmap.resolve(&t.name.to_str_lossy(), &t.email.to_str_lossy());
+                format!("{} <{}>", name, email)
+            });
 
             let tag_date = decoded.tagger.as_ref().map(|t| t.time.seconds);
 
-            (tag_message, tagger_info, tag_date)
+            (tag_message, tagger_info, tag_date, signature)
         } else {
             // Lightweight tag: use commit date for sorting
             let commit_date = target_commit.committer().ok().map(|c| c.time.seconds);
-            (None, None, commit_date)
+            (None, None, commit_date, None)
+        };
+
+        let mut tag = TagInfo::new(tag_name, target_oid, message, tagger, date);
+        tag.signature = signature;
+        tags.push(tag);
+    }
+
+    tags.sort_by(compare_tags);
+
+    Ok(tags)
+}
+
+/// Verifies the SSH signatures of commits against allowed signers.
+///
+/// Commits are reloaded to recover the signed payload. Unsigned commits and
+/// other signature formats keep their state.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `commits`: Commits whose signatures should be verified in place
+/// * `verifier`: Verifier holding the allowed signers
+///
+/// # Errors
+///
+/// Returns error if the repository cannot be opened, a commit cannot be
+/// read, or ssh-keygen cannot be run
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::{SignatureVerifier, list_commits, verify_commit_signatures};
+/// use std::path::Path;
+///
+/// let verifier = SignatureVerifier::new(Path::new("allowed_signers"))?;
+/// let mut commits = list_commits(Path::new("."), None, Some(10))?;
+/// verify_commit_signatures(Path::new("."), &mut commits, &verifier)?;
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn verify_commit_signatures(
+    repo_path: impl AsRef<Path>,
+    commits: &mut [CommitInfo],
+    verifier: &SignatureVerifier,
+) -> Result<()> {
+    if commits.iter().all(|c| c.signature.is_none()) {
+        return Ok(());
+    }
+
+    let repo = gix::open(repo_path.as_ref()).with_context(|| {
+        format!(
+            "Failed to open repository at {}",
+            repo_path.as_ref().display()
+        )
+    })?;
+
+    for info in commits.iter_mut() {
+        let Some(signature) = info.signature.as_mut() else {
+            continue;
         };
 
-        tags.push(TagInfo::new(tag_name, target_oid, message, tagger, date));
+        let id = gix::ObjectId::from_hex(info.oid.as_bytes())
+            .with_context(|| format!("Invalid commit hash: {}", info.oid))?;
+        let commit = repo
+            .find_object(id)
+            .with_context(|| format!("Failed to find commit: {}", info.oid))?
+            .try_into_commit()
+            .map_err(|_| anyhow::anyhow!("Object '{}' is not a commit", info.oid))?;
+
+        if let Some((armor, signed_data)) = commit
+            .signature()
+            .context("Failed to read commit signature")?
+        {
+            let payload = BString::from(signed_data);
+            signature.set_verification(verifier.verify(&armor.to_str_lossy(), &payload)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Verifies the SSH signatures of annotated tags against allowed signers.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `tags`: Tags whose signatures should be verified in place
+/// * `verifier`: Verifier holding the allowed signers
+///
+/// # Errors
+///
+/// Returns error if the repository cannot be opened, a tag object cannot be
+/// read, or ssh-keygen cannot be run
+pub fn verify_tag_signatures(
+    repo_path: impl AsRef<Path>,
+    tags: &mut [TagInfo],
+    verifier: &SignatureVerifier,
+) -> Result<()> {
+    if tags.iter().all(|t| t.signature.is_none()) {
+        return Ok(());
+    }
+
+    let repo = gix::open(repo_path.as_ref()).with_context(|| {
+        format!(
+            "Failed to open repository at {}",
+            repo_path.as_ref().display()
+        )
+    })?;
+
+    for tag in tags.iter_mut() {
+        let Some(signature) = tag.signature.as_mut() else {
+            continue;
+        };
+
+        let object = repo
+            .find_reference(format!("refs/tags/{}", tag.name).as_str())
+            .with_context(|| format!("Failed to find tag reference: {}", tag.name))?
+            .id()
+            .object()
+            .with_context(|| format!("Failed to find tag object: {}", tag.name))?;
+
+        if let Some((payload, armor)) = split_tag_signature(&object.data) {
+            signature.set_verification(verifier.verify(armor, payload)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Extracts CommitInfo from gix commit object.
+///
+/// Author and committer identities are normalized through the mailmap.
+pub(crate) fn extract_commit_info(commit: &gix::Commit, mailmap: &Mailmap) -> Result<CommitInfo> {
+    let author = commit.author().context("Failed to read author")?;
+    let committer = commit.committer().context("Failed to read committer")?;
+    let message_bytes = commit
+        .message_raw()
+        .context("Failed to read commit message")?;
+    let message_full = message_bytes.to_str_lossy().to_string();
+    let first_line = message_full.lines().next().unwrap_or("").to_string();
+    let trailers = parse_trailers(&message_full, mailmap);
+    let signature = commit
+        .signature()
+        .context("Failed to read commit signature")?
+        .and_then(|(armor, _)| Signature::parse(&armor.to_str_lossy()));
+
+    let (author_name, author_email) =
+        mailmap.resolve(&author.name.to_str_lossy(), &author.email.to_str_lossy());
+    let (committer_name, _) = mailmap.resolve(
+        &committer.name.to_str_lossy(),
+        &committer.email.to_str_lossy(),
+    );
+
+    Ok(CommitInfo {
+        oid: commit.id.to_hex().to_string(),
+        short_oid: commit.id.to_hex_with_len(7).to_string(),
+        author: author_name,
+        author_email,
+        committer: committer_name,
+        co_authors: collect_co_authors(&trailers),
+        trailers,
+        parents: commit.parent_ids().map(|id| id.to_string()).collect(),
+        date: author.time.seconds,
+        utc_offset: author.time.offset,
+        message: first_line,
+        message_full,
+        signature,
+        notes: Vec::new(),
+    })
+}
+
+/// Batch lookup last commits for multiple files in single history walk.
+///
+/// Performs single history walk to find most recent commit that modified each
+/// path. Paths may name files or directories; see `get_last_commits_tree`
+/// for how changes are detected.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `ref_name`: Reference name (branch/tag/commit), defaults to HEAD if None
+/// * `file_paths`: Slice of file or directory paths to lookup
+///
+/// # Returns
+///
+/// HashMap mapping file paths to their last CommitInfo
+///
+/// # Errors
+///
+/// Returns error if repository access or commit traversal fails
+///
+/// # Performance
+///
+/// Complexity: O(m × c) where m = commits walked, c = changes per commit
+///
+/// Single repository open and history walk with early exit when all files found
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::get_last_commits_batch;
+/// use std::path::Path;
+///
+/// let paths = &["src/lib.rs", "src/git.rs", "Cargo.toml"];
+/// let commits = get_last_commits_batch(Path::new("."), None, paths)?;
+/// for (path, commit) in commits {
+///     println!("{}: {}", path, commit.short_oid());
+/// }
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn get_last_commits_batch(
+    repo_path: impl AsRef<Path>,
+    ref_name: Option<&str>,
+    file_paths: &[&str],
+) -> Result<std::collections::HashMap<String, CommitInfo>> {
+    get_last_commits_batch_with_mailmap(repo_path, ref_name, file_paths, &Mailmap::default())
+}
+
+/// Same as [`get_last_commits_batch`], with author and committer identities
+/// resolved through a mailmap.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `ref_name`: Reference name (branch/tag/commit), defaults to HEAD if None
+/// * `file_paths`: Slice of file or directory paths to lookup
+/// * `mailmap`: Mailmap applied to commit identities
+///
+/// # Errors
+///
+/// Returns the same errors as [`get_last_commits_batch`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::{Mailmap, get_last_commits_batch_with_mailmap};
+/// use std::path::Path;
+///
+/// let mailmap = Mailmap::load(Path::new("."), None)?;
+/// let paths = &["src/lib.rs", "src/git.rs", "Cargo.toml"];
+/// let commits = get_last_commits_batch_with_mailmap(Path::new("."), None, paths, &mailmap)?;
+/// for (path, commit) in commits {
+///     println!("{}: {}", path, commit.short_oid());
+/// }
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn get_last_commits_batch_with_mailmap(
+    repo_path: impl AsRef<Path>,
+    ref_name: Option<&str>,
+    file_paths: &[&str],
+    mailmap: &Mailmap,
+) -> Result<std::collections::HashMap<String, CommitInfo>> {
+    use std::collections::HashMap;
+
+    if file_paths.is_empty() {
+        return Ok(HashMap::new());
     }
 
-    // Sort by date for annotated tags, then by name
-    tags.sort_by(|a, b| match (a.date, b.date) {
-        (Some(date_a), Some(date_b)) => date_b.cmp(&date_a),
-        (Some(_), None) => std::cmp::Ordering::Less,
-        (None, Some(_)) => std::cmp::Ordering::Greater,
-        (None, None) => a.name.cmp(&b.name),
-    });
-
-    Ok(tags)
-}
+    let repo = gix::open(repo_path.as_ref()).with_context(|| {
+        format!(
+            "Failed to open repository at {}",
+            repo_path.as_ref().display()
+        )
+    })?;
 
-/// Extracts CommitInfo from gix commit object.
-fn extract_commit_info(commit: &gix::Commit) -> Result<CommitInfo> {
-    let author = commit.author().context("Failed to read author")?;
-    let committer = commit.committer().context("Failed to read committer")?;
-    let message_bytes = commit
-        .message_raw()
-        .context("Failed to read commit message")?;
-    let message_full = message_bytes.to_str_lossy().to_string();
-    let first_line = message_full.lines().next().unwrap_or("").to_string();
-    let co_authors = parse_co_authors(&message_full);
+    let commit = resolve_commit(&repo, ref_name)?;
+    let remaining = file_paths.iter().map(|s| s.to_string()).collect();
 
-    Ok(CommitInfo {
-        oid: commit.id.to_hex().to_string(),
-        short_oid: commit.id.to_hex_with_len(7).to_string(),
-        author: author.name.to_str_lossy().to_string(),
-        author_email: author.email.to_str_lossy().to_string(),
-        committer: committer.name.to_str_lossy().to_string(),
-        co_authors,
-        date: author.time.seconds,
-        message: first_line,
-        message_full,
-    })
+    walk_last_commits(&repo, &commit, remaining, mailmap)
 }
 
-/// Batch lookup last commits for multiple files in single history walk.
+/// Computes last commits for every file and directory in a tree.
 ///
-/// Performs single history walk to find most recent commit that modified each
-/// file. Compares file OIDs between commit and parent trees to detect modifications.
+/// Walks history once from the ref tip. Each commit is tree-diffed against
+/// each of its parents a single time, and the diff reports changed
+/// directories alongside changed files, so both are resolved together. A
+/// path counts as changed when its object ID differs from a parent.
+///
+/// The resulting map is meant to be computed once per ref and shared by the
+/// index, tree, blob and history generators.
 ///
 /// # Arguments
 ///
 /// * `repo_path`: Path to git repository
 /// * `ref_name`: Reference name (branch/tag/commit), defaults to HEAD if None
-/// * `file_paths`: Slice of file paths to lookup
 ///
 /// # Returns
 ///
-/// HashMap mapping file paths to their last CommitInfo
+/// HashMap mapping every file and directory path at the tip (without the
+/// root directory) to its last CommitInfo
 ///
 /// # Errors
 ///
 /// Returns error if repository access or commit traversal fails
 ///
-/// # Performance
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::get_last_commits_tree;
+/// use std::path::Path;
+///
+/// let commits = get_last_commits_tree(Path::new("."), Some("main"))?;
+/// if let Some(commit) = commits.get("src") {
+///     println!("src/ last changed in {}", commit.short_oid());
+/// }
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn get_last_commits_tree(
+    repo_path: impl AsRef<Path>,
+    ref_name: Option<&str>,
+) -> Result<std::collections::HashMap<String, CommitInfo>> {
+    get_last_commits_tree_with_mailmap(repo_path, ref_name, &Mailmap::default())
+}
+
+/// Same as [`get_last_commits_tree`], with author and committer identities
+/// resolved through a mailmap.
+///
+/// # Arguments
 ///
-/// Complexity: O(m × n) where m = commits walked, n = files tracked
+/// * `repo_path`: Path to git repository
+/// * `ref_name`: Reference name (branch/tag/commit), defaults to HEAD if None
+/// * `mailmap`: Mailmap applied to commit identities
 ///
-/// Single repository open and history walk with early exit when all files found
+/// # Errors
+///
+/// Returns the same errors as [`get_last_commits_tree`].
 ///
 /// # Examples
 ///
 /// ```no_run
-/// use gitkyl::get_last_commits_batch;
+/// use gitkyl::{Mailmap, get_last_commits_tree_with_mailmap};
 /// use std::path::Path;
 ///
-/// let paths = &["src/lib.rs", "src/git.rs", "Cargo.toml"];
-/// let commits = get_last_commits_batch(Path::new("."), None, paths)?;
-/// for (path, commit) in commits {
-///     println!("{}: {}", path, commit.short_oid());
+/// let mailmap = Mailmap::load(Path::new("."), None)?;
+/// let commits = get_last_commits_tree_with_mailmap(Path::new("."), Some("main"), &mailmap)?;
+/// if let Some(commit) = commits.get("src") {
+///     println!("src/ last changed in {}", commit.short_oid());
 /// }
 /// # Ok::<(), anyhow::Error>(())
 /// ```
-pub fn get_last_commits_batch(
+pub fn get_last_commits_tree_with_mailmap(
     repo_path: impl AsRef<Path>,
     ref_name: Option<&str>,
-    file_paths: &[&str],
+    mailmap: &Mailmap,
 ) -> Result<std::collections::HashMap<String, CommitInfo>> {
-    use std::collections::{HashMap, HashSet};
-
-    if file_paths.is_empty() {
-        return Ok(HashMap::new());
-    }
+    use std::collections::HashSet;
 
     let repo = gix::open(repo_path.as_ref()).with_context(|| {
         format!(
@@ -904,11 +2018,29 @@ pub fn get_last_commits_batch(
     })?;
 
     let commit = resolve_commit(&repo, ref_name)?;
+    let tree = commit.tree().context("Failed to read commit tree")?;
 
-    let mut results: HashMap<String, CommitInfo> = HashMap::with_capacity(file_paths.len());
-    let mut remaining: HashSet<String> = file_paths.iter().map(|s| s.to_string()).collect();
+    let remaining: HashSet<String> = record_tree_changes(&repo, None, &tree)?
+        .iter()
+        .map(|change| change_path(change).to_str_lossy().into_owned())
+        .collect();
 
-    let walker = commit
+    walk_last_commits(&repo, &commit, remaining, mailmap)
+}
+
+/// Walks history from `tip` until every remaining path has a last commit.
+fn walk_last_commits(
+    repo: &gix::Repository,
+    tip: &gix::Commit<'_>,
+    mut remaining: std::collections::HashSet<String>,
+    mailmap: &Mailmap,
+) -> Result<std::collections::HashMap<String, CommitInfo>> {
+    use gix::diff::tree::recorder::Change;
+    use std::collections::HashMap;
+
+    let mut results: HashMap<String, CommitInfo> = HashMap::with_capacity(remaining.len());
+
+    let walker = tip
         .ancestors()
         .all()
         .context("Failed to create commit ancestor iterator")?;
@@ -918,79 +2050,386 @@ pub fn get_last_commits_batch(
             break;
         }
 
-        let commit_info = result.context("Failed to traverse commit ancestor")?;
-        let commit_obj = commit_info
-            .object()
-            .context("Failed to read commit object")?;
-
-        let parent_ids: Vec<_> = commit_obj.parent_ids().collect();
+        let info = result.context("Failed to traverse commit ancestor")?;
+        let commit = info.object().context("Failed to read commit object")?;
+        let tree = commit.tree().context("Failed to read commit tree")?;
 
-        if parent_ids.is_empty() {
-            // Initial commit contains all files added in this commit
-            let commit_data = extract_commit_info(&commit_obj)?;
+        // Initial commits are diffed against the empty tree
+        let mut parent_trees = Vec::with_capacity(info.parent_ids.len());
+        for parent_id in info.parent_ids.iter() {
+            let parent = repo
+                .find_object(*parent_id)
+                .context("Failed to find parent object")?
+                .try_into_commit()
+                .map_err(|_| anyhow::anyhow!("Parent object is not a commit"))?;
+            parent_trees.push(Some(parent.tree().context("Failed to read parent tree")?));
+        }
+        if parent_trees.is_empty() {
+            parent_trees.push(None);
+        }
 
-            let remaining_snapshot: Vec<String> = remaining.iter().cloned().collect();
-            for file_path in remaining_snapshot {
-                let mut tree = commit_obj.tree().context("Failed to read commit tree")?;
-                if let Ok(Some(_)) = tree.peel_to_entry_by_path(&file_path) {
-                    results.insert(file_path.clone(), commit_data.clone());
-                    remaining.remove(&file_path);
+        // Deletions are skipped: the path is absent from this commit, and
+        // its enclosing directories are reported as modified trees
+        let mut touched: Vec<String> = Vec::new();
+        for parent_tree in &parent_trees {
+            for change in record_tree_changes(repo, parent_tree.as_ref(), &tree)? {
+                if matches!(change, Change::Deletion { .. }) {
+                    continue;
+                }
+                let path = change_path(&change).to_str_lossy();
+                if remaining.contains(path.as_ref()) {
+                    touched.push(path.into_owned());
                 }
             }
-            break;
         }
 
-        // Process each parent to handle merge commits
-        let commit_data = extract_commit_info(&commit_obj)?;
+        if touched.is_empty() {
+            continue;
+        }
 
-        for parent_id in parent_ids {
-            if remaining.is_empty() {
-                break;
+        let commit_data = extract_commit_info(&commit, mailmap)?;
+        for path in touched {
+            if remaining.remove(&path) {
+                results.insert(path, commit_data.clone());
             }
+        }
+    }
 
-            let parent = repo
-                .find_object(parent_id)
+    Ok(results)
+}
+
+/// Returns the repository relative path of a recorded tree change.
+fn change_path(change: &gix::diff::tree::recorder::Change) -> &BString {
+    use gix::diff::tree::recorder::Change;
+
+    match change {
+        Change::Addition { path, .. }
+        | Change::Deletion { path, .. }
+        | Change::Modification { path, .. } => path,
+    }
+}
+
+/// Kind of change applied to a file by a commit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// File created by the commit
+    Added,
+    /// File removed by the commit
+    Deleted,
+    /// File content changed by the commit
+    Modified,
+}
+
+/// File changed by a commit, with content on both sides of the change.
+#[derive(Debug, Clone)]
+pub struct FileChange {
+    /// File path relative to repository root
+    pub path: String,
+    /// Kind of change
+    pub kind: ChangeKind,
+    /// Content before the commit (None when added)
+    pub old_content: Option<Vec<u8>>,
+    /// Content after the commit (None when deleted)
+    pub new_content: Option<Vec<u8>>,
+}
+
+/// Commit metadata with parents and file changes for commit detail pages.
+#[derive(Debug, Clone)]
+pub struct CommitDetail {
+    /// Commit metadata shared with list views
+    pub commit: CommitInfo,
+    /// Committer email address
+    pub committer_email: String,
+    /// Commit timestamp from committer signature (Unix seconds)
+    pub committer_date: i64,
+    /// Full parent commit hashes in parent order
+    pub parents: Vec<String>,
+    /// Files changed relative to the first parent, sorted by path
+    pub changes: Vec<FileChange>,
+}
+
+/// Computes blob level changes needed to turn one tree into another.
+///
+/// Uses gix tree diffing so unchanged subtrees are skipped without being
+/// traversed. Only blob and symlink entries are reported; directories and
+/// submodule links are omitted.
+///
+/// # Arguments
+///
+/// * `repo`: Open repository handle
+/// * `old_tree`: Tree before the change (None for an empty tree)
+/// * `new_tree`: Tree after the change
+///
+/// # Returns
+///
+/// Recorded changes with full repository relative paths
+///
+/// # Errors
+///
+/// Returns error if tree objects cannot be read or decoded
+pub(crate) fn diff_trees(
+    repo: &gix::Repository,
+    old_tree: Option<&gix::Tree<'_>>,
+    new_tree: &gix::Tree<'_>,
+) -> Result<Vec<gix::diff::tree::recorder::Change>> {
+    use gix::diff::tree::recorder::Change;
+
+    let is_file = |mode: &gix::object::tree::EntryMode| mode.is_blob_or_symlink();
+
+    Ok(record_tree_changes(repo, old_tree, new_tree)?
+        .into_iter()
+        .filter(|change| match change {
+            Change::Addition { entry_mode, .. } | Change::Deletion { entry_mode, .. } => {
+                is_file(entry_mode)
+            }
+            Change::Modification {
+                previous_entry_mode,
+                entry_mode,
+                ..
+            } => is_file(previous_entry_mode) || is_file(entry_mode),
+        })
+        .collect())
+}
+
+/// Records every changed entry between two trees, directories included.
+///
+/// Unlike `diff_trees`, changed subtrees and submodules are kept, and a
+/// directory is reported whenever its tree object differs.
+fn record_tree_changes(
+    repo: &gix::Repository,
+    old_tree: Option<&gix::Tree<'_>>,
+    new_tree: &gix::Tree<'_>,
+) -> Result<Vec<gix::diff::tree::recorder::Change>> {
+    use gix::diff::tree::{Changes, Recorder, State};
+    use gix::objs::TreeRefIter;
+
+    let mut recorder = Recorder::default();
+    Changes::from(old_tree.map(|tree| TreeRefIter::from_bytes(&tree.data)))
+        .needed_to_obtain(
+            TreeRefIter::from_bytes(&new_tree.data),
+            &mut State::default(),
+            &repo.objects,
+            &mut recorder,
+        )
+        .context("Failed to diff trees")?;
+
+    Ok(recorder.records)
+}
+
+/// Walks the ancestors of a commit in topological order.
+///
+/// Uses the same walk as `git log --topo-order`: no commit comes before
+/// any of its children, and each commit is returned with all its parents.
+pub(crate) fn topological_order(
+    repo: &gix::Repository,
+    tip: gix::ObjectId,
+) -> Result<Vec<(gix::ObjectId, Vec<gix::ObjectId>)>> {
+    use gix::traverse::commit::topo;
+
+    let walker = topo::Builder::from_iters(&repo.objects, [tip], None::<Vec<gix::ObjectId>>)
+        .sorting(topo::Sorting::TopoOrder)
+        .build()
+        .context("Failed to create topological commit walk")?;
+
+    let mut commits = Vec::new();
+    for result in walker {
+        let info = result.context("Failed to traverse commit history")?;
+        commits.push((info.id, info.parent_ids.to_vec()));
+    }
+
+    Ok(commits)
+}
+
+/// Reads commit metadata and the file changes it introduced.
+///
+/// Changes are computed against the first parent, which matches how merge
+/// commits are usually presented. Root commits are compared against an
+/// empty tree so every file shows as added.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `oid`: Full commit hash
+///
+/// # Returns
+///
+/// CommitDetail with parents and per file before/after content
+///
+/// # Errors
+///
+/// Returns error if:
+/// - Repository cannot be opened
+/// - Hash is malformed or does not resolve to a commit
+/// - Trees or blobs cannot be read
+pub fn get_commit_detail(repo_path: impl AsRef<Path>, oid: &str) -> Result<CommitDetail> {
+    get_commit_detail_with_mailmap(repo_path, oid, &Mailmap::default())
+}
+
+/// Same as [`get_commit_detail`], with author and committer identities resolved
+/// through a mailmap.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `oid`: Full commit hash
+/// * `mailmap`: Mailmap applied to author and committer identities
+///
+/// # Errors
+///
+/// Returns the same errors as [`get_commit_detail`].
+pub fn get_commit_detail_with_mailmap(
+    repo_path: impl AsRef<Path>,
+    oid: &str,
+    mailmap: &Mailmap,
+) -> Result<CommitDetail> {
+    let repo = gix::open(repo_path.as_ref()).with_context(|| {
+        format!(
+            "Failed to open repository at {}",
+            repo_path.as_ref().display()
+        )
+    })?;
+
+    let id = gix::ObjectId::from_hex(oid.as_bytes())
+        .with_context(|| format!("Invalid commit hash: {}", oid))?;
+    let commit = repo
+        .find_object(id)
+        .with_context(|| format!("Failed to find commit: {}", oid))?
+        .try_into_commit()
+        .map_err(|_| anyhow::anyhow!("Object '{}' is not a commit", oid))?;
+
+    let info = extract_commit_info(&commit, mailmap)?;
+    let committer = commit.committer().context("Failed to read committer")?;
+    let (_, committer_email) = mailmap.resolve(
+        &committer.name.to_str_lossy(),
+        &committer.email.to_str_lossy(),
+    );
+    let committer_date = committer.time.seconds;
+
+    let parents: Vec<String> = commit
+        .parent_ids()
+        .map(|id| id.detach().to_hex().to_string())
+        .collect();
+
+    let new_tree = commit.tree().context("Failed to read commit tree")?;
+    let old_tree = match commit.parent_ids().next() {
+        Some(parent_id) => Some(
+            parent_id
+                .object()
                 .context("Failed to find parent object")?
                 .try_into_commit()
-                .map_err(|_| anyhow::anyhow!("Parent object is not a commit"))?;
+                .map_err(|_| anyhow::anyhow!("Parent object is not a commit"))?
+                .tree()
+                .context("Failed to read parent tree")?,
+        ),
+        None => None,
+    };
+
+    Ok(CommitDetail {
+        commit: info,
+        committer_email,
+        committer_date,
+        parents,
+        changes: file_changes(&repo, old_tree.as_ref(), &new_tree)?,
+    })
+}
 
-            // Check each remaining file for modifications via OID comparison
-            let remaining_snapshot: Vec<String> = remaining.iter().cloned().collect();
-            for file_path in remaining_snapshot {
-                let mut current_tree = commit_obj.tree().context("Failed to read commit tree")?;
-                let mut parent_tree = parent.tree().context("Failed to read parent tree")?;
-
-                let current_entry = match current_tree.peel_to_entry_by_path(&file_path) {
-                    Ok(entry) => entry,
-                    Err(_) => continue,
-                };
-
-                let parent_entry = match parent_tree.peel_to_entry_by_path(&file_path) {
-                    Ok(entry) => entry,
-                    Err(_) => continue,
-                };
-
-                // File modified if OID differs or file was added
-                let was_modified = match (current_entry, parent_entry) {
-                    (Some(_), None) => true,
-                    (Some(curr), Some(par)) => curr.oid() != par.oid(),
-                    _ => false,
-                };
-
-                if was_modified {
-                    results.insert(file_path.clone(), commit_data.clone());
-                    remaining.remove(&file_path);
-                }
+/// Reads the content of every file that differs between two trees.
+///
+/// # Arguments
+///
+/// * `repo`: Open repository handle
+/// * `old_tree`: Tree before the change (None for an empty tree)
+/// * `new_tree`: Tree after the change
+///
+/// # Returns
+///
+/// Changed files with content on both sides, sorted by path
+///
+/// # Errors
+///
+/// Returns error if trees or blobs cannot be read
+pub(crate) fn file_changes(
+    repo: &gix::Repository,
+    old_tree: Option<&gix::Tree<'_>>,
+    new_tree: &gix::Tree<'_>,
+) -> Result<Vec<FileChange>> {
+    let mut changes = diff_trees(repo, old_tree, new_tree)?
+        .into_iter()
+        .map(|change| read_file_change(repo, change))
+        .collect::<Result<Vec<_>>>()?;
+
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(changes)
+}
+
+/// Reads the content on both sides of one change from `diff_trees`.
+///
+/// Lets callers that only need a summary of each file handle changes one
+/// at a time instead of holding every blob in memory.
+///
+/// # Errors
+///
+/// Returns error if a blob cannot be read
+pub(crate) fn read_file_change(
+    repo: &gix::Repository,
+    change: gix::diff::tree::recorder::Change,
+) -> Result<FileChange> {
+    use gix::diff::tree::recorder::Change;
+
+    let read = |id: gix::ObjectId| -> Result<Vec<u8>> {
+        Ok(repo
+            .find_object(id)
+            .with_context(|| format!("Failed to find blob {}", id))?
+            .detach()
+            .data)
+    };
+
+    Ok(match change {
+        Change::Addition { oid, path, .. } => FileChange {
+            path: path.to_str_lossy().to_string(),
+            kind: ChangeKind::Added,
+            old_content: None,
+            new_content: Some(read(oid)?),
+        },
+        Change::Deletion { oid, path, .. } => FileChange {
+            path: path.to_str_lossy().to_string(),
+            kind: ChangeKind::Deleted,
+            old_content: Some(read(oid)?),
+            new_content: None,
+        },
+        Change::Modification {
+            previous_entry_mode,
+            previous_oid,
+            entry_mode,
+            oid,
+            path,
+        } => {
+            // A directory replaced by a file (or vice versa) reads as add/delete
+            let old_is_file = previous_entry_mode.is_blob_or_symlink();
+            let new_is_file = entry_mode.is_blob_or_symlink();
+            FileChange {
+                path: path.to_str_lossy().to_string(),
+                kind: match (old_is_file, new_is_file) {
+                    (true, true) => ChangeKind::Modified,
+                    (false, true) => ChangeKind::Added,
+                    _ => ChangeKind::Deleted,
+                },
+                old_content: if old_is_file {
+                    Some(read(previous_oid)?)
+                } else {
+                    None
+                },
+                new_content: if new_is_file { Some(read(oid)?) } else { None },
             }
         }
-    }
-
-    Ok(results)
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::signature::{SignatureKind, Verification};
     use std::path::{Path, PathBuf};
 
     fn temp_repo() -> tempfile::TempDir {
@@ -1064,21 +2503,43 @@ mod tests {
             .expect("Failed to create annotated git tag");
     }
 
+    fn co_authors_of(msg: &str) -> Vec<String> {
+        collect_co_authors(&parse_trailers(msg, &Mailmap::default()))
+    }
+
     #[test]
-    fn test_parse_co_authors_none() {
-        assert!(parse_co_authors("Add feature\n\nDetails here.").is_empty());
+    fn test_collect_co_authors_none() {
+        assert!(co_authors_of("Add feature\n\nDetails here.").is_empty());
     }
 
     #[test]
-    fn test_parse_co_authors_extracts_name() {
+    fn test_collect_co_authors_extracts_name() {
         let msg = "Fix\n\nCo-authored-by: Alice <alice@example.com>";
-        assert_eq!(parse_co_authors(msg), vec!["Alice"]);
+        assert_eq!(co_authors_of(msg), vec!["Alice"]);
     }
 
     #[test]
-    fn test_parse_co_authors_case_insensitive() {
+    fn test_collect_co_authors_case_insensitive() {
         let msg = "Fix\n\nCo-Authored-By: Alice <a@x.com>\nco-authored-by: Bob";
-        assert_eq!(parse_co_authors(msg), vec!["Alice", "Bob"]);
+        assert_eq!(co_authors_of(msg), vec!["Alice", "Bob"]);
+    }
+
+    #[test]
+    fn test_commit_info_splits_body_and_trailers() {
+        // Arrange
+        let commit = CommitInfo::new(
+            "abc123def456".to_string(),
+            "Fix".to_string(),
+            "Fix\n\nBody.\n\nSigned-off-by: Alice <a@x.com>\nCo-authored-by: Bob <b@x.com>"
+                .to_string(),
+            "Alice".to_string(),
+            1234567890,
+        );
+
+        // Assert
+        assert_eq!(commit.body(), "Body.");
+        assert_eq!(commit.trailers().len(), 2);
+        assert_eq!(commit.co_authors(), ["Bob"]);
     }
 
     #[test]
@@ -1090,6 +2551,10 @@ mod tests {
             branches: vec!["main".to_string(), "dev".to_string()],
             commit_count: 42,
             owner: Some("testowner".to_string()),
+            mailmap: Mailmap::default(),
+            signature_verifier: None,
+            notes: Notes::default(),
+            ref_labels: RefLabels::default(),
         };
 
         // Act & Assert
@@ -1112,6 +2577,10 @@ mod tests {
             branches: vec!["master".to_string()],
             commit_count: 10,
             owner: None,
+            mailmap: Mailmap::default(),
+            signature_verifier: None,
+            notes: Notes::default(),
+            ref_labels: RefLabels::default(),
         };
 
         // Act & Assert
@@ -1171,6 +2640,41 @@ mod tests {
         );
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_list_files_modes() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Arrange
+        let td = temp_repo();
+        let repo_path = td.path();
+        write_file(repo_path, "README.md", "# Readme");
+        write_file(repo_path, "run.sh", "echo hi");
+        let script_path = repo_path.join("run.sh");
+        let mut perms = std::fs::metadata(&script_path).unwrap().permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&script_path, perms).unwrap();
+        std::os::unix::fs::symlink("README.md", repo_path.join("link.md")).unwrap();
+        git_add(repo_path);
+        git_commit(repo_path, "Initial commit");
+
+        // Act
+        let files = list_files(repo_path, None).expect("Should list files");
+
+        // Assert
+        let find = |name: &str| {
+            files
+                .iter()
+                .find(|entry| entry.path() == Some(Path::new(name)))
+                .unwrap_or_else(|| panic!("{} should be listed", name))
+        };
+        assert!(!find("README.md").is_symlink());
+        assert!(!find("README.md").is_executable());
+        assert!(find("run.sh").is_executable());
+        assert!(find("link.md").is_symlink());
+        assert!(!find("link.md").is_executable());
+    }
+
     #[test]
     fn test_list_files_invalid_ref() {
         // Arrange
@@ -1386,16 +2890,61 @@ mod tests {
         let commits = list_commits(&repo_path, None, Some(5)).expect("Should list commits");
 
         // Assert
-        assert!(
-            commits.len() >= 2,
-            "Need at least 2 commits to test ordering"
-        );
-        for i in 0..commits.len() - 1 {
-            assert!(
-                commits[i].date() >= commits[i + 1].date(),
-                "Commits should be in reverse chronological order (newest first)"
-            );
+        assert!(
+            commits.len() >= 2,
+            "Need at least 2 commits to test ordering"
+        );
+        for i in 0..commits.len() - 1 {
+            assert!(
+                commits[i].date() >= commits[i + 1].date(),
+                "Commits should be in reverse chronological order (newest first)"
+            );
+        }
+    }
+
+    #[test]
+    fn test_list_commits_topo_lists_children_first() {
+        // Arrange: side branch merged into the main line
+        let td = temp_repo();
+        let repo = td.path();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(repo)
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {:?} failed", args);
+        };
+        write_file(repo, "a.txt", "a");
+        git_add(repo);
+        let base = git_commit(repo, "Base");
+        git(&["checkout", "-q", "-b", "side"]);
+        write_file(repo, "side.txt", "side");
+        git_add(repo);
+        let side = git_commit(repo, "Side");
+        git(&["checkout", "-q", "-"]);
+        write_file(repo, "main.txt", "main");
+        git_add(repo);
+        let main = git_commit(repo, "Main");
+        git(&["merge", "-q", "--no-ff", "-m", "Merge side", "side"]);
+
+        // Act
+        let commits =
+            list_commits_topo(repo, None, &Mailmap::default()).expect("Should list commits");
+
+        // Assert
+        assert_eq!(commits.len(), 4);
+        assert_eq!(commits[0].parents(), [main.clone(), side.clone()]);
+        let position = |oid: &str| commits.iter().position(|c| c.oid() == oid).unwrap();
+        for commit in &commits {
+            for parent in commit.parents() {
+                assert!(
+                    position(commit.oid()) < position(parent),
+                    "Parents follow children"
+                );
+            }
         }
+        assert_eq!(commits[3].oid(), base);
     }
 
     #[test]
@@ -1937,6 +3486,282 @@ mod tests {
         assert!(result.contains_key("renamed.txt"));
     }
 
+    #[test]
+    fn test_list_submodules_with_gitmodules() {
+        // Arrange: gitlink entries do not need the submodule to exist
+        let td = temp_repo();
+        let repo_path = td.path();
+        write_file(
+            repo_path,
+            ".gitmodules",
+            "[submodule \"vendor/lib\"]\n\tpath = vendor/lib\n\turl = https://example.com/lib.git\n",
+        );
+        git_add(repo_path);
+        let pinned = "0123456789abcdef0123456789abcdef01234567";
+        std::process::Command::new("git")
+            .args([
+                "update-index",
+                "--add",
+                "--cacheinfo",
+                &format!("160000,{},vendor/lib", pinned),
+            ])
+            .current_dir(repo_path)
+            .output()
+            .unwrap();
+        std::process::Command::new("git")
+            .args([
+                "update-index",
+                "--add",
+                "--cacheinfo",
+                &format!("160000,{},undeclared", pinned),
+            ])
+            .current_dir(repo_path)
+            .output()
+            .unwrap();
+        git_commit(repo_path, "Add submodules");
+
+        // Act
+        let mut submodules = list_submodules(repo_path, None).expect("Should list submodules");
+        submodules.sort_by_key(|s| s.path().map(Path::to_path_buf));
+        let files = list_files(repo_path, None).expect("Should list files");
+
+        // Assert
+        assert_eq!(submodules.len(), 2);
+        assert_eq!(submodules[0].path(), Some(Path::new("undeclared")));
+        assert_eq!(submodules[0].url(), None);
+        assert_eq!(submodules[1].path(), Some(Path::new("vendor/lib")));
+        assert_eq!(submodules[1].oid().to_string(), pinned);
+        assert_eq!(submodules[1].url(), Some("https://example.com/lib.git"));
+        assert_eq!(files.len(), 1, "Gitlinks are not files");
+    }
+
+    #[test]
+    fn test_list_submodules_none() {
+        // Arrange
+        let repo_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+        // Act
+        let submodules = list_submodules(&repo_path, None).expect("Should list submodules");
+
+        // Assert
+        assert!(submodules.is_empty());
+    }
+
+    #[test]
+    fn test_lfs_object_states() {
+        // Arrange
+        let td = temp_repo();
+        let repo_path = td.path();
+        let oid = "4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393";
+        let content = "id,name\n1,widget\n";
+        write_file(
+            repo_path,
+            "data.csv",
+            &format!(
+                "version https://git-lfs.github.com/spec/v1\noid sha256:{}\nsize {}\n",
+                oid,
+                content.len()
+            ),
+        );
+        write_file(repo_path, "large.txt", &"x".repeat(4096));
+        git_add(repo_path);
+        git_commit(repo_path, "Add files");
+        let files = list_files(repo_path, None).expect("Should list files");
+        let blobs: Vec<gix::ObjectId> = files.iter().map(|f| *f.oid()).collect();
+        let pointer_blob = *files
+            .iter()
+            .find(|f| f.path() == Some(Path::new("data.csv")))
+            .expect("Pointer should be listed")
+            .oid();
+        let check = || {
+            lfs_object_states(repo_path, blobs.iter().map(AsRef::as_ref))
+                .expect("Should check blobs")
+        };
+        let store = repo_path.join(".git/lfs/objects");
+
+        // Act
+        let without_store = check();
+        let empty_fingerprint = lfs_store_fingerprint(repo_path).expect("Should read store");
+        std::fs::create_dir_all(&store).unwrap();
+        let missing = check();
+        let object = store.join(&oid[0..2]).join(&oid[2..4]).join(oid);
+        std::fs::create_dir_all(object.parent().unwrap()).unwrap();
+        std::fs::write(&object, content).unwrap();
+        let fetched = check();
+        let fetched_fingerprint = lfs_store_fingerprint(repo_path).expect("Should read store");
+
+        // Assert
+        assert!(without_store.is_empty(), "No store, no blobs read");
+        assert_eq!(missing.len(), 1, "Large blobs are not pointers");
+        assert_eq!(missing.get(&pointer_blob), Some(&false));
+        assert_eq!(fetched.get(&pointer_blob), Some(&true));
+        assert!(
+            empty_fingerprint.is_empty(),
+            "Missing store has no fingerprint"
+        );
+        assert!(
+            !fetched_fingerprint.is_empty(),
+            "Fetching changes the fingerprint"
+        );
+    }
+
+    #[test]
+    fn test_pin_commit() {
+        // Arrange: pin the first of two commits
+        let td = temp_repo();
+        let repo_path = td.path();
+        write_file(repo_path, "a.txt", "one\n");
+        git_add(repo_path);
+        let first = git_commit(repo_path, "First");
+        write_file(repo_path, "b.txt", "two\n");
+        git_add(repo_path);
+        git_commit(repo_path, "Second");
+        let dest = tempfile::TempDir::new().unwrap();
+        let oid = gix::ObjectId::from_hex(first.as_bytes()).unwrap();
+
+        // Act
+        let branch = pin_commit(repo_path, &oid, dest.path()).expect("Should pin commit");
+
+        // Assert
+        assert_eq!(branch, first[..7]);
+        let info = analyze_repository(dest.path(), None).expect("Should open pinned repository");
+        assert_eq!(info.default_branch(), branch);
+        assert_eq!(info.branches(), std::slice::from_ref(&branch));
+        assert_eq!(info.commit_count(), 1);
+        let files = list_files(dest.path(), Some(&branch)).expect("Should read pinned tree");
+        assert_eq!(files.len(), 1, "Only the pinned commit's files are shown");
+    }
+
+    #[test]
+    fn test_pin_commit_missing() {
+        // Arrange
+        let td = temp_repo();
+        let dest = tempfile::TempDir::new().unwrap();
+        let oid = gix::ObjectId::from_hex(b"0123456789abcdef0123456789abcdef01234567").unwrap();
+
+        // Act
+        let result = pin_commit(td.path(), &oid, dest.path());
+
+        // Assert
+        assert!(result.is_err(), "Unfetched commits cannot be pinned");
+    }
+
+    #[test]
+    fn test_parse_gitmodules() {
+        // Arrange
+        let content = "[submodule \"a\"]\n\tpath = libs/a\n\turl = ../a.git\n\
+                       [submodule \"b\"]\n\tpath = b\n";
+
+        // Act
+        let urls = parse_gitmodules(content);
+
+        // Assert
+        assert_eq!(urls.get("libs/a").map(String::as_str), Some("../a.git"));
+        assert!(!urls.contains_key("b"), "Entries without url are skipped");
+    }
+
+    #[test]
+    fn test_get_last_commits_tree_files_and_dirs() {
+        // Arrange
+        let td = temp_repo();
+        let repo_path = td.path();
+        write_file(repo_path, "src/lib.rs", "lib");
+        write_file(repo_path, "src/util/mod.rs", "util");
+        write_file(repo_path, "README.md", "readme");
+        git_add(repo_path);
+        git_commit(repo_path, "Initial commit");
+        write_file(repo_path, "src/util/mod.rs", "util v2");
+        git_add(repo_path);
+        git_commit(repo_path, "Update util");
+        write_file(repo_path, "README.md", "readme v2");
+        git_add(repo_path);
+        git_commit(repo_path, "Update readme");
+
+        // Act
+        let result = get_last_commits_tree(repo_path, None).expect("Should compute map");
+
+        // Assert
+        assert_eq!(result.len(), 5, "Three files and two directories");
+        assert_eq!(result["README.md"].message(), "Update readme");
+        assert_eq!(result["src/lib.rs"].message(), "Initial commit");
+        assert_eq!(result["src/util/mod.rs"].message(), "Update util");
+        assert_eq!(result["src/util"].message(), "Update util");
+        assert_eq!(result["src"].message(), "Update util");
+    }
+
+    #[test]
+    fn test_get_last_commits_tree_deletion_updates_directory() {
+        // Arrange
+        let td = temp_repo();
+        let repo_path = td.path();
+        write_file(repo_path, "docs/a.md", "a");
+        write_file(repo_path, "docs/b.md", "b");
+        git_add(repo_path);
+        git_commit(repo_path, "Add docs");
+        std::fs::remove_file(repo_path.join("docs/b.md")).unwrap();
+        std::process::Command::new("git")
+            .args(["add", "-A"])
+            .current_dir(repo_path)
+            .output()
+            .unwrap();
+        git_commit(repo_path, "Remove b");
+
+        // Act
+        let result = get_last_commits_tree(repo_path, None).expect("Should compute map");
+
+        // Assert
+        assert_eq!(result["docs"].message(), "Remove b");
+        assert_eq!(result["docs/a.md"].message(), "Add docs");
+        assert!(!result.contains_key("docs/b.md"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_get_last_commits_tree_mode_change_updates_directory() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Arrange
+        let td = temp_repo();
+        let repo_path = td.path();
+        write_file(repo_path, "bin/run.sh", "echo hi");
+        git_add(repo_path);
+        git_commit(repo_path, "Initial commit");
+        let script_path = repo_path.join("bin/run.sh");
+        let mut perms = std::fs::metadata(&script_path).unwrap().permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&script_path, perms).unwrap();
+        git_add(repo_path);
+        git_commit(repo_path, "Make executable");
+
+        // Act
+        let result = get_last_commits_tree(repo_path, None).expect("Should compute map");
+
+        // Assert: file content is unchanged, its directory tree is not
+        assert_eq!(result["bin/run.sh"].message(), "Initial commit");
+        assert_eq!(result["bin"].message(), "Make executable");
+    }
+
+    #[test]
+    fn test_get_last_commits_tree_matches_batch() {
+        // Arrange
+        let repo_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let paths = vec!["src", "src/git.rs", "Cargo.toml", "assets"];
+
+        // Act
+        let tree = get_last_commits_tree(&repo_path, None).expect("Should compute map");
+        let batch = get_last_commits_batch(&repo_path, None, &paths).expect("Should lookup");
+
+        // Assert
+        for path in paths {
+            assert_eq!(
+                tree.get(path).map(|c| c.oid()),
+                batch.get(path).map(|c| c.oid()),
+                "Mismatch for {}",
+                path
+            );
+        }
+    }
+
     #[test]
     fn test_get_last_commits_batch_mode_change_only() {
         let td = temp_repo();
@@ -2233,4 +4058,244 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_list_commits_applies_mailmap() {
+        // Arrange
+        let td = temp_repo();
+        write_file(td.path(), "a.txt", "alpha\n");
+        git_add(td.path());
+        git_commit(
+            td.path(),
+            "Add a\n\nCo-authored-by: bob <bob@old.example.com>",
+        );
+        let mailmap = Mailmap::parse(
+            "Canonical User <canonical@example.com> <test@example.com>\n\
+             Bob Smith <bob@example.com> <bob@old.example.com>\n",
+        );
+
+        // Act
+        let commits = list_commits_with_mailmap(td.path(), None, None, &mailmap)
+            .expect("Should list commits");
+
+        // Assert
+        let commit = &commits[0];
+        assert_eq!(commit.author(), "Canonical User");
+        assert_eq!(commit.author_email(), "canonical@example.com");
+        assert_eq!(commit.committer(), "Canonical User");
+        assert_eq!(commit.co_authors(), ["Bob Smith"]);
+    }
+
+    #[test]
+    fn test_list_tags_applies_mailmap() {
+        // Arrange
+        let td = temp_repo();
+        write_file(td.path(), "a.txt", "alpha\n");
+        git_add(td.path());
+        git_commit(td.path(), "Initial commit");
+        git_tag_annotated(td.path(), "v1.0.0", "Release");
+        let mailmap = Mailmap::parse("Canonical User <test@example.com>\n");
+
+        // Act
+        let tags = list_tags_with_mailmap(td.path(), &mailmap).expect("Should list tags");
+
+        // Assert
+        assert_eq!(
+            tags[0].tagger.as_deref(),
+            Some("Canonical User <test@example.com>")
+        );
+    }
+
+    /// Configures SSH signing with a fresh key, returning an allowed signers file.
+    fn enable_ssh_signing(repo_path: &Path) -> PathBuf {
+        let key = repo_path.join(".git/signing_key");
+        let output = std::process::Command::new("ssh-keygen")
+            .args(["-q", "-t", "ed25519", "-N", "", "-C", "test", "-f"])
+            .arg(&key)
+            .output()
+            .expect("Failed to run ssh-keygen");
+        assert!(output.status.success());
+
+        for (name, value) in [
+            ("gpg.format", "ssh"),
+            ("user.signingkey", key.to_str().unwrap()),
+            ("commit.gpgsign", "true"),
+            ("tag.gpgsign", "true"),
+        ] {
+            std::process::Command::new("git")
+                .args(["config", name, value])
+                .current_dir(repo_path)
+                .output()
+                .unwrap();
+        }
+
+        let public_key = std::fs::read_to_string(key.with_extension("pub")).unwrap();
+        let allowed = repo_path.join(".git/allowed_signers");
+        std::fs::write(&allowed, format!("test@example.com {}", public_key)).unwrap();
+        allowed
+    }
+
+    #[test]
+    fn test_ssh_signed_commit_and_tag() {
+        // Arrange
+        let td = temp_repo();
+        let allowed = enable_ssh_signing(td.path());
+        write_file(td.path(), "a.txt", "alpha\n");
+        git_add(td.path());
+        git_commit(td.path(), "Signed commit");
+        git_tag_annotated(td.path(), "v1.0.0", "Signed release");
+        let verifier = SignatureVerifier::new(&allowed).expect("Should create verifier");
+
+        // Act
+        let mut commits = list_commits(td.path(), None, None).expect("Should list commits");
+        let mut tags = list_tags(td.path()).expect("Should list tags");
+        verify_commit_signatures(td.path(), &mut commits, &verifier)
+            .expect("Should verify commits");
+        verify_tag_signatures(td.path(), &mut tags, &verifier).expect("Should verify tags");
+
+        // Assert
+        let commit_signature = commits[0].signature().expect("Commit should be signed");
+        assert_eq!(commit_signature.kind(), SignatureKind::Ssh);
+        assert!(
+            commit_signature
+                .fingerprint()
+                .is_some_and(|f| f.starts_with("SHA256:"))
+        );
+        assert_eq!(
+            commit_signature.verification(),
+            &Verification::Verified("test@example.com".to_string())
+        );
+
+        let tag_signature = tags[0].signature.as_ref().expect("Tag should be signed");
+        assert_eq!(tag_signature.fingerprint(), commit_signature.fingerprint());
+        assert_eq!(
+            tag_signature.verification(),
+            &Verification::Verified("test@example.com".to_string())
+        );
+        assert_eq!(
+            tags[0].message.as_deref().map(str::trim),
+            Some("Signed release"),
+            "Signature should be stripped from the tag message"
+        );
+    }
+
+    #[test]
+    fn test_ssh_signature_from_untrusted_key() {
+        // Arrange
+        let td = temp_repo();
+        enable_ssh_signing(td.path());
+        write_file(td.path(), "a.txt", "alpha\n");
+        git_add(td.path());
+        git_commit(td.path(), "Signed commit");
+        let untrusted = td.path().join("untrusted_signers");
+        std::fs::write(&untrusted, "").unwrap();
+        let verifier = SignatureVerifier::new(&untrusted).expect("Should create verifier");
+
+        // Act
+        let mut commits = list_commits(td.path(), None, None).expect("Should list commits");
+        verify_commit_signatures(td.path(), &mut commits, &verifier)
+            .expect("Should verify commits");
+
+        // Assert
+        assert_eq!(
+            commits[0].signature().map(|s| s.verification()),
+            Some(&Verification::Unverified)
+        );
+    }
+
+    #[test]
+    fn test_unsigned_commit_has_no_signature() {
+        // Arrange
+        let td = temp_repo();
+        write_file(td.path(), "a.txt", "alpha\n");
+        git_add(td.path());
+        git_commit(td.path(), "Unsigned commit");
+        git_tag_annotated(td.path(), "v1.0.0", "Unsigned release");
+
+        // Act
+        let commits = list_commits(td.path(), None, None).expect("Should list commits");
+        let tags = list_tags(td.path()).expect("Should list tags");
+
+        // Assert
+        assert!(commits[0].signature().is_none());
+        assert!(tags[0].signature.is_none());
+    }
+
+    #[test]
+    fn test_get_commit_detail_root_commit() {
+        // Arrange
+        let td = temp_repo();
+        write_file(td.path(), "a.txt", "alpha\n");
+        write_file(td.path(), "dir/b.txt", "beta\n");
+        git_add(td.path());
+        let oid = git_commit(td.path(), "Initial commit");
+
+        // Act
+        let detail = get_commit_detail(td.path(), &oid).expect("Should read commit detail");
+
+        // Assert
+        assert_eq!(detail.commit.oid(), oid);
+        assert!(detail.parents.is_empty(), "Root commit has no parents");
+        let paths: Vec<&str> = detail.changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, vec!["a.txt", "dir/b.txt"]);
+        assert!(
+            detail
+                .changes
+                .iter()
+                .all(|c| c.kind == ChangeKind::Added && c.old_content.is_none())
+        );
+    }
+
+    #[test]
+    fn test_get_commit_detail_modify_and_delete() {
+        // Arrange
+        let td = temp_repo();
+        write_file(td.path(), "keep.txt", "one\n");
+        write_file(td.path(), "gone.txt", "bye\n");
+        git_add(td.path());
+        let first = git_commit(td.path(), "Initial commit");
+
+        write_file(td.path(), "keep.txt", "one\ntwo\n");
+        std::fs::remove_file(td.path().join("gone.txt")).unwrap();
+        std::process::Command::new("git")
+            .args(["add", "-A"])
+            .current_dir(td.path())
+            .output()
+            .unwrap();
+        let second = git_commit(td.path(), "Update files\n\nMore detail.");
+
+        // Act
+        let detail = get_commit_detail(td.path(), &second).expect("Should read commit detail");
+
+        // Assert
+        assert_eq!(detail.parents, vec![first]);
+        assert_eq!(detail.commit.message(), "Update files");
+        assert_eq!(detail.changes.len(), 2);
+
+        let gone = &detail.changes[0];
+        assert_eq!(gone.path, "gone.txt");
+        assert_eq!(gone.kind, ChangeKind::Deleted);
+        assert_eq!(gone.old_content.as_deref(), Some(&b"bye\n"[..]));
+        assert!(gone.new_content.is_none());
+
+        let keep = &detail.changes[1];
+        assert_eq!(keep.path, "keep.txt");
+        assert_eq!(keep.kind, ChangeKind::Modified);
+        assert_eq!(keep.new_content.as_deref(), Some(&b"one\ntwo\n"[..]));
+    }
+
+    #[test]
+    fn test_get_commit_detail_invalid_hash() {
+        // Arrange
+        let td = temp_repo();
+        write_file(td.path(), "a.txt", "alpha");
+        git_add(td.path());
+        git_commit(td.path(), "Initial commit");
+
+        // Act
+        let result = get_commit_detail(td.path(), "not-a-hash");
+
+        // Assert
+        assert!(result.is_err(), "Malformed hash should fail");
+    }
 }
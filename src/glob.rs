@@ -0,0 +1,147 @@
+//! Glob pattern matching for paths and reference names.
+//!
+//! Implements the wildcard syntax of gitignore and gitattributes patterns:
+//! `*` and `?` match within a single path component, `**` spans
+//! components, `[...]` matches a character class (negated with `!` or `^`)
+//! and `\` escapes the next character.
+
+/// Matches text against a glob pattern.
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    match_from(&pattern, &text)
+}
+
+/// Matches a pattern suffix against a text suffix.
+fn match_from(p: &[char], t: &[char]) -> bool {
+    match p.first() {
+        None => t.is_empty(),
+        Some('*') => {
+            let stars = p.iter().take_while(|&&c| c == '*').count();
+            let rest = &p[stars..];
+
+            if stars >= 2 {
+                // "**/" also matches zero leading directories
+                if let Some(after_slash) = rest.strip_prefix(&['/']) {
+                    return match_from(after_slash, t)
+                        || t.iter()
+                            .enumerate()
+                            .any(|(i, &c)| c == '/' && match_from(after_slash, &t[i + 1..]));
+                }
+                return (0..=t.len()).any(|i| match_from(rest, &t[i..]));
+            }
+
+            for i in 0..=t.len() {
+                if match_from(rest, &t[i..]) {
+                    return true;
+                }
+                if t.get(i) == Some(&'/') {
+                    break;
+                }
+            }
+            false
+        }
+        Some('?') => t.first().is_some_and(|&c| c != '/') && match_from(&p[1..], &t[1..]),
+        Some('[') => match (t.first(), match_class(&p[1..], t.first().copied())) {
+            (Some(&c), Some((matched, len))) => {
+                c != '/' && matched && match_from(&p[1 + len..], &t[1..])
+            }
+            (_, Some(_)) => false,
+            // Unterminated class matches a literal bracket
+            (first, None) => first == Some(&'[') && match_from(&p[1..], &t[1..]),
+        },
+        Some('\\') if p.len() > 1 => t.first() == Some(&p[1]) && match_from(&p[2..], &t[1..]),
+        Some(c) => t.first() == Some(c) && match_from(&p[1..], &t[1..]),
+    }
+}
+
+/// Matches a character against a class body following `[`.
+///
+/// # Returns
+///
+/// Whether the character is in the class and the pattern length consumed
+/// including the closing `]`, or None if the class is unterminated
+fn match_class(p: &[char], c: Option<char>) -> Option<(bool, usize)> {
+    let negate = matches!(p.first(), Some('!' | '^'));
+    let mut i = usize::from(negate);
+    let start = i;
+    let mut matched = false;
+
+    while i < p.len() {
+        if p[i] == ']' && i > start {
+            return Some((matched != negate, i + 1));
+        }
+
+        let (low, low_len) = match p[i] {
+            '\\' => (*p.get(i + 1)?, 2),
+            other => (other, 1),
+        };
+        i += low_len;
+
+        if p.get(i) == Some(&'-') && p.get(i + 1).is_some_and(|&high| high != ']') {
+            let high = p[i + 1];
+            matched |= c.is_some_and(|c| low <= c && c <= high);
+            i += 2;
+        } else {
+            matched |= c == Some(low);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_literal_and_question_mark() {
+        assert!(glob_match("README.md", "README.md"));
+        assert!(!glob_match("README.md", "README.txt"));
+        assert!(glob_match("v?.0", "v1.0"));
+        assert!(!glob_match("a?b", "a/b"), "? should not match a slash");
+    }
+
+    #[test]
+    fn test_star_stays_within_component() {
+        assert!(glob_match("*.rs", "lib.rs"));
+        assert!(glob_match("src/*.rs", "src/lib.rs"));
+        assert!(!glob_match("src/*.rs", "src/pages/index.rs"));
+        assert!(glob_match("feature/*", "feature/login"));
+        assert!(!glob_match("feature/*", "feature/login/ui"));
+        assert!(glob_match("*", ""));
+    }
+
+    #[test]
+    fn test_double_star_spans_components() {
+        assert!(glob_match("src/**/*.rs", "src/pages/index.rs"));
+        assert!(
+            glob_match("src/**/*.rs", "src/lib.rs"),
+            "**/ matches zero dirs"
+        );
+        assert!(glob_match("**/test", "a/b/test"));
+        assert!(glob_match("**/test", "test"));
+        assert!(glob_match("docs/**", "docs/a/b.md"));
+        assert!(glob_match("release-**", "release-1/rc"));
+        assert!(!glob_match("docs/**", "src/docs/a.md"));
+    }
+
+    #[test]
+    fn test_character_classes() {
+        assert!(glob_match("v[0-9].*", "v1.2"));
+        assert!(!glob_match("v[0-9].*", "vx.2"));
+        assert!(glob_match("[!a]b", "cb"));
+        assert!(!glob_match("[!a]b", "ab"));
+        assert!(glob_match("[^a]b", "cb"));
+        assert!(glob_match("[]x]", "]"));
+        assert!(glob_match("[a-]", "-"));
+        assert!(!glob_match("a[/]b", "a/b"), "Classes never match a slash");
+    }
+
+    #[test]
+    fn test_escapes_and_unterminated_class() {
+        assert!(glob_match(r"\*.md", "*.md"));
+        assert!(!glob_match(r"\*.md", "a.md"));
+        assert!(glob_match("[abc", "[abc"));
+    }
+}
@@ -0,0 +1,417 @@
+//! Commit graph layout for history pages.
+//!
+//! Assigns every commit of a walk to a lane (column) and records the edges
+//! crossing its row, the way `git log --graph` draws history. Lanes stay in
+//! place while they are in use, so rows of different pages line up when the
+//! layout is computed over the whole walk and then split into pages.
+
+use anyhow::{Context, Result};
+use gix::bstr::ByteSlice;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::git::CommitInfo;
+
+/// Reference pointing at a commit, shown next to it in the graph.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RefLabel {
+    /// Local branch tip
+    Branch(String),
+    /// Tag, peeled to the tagged commit
+    Tag(String),
+}
+
+/// Line segment of a graph row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edge {
+    /// Lane passing the row from top to bottom
+    Through(usize),
+    /// Lane ending at the commit, coming from a child above
+    Into(usize),
+    /// Lane leaving the commit towards a parent below
+    OutOf(usize),
+}
+
+/// Graph drawing for one commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphRow {
+    /// Lane of the commit node
+    pub column: usize,
+    /// Number of lanes in use across the row
+    pub width: usize,
+    /// Edges crossing the row
+    pub edges: Vec<Edge>,
+    /// Whether the commit has several parents
+    pub is_merge: bool,
+    /// Branches and tags pointing at the commit
+    pub labels: Vec<RefLabel>,
+}
+
+/// Branch and tag labels of a repository, indexed by commit.
+#[derive(Debug, Clone, Default)]
+pub struct RefLabels {
+    by_commit: HashMap<String, Vec<RefLabel>>,
+    fingerprint: String,
+}
+
+impl RefLabels {
+    /// Collects the branch and tag names pointing at each commit.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_path`: Path to git repository
+    ///
+    /// # Returns
+    ///
+    /// Labels with branches before tags, each sorted by name
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the repository or its references cannot be read
+    pub fn load(repo_path: impl AsRef<Path>) -> Result<Self> {
+        let repo = gix::open(repo_path.as_ref()).with_context(|| {
+            format!(
+                "Failed to open repository at {}",
+                repo_path.as_ref().display()
+            )
+        })?;
+        let references = repo.references().context("Failed to read references")?;
+
+        let mut labels = Self::default();
+        for reference in references
+            .all()
+            .context("Failed to get all references")?
+            .flatten()
+        {
+            let full_name = reference.name().as_bstr();
+            let is_branch = full_name.starts_with(b"refs/heads/");
+            if !is_branch && !full_name.starts_with(b"refs/tags/") {
+                continue;
+            }
+            let name = reference.name().shorten().to_str_lossy().to_string();
+
+            let mut reference = reference;
+            let Ok(id) = reference.peel_to_id_in_place() else {
+                continue;
+            };
+            let label = if is_branch {
+                RefLabel::Branch(name)
+            } else {
+                RefLabel::Tag(name)
+            };
+            labels
+                .by_commit
+                .entry(id.to_string())
+                .or_default()
+                .push(label);
+        }
+
+        for commit_labels in labels.by_commit.values_mut() {
+            commit_labels.sort();
+        }
+        labels.update_fingerprint();
+
+        Ok(labels)
+    }
+
+    /// Keeps only the labels of published refs.
+    ///
+    /// # Arguments
+    ///
+    /// * `keep`: Returns true for labels to keep
+    pub fn retain(mut self, mut keep: impl FnMut(&RefLabel) -> bool) -> Self {
+        for commit_labels in self.by_commit.values_mut() {
+            commit_labels.retain(&mut keep);
+        }
+        self.by_commit
+            .retain(|_, commit_labels| !commit_labels.is_empty());
+        self.update_fingerprint();
+        self
+    }
+
+    /// Hashes all labels and the commits they point at.
+    fn update_fingerprint(&mut self) {
+        let mut lines = Vec::new();
+        for (oid, commit_labels) in &self.by_commit {
+            for label in commit_labels {
+                lines.push(format!("{} {:?}\n", oid, label));
+            }
+        }
+        lines.sort();
+        self.fingerprint = gix::objs::compute_hash(
+            gix::hash::Kind::Sha1,
+            gix::objs::Kind::Blob,
+            lines.concat().as_bytes(),
+        )
+        .to_hex_with_len(12)
+        .to_string();
+    }
+
+    /// Labels of a commit.
+    pub fn for_commit(&self, oid: &str) -> &[RefLabel] {
+        self.by_commit.get(oid).map_or(&[], Vec::as_slice)
+    }
+
+    /// Short hash of all labels and the commits they point at.
+    pub fn fingerprint(&self) -> &str {
+        &self.fingerprint
+    }
+}
+
+/// Lays out the commit graph of a walk.
+///
+/// Commits must be listed children first, as returned by
+/// `list_commits_topo`. A commit takes the leftmost lane waiting for it (or
+/// the first free lane when nothing points at it yet), its first parent
+/// continues in that lane and further parents get lanes of their own unless
+/// a lane already waits for them.
+///
+/// # Arguments
+///
+/// * `commits`: Commits in topological order, with parents
+/// * `labels`: Reference labels to attach to the rows
+///
+/// # Returns
+///
+/// One graph row per commit, in the same order
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::{Mailmap, RefLabels, graph_layout, list_commits_topo};
+/// use std::path::Path;
+///
+/// let commits = list_commits_topo(Path::new("."), None, &Mailmap::default())?;
+/// let rows = graph_layout(&commits, &RefLabels::load(Path::new("."))?);
+/// assert_eq!(rows.len(), commits.len());
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn graph_layout(commits: &[CommitInfo], labels: &RefLabels) -> Vec<GraphRow> {
+    // Each lane holds the commit expected next on it
+    let mut lanes: Vec<Option<&str>> = Vec::new();
+    let mut rows = Vec::with_capacity(commits.len());
+
+    for commit in commits {
+        let oid = commit.oid();
+        let column = lanes
+            .iter()
+            .position(|lane| *lane == Some(oid))
+            .unwrap_or_else(|| free_lane(&mut lanes));
+
+        let mut edges = Vec::new();
+        for (idx, lane) in lanes.iter_mut().enumerate() {
+            match *lane {
+                Some(expected) if expected == oid => {
+                    edges.push(Edge::Into(idx));
+                    *lane = None;
+                }
+                Some(_) => edges.push(Edge::Through(idx)),
+                None => {}
+            }
+        }
+
+        for (idx, parent) in commit.parents().iter().enumerate() {
+            let parent = parent.as_str();
+            // The first parent always continues the lane; lanes waiting for the
+            // same commit join where it appears, which draws the fork point
+            let existing = lanes.iter().position(|lane| *lane == Some(parent));
+            let target = match existing {
+                _ if idx == 0 => {
+                    lanes[column] = Some(parent);
+                    column
+                }
+                Some(existing) => existing,
+                None => {
+                    let lane = free_lane(&mut lanes);
+                    lanes[lane] = Some(parent);
+                    lane
+                }
+            };
+            edges.push(Edge::OutOf(target));
+        }
+
+        let width = edges
+            .iter()
+            .map(|edge| match *edge {
+                Edge::Through(lane) | Edge::Into(lane) | Edge::OutOf(lane) => lane + 1,
+            })
+            .max()
+            .unwrap_or(0)
+            .max(column + 1);
+
+        while lanes.last() == Some(&None) {
+            lanes.pop();
+        }
+
+        rows.push(GraphRow {
+            column,
+            width,
+            edges,
+            is_merge: commit.parents().len() > 1,
+            labels: labels.for_commit(oid).to_vec(),
+        });
+    }
+
+    rows
+}
+
+/// Returns the first unused lane, adding one when all are taken.
+fn free_lane(lanes: &mut Vec<Option<&str>>) -> usize {
+    match lanes.iter().position(Option::is_none) {
+        Some(idx) => idx,
+        None => {
+            lanes.push(None);
+            lanes.len() - 1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commit(oid: &str, parents: &[&str]) -> CommitInfo {
+        CommitInfo::new(
+            oid.to_string(),
+            oid.to_string(),
+            oid.to_string(),
+            "Alice".to_string(),
+            0,
+        )
+        .with_parents(parents.iter().map(|p| p.to_string()).collect())
+    }
+
+    #[test]
+    fn test_layout_linear_history_single_lane() {
+        // Arrange
+        let commits = vec![commit("c", &["b"]), commit("b", &["a"]), commit("a", &[])];
+
+        // Act
+        let rows = graph_layout(&commits, &RefLabels::default());
+
+        // Assert
+        assert!(rows.iter().all(|row| row.column == 0 && row.width == 1));
+        assert_eq!(rows[0].edges, vec![Edge::OutOf(0)]);
+        assert_eq!(rows[1].edges, vec![Edge::Into(0), Edge::OutOf(0)]);
+        assert_eq!(rows[2].edges, vec![Edge::Into(0)]);
+    }
+
+    #[test]
+    fn test_layout_merge_and_fork_point() {
+        // Arrange: m merges side branch s into main line b, both fork from a
+        let commits = vec![
+            commit("m", &["b", "s"]),
+            commit("b", &["a"]),
+            commit("s", &["a"]),
+            commit("a", &[]),
+        ];
+
+        // Act
+        let rows = graph_layout(&commits, &RefLabels::default());
+
+        // Assert
+        assert!(rows[0].is_merge);
+        assert_eq!(rows[0].edges, vec![Edge::OutOf(0), Edge::OutOf(1)]);
+        assert_eq!(
+            rows[1].edges,
+            vec![Edge::Into(0), Edge::Through(1), Edge::OutOf(0)]
+        );
+        assert_eq!(rows[2].column, 1);
+        assert_eq!(
+            rows[2].edges,
+            vec![Edge::Through(0), Edge::Into(1), Edge::OutOf(1)]
+        );
+        assert_eq!(
+            rows[3].edges,
+            vec![Edge::Into(0), Edge::Into(1)],
+            "Fork point joins lanes"
+        );
+        assert_eq!(rows[3].column, 0);
+    }
+
+    #[test]
+    fn test_layout_attaches_labels() {
+        // Arrange
+        let commits = vec![commit("b", &["a"]), commit("a", &[])];
+        let labels = RefLabels {
+            by_commit: HashMap::from([("a".to_string(), vec![RefLabel::Tag("v1.0".to_string())])]),
+            fingerprint: String::new(),
+        };
+
+        // Act
+        let rows = graph_layout(&commits, &labels);
+
+        // Assert
+        assert!(rows[0].labels.is_empty());
+        assert_eq!(rows[1].labels, vec![RefLabel::Tag("v1.0".to_string())]);
+    }
+
+    #[test]
+    fn test_load_ref_labels_reads_branches_and_tags() {
+        // Arrange
+        let td = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let repo = td.path();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(repo)
+                .output()
+                .expect("Failed to run git");
+            assert!(output.status.success(), "git {:?} failed", args);
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        };
+        git(&["init", "-b", "main"]);
+        git(&["config", "user.name", "Test"]);
+        git(&["config", "user.email", "test@test.com"]);
+        git(&["commit", "--allow-empty", "-m", "First"]);
+        git(&["tag", "-a", "v1.0", "-m", "Release"]);
+        git(&["branch", "feature"]);
+        let head = git(&["rev-parse", "HEAD"]);
+
+        // Act
+        let labels = RefLabels::load(repo).expect("Should read labels");
+
+        // Assert
+        assert!(!labels.fingerprint().is_empty());
+        assert_eq!(
+            labels.for_commit(&head),
+            vec![
+                RefLabel::Branch("feature".to_string()),
+                RefLabel::Branch("main".to_string()),
+                RefLabel::Tag("v1.0".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_retain_drops_unpublished_labels() {
+        // Arrange
+        let labels = RefLabels {
+            by_commit: HashMap::from([
+                (
+                    "a".to_string(),
+                    vec![
+                        RefLabel::Branch("main".to_string()),
+                        RefLabel::Tag("nightly".to_string()),
+                    ],
+                ),
+                (
+                    "b".to_string(),
+                    vec![RefLabel::Tag("nightly-2".to_string())],
+                ),
+            ]),
+            fingerprint: String::new(),
+        };
+
+        // Act
+        let labels = labels.retain(|label| matches!(label, RefLabel::Branch(_)));
+
+        // Assert
+        assert_eq!(
+            labels.for_commit("a"),
+            vec![RefLabel::Branch("main".to_string())]
+        );
+        assert!(labels.for_commit("b").is_empty());
+        assert_eq!(labels.fingerprint().len(), 12);
+    }
+}
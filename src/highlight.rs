@@ -11,7 +11,7 @@ use std::path::Path;
 use syntect::easy::HighlightLines;
 use syntect::highlighting::{Theme, ThemeSet};
 use syntect::html::{IncludeBackground, styled_line_to_highlighted_html};
-use syntect::parsing::SyntaxSet;
+use syntect::parsing::{SyntaxReference, SyntaxSet};
 use syntect::util::LinesWithEndings;
 
 /// Syntax highlighting engine with lazy-loaded syntaxes and themes.
@@ -103,12 +103,7 @@ impl Highlighter {
     ///
     /// Returns error if syntax highlighting fails.
     pub fn highlight(&self, code: &str, path: &Path) -> Result<Vec<String>> {
-        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("txt");
-
-        let syntax = self
-            .syntax_set
-            .find_syntax_by_extension(extension)
-            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text());
+        let syntax = self.find_syntax(path);
 
         let mut highlighter = HighlightLines::new(syntax, &self.theme);
         let mut result = Vec::new();
@@ -124,6 +119,30 @@ impl Highlighter {
 
         Ok(result)
     }
+
+    /// Detects the language of a file the way `highlight` does.
+    ///
+    /// # Arguments
+    ///
+    /// * `path`: File path for language detection
+    ///
+    /// # Returns
+    ///
+    /// Syntax name (e.g. "Rust"), or None for plain text
+    pub fn language(&self, path: &Path) -> Option<&str> {
+        let syntax = self.find_syntax(path);
+        (syntax.name != self.syntax_set.find_syntax_plain_text().name)
+            .then_some(syntax.name.as_str())
+    }
+
+    /// Finds the syntax for a file extension, falling back to plain text.
+    fn find_syntax(&self, path: &Path) -> &SyntaxReference {
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("txt");
+
+        self.syntax_set
+            .find_syntax_by_extension(extension)
+            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text())
+    }
 }
 
 /// Highlights source code with syntax highlighting.
@@ -167,6 +186,17 @@ mod tests {
         assert!(Highlighter::with_theme("NonexistentTheme").is_err());
     }
 
+    #[test]
+    fn test_language_detection() {
+        // Arrange
+        let highlighter = Highlighter::new().expect("Should create highlighter");
+
+        // Act & Assert
+        assert_eq!(highlighter.language(Path::new("src/main.rs")), Some("Rust"));
+        assert_eq!(highlighter.language(Path::new("notes.txt")), None);
+        assert_eq!(highlighter.language(Path::new("LICENSE")), None);
+    }
+
     #[test]
     fn test_highlight_rust() {
         // Arrange
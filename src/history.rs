@@ -0,0 +1,561 @@
+//! Commit history of individual files and directories.
+
+use anyhow::{Context, Result};
+use gix::bstr::ByteSlice;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::diff::{Edit, diff_lines};
+use crate::git::{CommitInfo, CommitOrder, PaginatedCommits, diff_trees, extract_commit_info};
+use crate::mailmap::Mailmap;
+
+/// Minimum share of common lines for a delete/add pair to count as a rename.
+///
+/// Matches the 50% similarity threshold git uses by default.
+const RENAME_SIMILARITY: f64 = 0.5;
+
+/// Lists commits that changed a file or directory.
+///
+/// Follows the ref's history in the given topological order, visiting
+/// commits children first. A commit is listed when the object at the path
+/// differs from every parent, so merges that only brought in an existing
+/// version are skipped, mirroring `git log -- <path>`.
+///
+/// With `follow_renames`, a file that appears in a commit is matched against
+/// files deleted by the same commit. An identical or sufficiently similar
+/// deleted file is taken as the previous name and history continues under it.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `order`: History of the ref, shared by all paths of the ref
+/// * `path`: File or directory path within repository tree
+/// * `follow_renames`: Continue file history across renames
+/// * `limit`: Maximum number of commits to return
+/// * `mailmap`: Mailmap applied to commit identities
+///
+/// # Returns
+///
+/// Commits touching the path, newest first
+///
+/// # Errors
+///
+/// Returns error if:
+/// - Repository cannot be opened
+/// - Commits or trees cannot be read
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::{CommitOrder, Mailmap, list_path_commits};
+/// use std::path::Path;
+///
+/// let mailmap = Mailmap::default();
+/// let order = CommitOrder::load(Path::new("."), Some("main"))?;
+/// let commits = list_path_commits(Path::new("."), &order, "src/git.rs", true, None, &mailmap)?;
+/// for commit in commits {
+///     println!("{} {}", commit.short_oid(), commit.message());
+/// }
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn list_path_commits(
+    repo_path: impl AsRef<Path>,
+    order: &CommitOrder,
+    path: &str,
+    follow_renames: bool,
+    limit: Option<usize>,
+    mailmap: &Mailmap,
+) -> Result<Vec<CommitInfo>> {
+    let repo = gix::open(repo_path.as_ref()).with_context(|| {
+        format!(
+            "Failed to open repository at {}",
+            repo_path.as_ref().display()
+        )
+    })?;
+
+    let Some(tip) = order.tip() else {
+        return Ok(Vec::new());
+    };
+    let path = path.trim_matches('/');
+
+    let mut tracked: HashMap<gix::ObjectId, String> = HashMap::new();
+    tracked.insert(tip, path.to_string());
+
+    let mut commits = Vec::new();
+
+    for (commit_id, parent_ids) in order.commits() {
+        if limit.is_some_and(|max| commits.len() >= max) {
+            break;
+        }
+        let Some(current_path) = tracked.remove(commit_id) else {
+            continue;
+        };
+
+        let commit = find_commit(&repo, *commit_id)?;
+        let current = entry_at(&commit, &current_path)?;
+
+        let mut unchanged_parent = None;
+        let mut changed_parents = Vec::with_capacity(parent_ids.len());
+
+        for &parent_id in parent_ids {
+            let parent = find_commit(&repo, parent_id)?;
+            let previous = entry_at(&parent, &current_path)?;
+
+            if previous.map(|(oid, _)| oid) == current.map(|(oid, _)| oid) {
+                unchanged_parent = Some(parent_id);
+                break;
+            }
+
+            match (previous, current) {
+                (Some(_), _) => changed_parents.push((parent_id, current_path.clone())),
+                (None, Some((oid, is_file))) if follow_renames && is_file => {
+                    if let Some(old_path) = find_rename(&repo, &parent, &commit, oid)? {
+                        changed_parents.push((parent_id, old_path));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        match unchanged_parent {
+            // Path untouched relative to this parent: follow it alone, as git
+            // history simplification does, unless the path is absent there
+            Some(parent_id) => {
+                if current.is_some() {
+                    tracked.entry(parent_id).or_insert(current_path);
+                }
+            }
+            None => {
+                commits.push(extract_commit_info(&commit, mailmap)?);
+                for (parent_id, parent_path) in changed_parents {
+                    tracked.entry(parent_id).or_insert(parent_path);
+                }
+            }
+        }
+    }
+
+    Ok(commits)
+}
+
+/// Lists commits that changed a path with pagination support.
+///
+/// Applies the same filtering as `list_path_commits` and slices the result
+/// like `list_commits_paginated`.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `order`: History of the ref, shared by all paths of the ref
+/// * `path`: File or directory path within repository tree
+/// * `follow_renames`: Continue file history across renames
+/// * `page`: Page number (1-indexed)
+/// * `per_page`: Number of commits per page
+/// * `mailmap`: Mailmap applied to commit identities
+///
+/// # Returns
+///
+/// PaginatedCommits with commits for requested page and pagination metadata
+///
+/// # Errors
+///
+/// Returns error if:
+/// - Page or per_page is zero
+/// - History cannot be listed
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::{CommitOrder, Mailmap, list_path_commits_paginated};
+/// use std::path::Path;
+///
+/// let mailmap = Mailmap::default();
+/// let order = CommitOrder::load(Path::new("."), Some("main"))?;
+/// let page = list_path_commits_paginated(Path::new("."), &order, "src", false, 1, 35, &mailmap)?;
+/// println!("Has more: {}", page.has_more);
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn list_path_commits_paginated(
+    repo_path: impl AsRef<Path>,
+    order: &CommitOrder,
+    path: &str,
+    follow_renames: bool,
+    page: usize,
+    per_page: usize,
+    mailmap: &Mailmap,
+) -> Result<PaginatedCommits> {
+    if page == 0 {
+        anyhow::bail!("Page number must be >= 1, got 0");
+    }
+    if per_page == 0 {
+        anyhow::bail!("Per page count must be >= 1, got 0");
+    }
+
+    let skip = (page - 1) * per_page;
+    // Fetch one extra to detect if more pages exist
+    let commits = list_path_commits(
+        repo_path,
+        order,
+        path,
+        follow_renames,
+        Some(skip + per_page + 1),
+        mailmap,
+    )?;
+
+    let mut commits: Vec<CommitInfo> = commits.into_iter().skip(skip).collect();
+    let has_more = commits.len() > per_page;
+    commits.truncate(per_page);
+
+    Ok(PaginatedCommits::new(commits, page, per_page, has_more))
+}
+
+/// Reads a commit object by ID.
+fn find_commit(repo: &gix::Repository, id: gix::ObjectId) -> Result<gix::Commit<'_>> {
+    repo.find_object(id)
+        .context("Failed to find commit object")?
+        .try_into_commit()
+        .map_err(|_| anyhow::anyhow!("Object is not a commit"))
+}
+
+/// Looks up the object at a path in a commit tree.
+///
+/// The empty path refers to the root tree. Returns the object ID and
+/// whether the entry is a file, or None when the path is missing.
+fn entry_at(commit: &gix::Commit<'_>, path: &str) -> Result<Option<(gix::ObjectId, bool)>> {
+    if path.is_empty() {
+        let tree_id = commit.tree_id().context("Failed to read commit tree")?;
+        return Ok(Some((tree_id.detach(), false)));
+    }
+
+    let mut tree = commit.tree().context("Failed to read commit tree")?;
+    let entry = tree
+        .peel_to_entry_by_path(path)
+        .context("Failed to traverse tree to path")?;
+
+    Ok(entry.map(|e| (e.object_id(), e.mode().is_blob_or_symlink())))
+}
+
+/// Finds the path a file had in the parent before being renamed.
+///
+/// Candidates are files the commit deleted. An identical blob wins outright;
+/// otherwise the most similar text file above `RENAME_SIMILARITY` is chosen.
+fn find_rename(
+    repo: &gix::Repository,
+    parent: &gix::Commit<'_>,
+    commit: &gix::Commit<'_>,
+    added: gix::ObjectId,
+) -> Result<Option<String>> {
+    use gix::diff::tree::recorder::Change;
+
+    let parent_tree = parent.tree().context("Failed to read parent tree")?;
+    let commit_tree = commit.tree().context("Failed to read commit tree")?;
+
+    let deleted: Vec<(String, gix::ObjectId)> = diff_trees(repo, Some(&parent_tree), &commit_tree)?
+        .into_iter()
+        .filter_map(|change| match change {
+            Change::Deletion {
+                entry_mode,
+                oid,
+                path,
+            } if entry_mode.is_blob_or_symlink() => Some((path.to_str_lossy().into_owned(), oid)),
+            _ => None,
+        })
+        .collect();
+
+    if let Some((path, _)) = deleted.iter().find(|(_, oid)| *oid == added) {
+        return Ok(Some(path.clone()));
+    }
+
+    let added_data = repo
+        .find_object(added)
+        .context("Failed to find added blob")?
+        .detach()
+        .data;
+    if added_data.contains(&0) {
+        return Ok(None);
+    }
+    let added_text = added_data.to_str_lossy();
+    let added_lines: Vec<&str> = added_text.lines().collect();
+
+    let mut best: Option<(f64, String)> = None;
+    for (path, oid) in deleted {
+        let data = repo
+            .find_object(oid)
+            .context("Failed to find deleted blob")?
+            .detach()
+            .data;
+        if data.contains(&0) {
+            continue;
+        }
+        let text = data.to_str_lossy();
+        let lines: Vec<&str> = text.lines().collect();
+
+        let score = similarity(&lines, &added_lines);
+        if score >= RENAME_SIMILARITY && best.as_ref().is_none_or(|(top, _)| score > *top) {
+            best = Some((score, path));
+        }
+    }
+
+    Ok(best.map(|(_, path)| path))
+}
+
+/// Share of lines two versions have in common, from 0.0 to 1.0.
+fn similarity(old: &[&str], new: &[&str]) -> f64 {
+    let total = old.len() + new.len();
+    if total == 0 {
+        return 1.0;
+    }
+
+    let common = diff_lines(old, new)
+        .iter()
+        .filter(|edit| matches!(edit, Edit::Equal { .. }))
+        .count();
+
+    (2 * common) as f64 / total as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::process::Command;
+
+    fn temp_repo() -> tempfile::TempDir {
+        let td = tempfile::TempDir::with_prefix("gitkyl-history-").unwrap();
+        for args in [
+            vec!["init", "-q", "-b", "main"],
+            vec!["config", "user.name", "Test"],
+            vec!["config", "user.email", "test@example.com"],
+        ] {
+            Command::new("git")
+                .args(&args)
+                .current_dir(td.path())
+                .output()
+                .unwrap();
+        }
+        td
+    }
+
+    fn commit(dir: &Path, message: &str) {
+        Command::new("git")
+            .args(["add", "-A"])
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["commit", "-q", "-m", message])
+            .current_dir(dir)
+            .output()
+            .unwrap();
+    }
+
+    fn order(dir: &Path) -> CommitOrder {
+        CommitOrder::load(dir, Some("main")).expect("Should walk history")
+    }
+
+    fn messages(commits: &[CommitInfo]) -> Vec<&str> {
+        commits.iter().map(|c| c.message()).collect()
+    }
+
+    #[test]
+    fn test_file_history_skips_unrelated_commits() {
+        // Arrange
+        let repo = temp_repo();
+        fs::write(repo.path().join("a.txt"), "one\n").unwrap();
+        commit(repo.path(), "add a");
+        fs::write(repo.path().join("b.txt"), "other\n").unwrap();
+        commit(repo.path(), "add b");
+        fs::write(repo.path().join("a.txt"), "one\ntwo\n").unwrap();
+        commit(repo.path(), "edit a");
+
+        // Act
+        let commits = list_path_commits(
+            repo.path(),
+            &order(repo.path()),
+            "a.txt",
+            false,
+            None,
+            &Mailmap::default(),
+        )
+        .expect("Should list history");
+
+        // Assert
+        assert_eq!(messages(&commits), vec!["edit a", "add a"]);
+    }
+
+    #[test]
+    fn test_directory_history() {
+        // Arrange
+        let repo = temp_repo();
+        fs::create_dir(repo.path().join("src")).unwrap();
+        fs::write(repo.path().join("src/lib.rs"), "fn a() {}\n").unwrap();
+        commit(repo.path(), "add lib");
+        fs::write(repo.path().join("README.md"), "# Readme\n").unwrap();
+        commit(repo.path(), "add readme");
+        fs::write(repo.path().join("src/main.rs"), "fn main() {}\n").unwrap();
+        commit(repo.path(), "add main");
+
+        // Act
+        let commits = list_path_commits(
+            repo.path(),
+            &order(repo.path()),
+            "src",
+            false,
+            None,
+            &Mailmap::default(),
+        )
+        .expect("Should list history");
+
+        // Assert
+        assert_eq!(messages(&commits), vec!["add main", "add lib"]);
+    }
+
+    #[test]
+    fn test_follow_renames() {
+        // Arrange
+        let repo = temp_repo();
+        let content = "line 1\nline 2\nline 3\nline 4\n";
+        fs::write(repo.path().join("old.txt"), content).unwrap();
+        commit(repo.path(), "create");
+        fs::remove_file(repo.path().join("old.txt")).unwrap();
+        fs::write(repo.path().join("new.txt"), format!("{}line 5\n", content)).unwrap();
+        commit(repo.path(), "rename and extend");
+
+        // Act
+        let plain = list_path_commits(
+            repo.path(),
+            &order(repo.path()),
+            "new.txt",
+            false,
+            None,
+            &Mailmap::default(),
+        )
+        .expect("Should list history");
+        let followed = list_path_commits(
+            repo.path(),
+            &order(repo.path()),
+            "new.txt",
+            true,
+            None,
+            &Mailmap::default(),
+        )
+        .expect("Should list history");
+
+        // Assert
+        assert_eq!(messages(&plain), vec!["rename and extend"]);
+        assert_eq!(messages(&followed), vec!["rename and extend", "create"]);
+    }
+
+    #[test]
+    fn test_merge_without_changes_is_skipped() {
+        // Arrange
+        let repo = temp_repo();
+        fs::write(repo.path().join("a.txt"), "base\n").unwrap();
+        commit(repo.path(), "base");
+        Command::new("git")
+            .args(["checkout", "-q", "-b", "side"])
+            .current_dir(repo.path())
+            .output()
+            .unwrap();
+        fs::write(repo.path().join("a.txt"), "side\n").unwrap();
+        commit(repo.path(), "side edit");
+        Command::new("git")
+            .args(["checkout", "-q", "main"])
+            .current_dir(repo.path())
+            .output()
+            .unwrap();
+        fs::write(repo.path().join("b.txt"), "main\n").unwrap();
+        commit(repo.path(), "main edit");
+        Command::new("git")
+            .args(["merge", "-q", "--no-edit", "side"])
+            .current_dir(repo.path())
+            .output()
+            .unwrap();
+
+        // Act
+        let commits = list_path_commits(
+            repo.path(),
+            &order(repo.path()),
+            "a.txt",
+            false,
+            None,
+            &Mailmap::default(),
+        )
+        .expect("Should list history");
+
+        // Assert
+        assert_eq!(messages(&commits), vec!["side edit", "base"]);
+    }
+
+    #[test]
+    fn test_paginated_history() {
+        // Arrange
+        let repo = temp_repo();
+        for idx in 0..5 {
+            fs::write(repo.path().join("a.txt"), format!("{}\n", idx)).unwrap();
+            commit(repo.path(), &format!("edit {}", idx));
+        }
+
+        // Act
+        let first = list_path_commits_paginated(
+            repo.path(),
+            &order(repo.path()),
+            "a.txt",
+            false,
+            1,
+            2,
+            &Mailmap::default(),
+        )
+        .expect("Should list page 1");
+        let last = list_path_commits_paginated(
+            repo.path(),
+            &order(repo.path()),
+            "a.txt",
+            false,
+            3,
+            2,
+            &Mailmap::default(),
+        )
+        .expect("Should list page 3");
+
+        // Assert
+        assert_eq!(messages(&first.commits), vec!["edit 4", "edit 3"]);
+        assert!(first.has_more);
+        assert_eq!(messages(&last.commits), vec!["edit 0"]);
+        assert!(!last.has_more);
+    }
+
+    #[test]
+    fn test_paginated_history_rejects_zero_page() {
+        // Arrange
+        let repo = temp_repo();
+        fs::write(repo.path().join("a.txt"), "a\n").unwrap();
+        commit(repo.path(), "add");
+
+        // Act
+        let result = list_path_commits_paginated(
+            repo.path(),
+            &order(repo.path()),
+            "a.txt",
+            false,
+            0,
+            10,
+            &Mailmap::default(),
+        );
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_similarity() {
+        // Arrange
+        let old = vec!["a", "b", "c", "d"];
+        let new = vec!["a", "b", "c", "x"];
+
+        // Act
+        let score = similarity(&old, &new);
+
+        // Assert
+        assert!((score - 0.75).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,171 @@
+//! Bounds on the history listed by commits pages.
+//!
+//! Long lived repositories have tens of thousands of commits per ref, and
+//! every ref would otherwise get pages down to the root commit. A limit
+//! keeps a prefix of the topologically ordered history: the newest commits
+//! up to a count, a date, or the point where a branch joins the default
+//! branch. Keeping a prefix preserves the commit graph drawn next to it.
+
+use std::collections::HashSet;
+
+use crate::git::CommitInfo;
+
+/// Reason a listed history ends before the root commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Truncation {
+    /// Only the given number of newest commits is listed
+    MaxCommits(usize),
+    /// Commits older than the given time (Unix seconds) are left out
+    Since(i64),
+    /// The rest of the history is listed on the given default branch
+    DefaultBranch(String),
+}
+
+/// Limits applied to the history of each ref.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HistoryLimit {
+    max_commits: Option<usize>,
+    since: Option<i64>,
+}
+
+impl HistoryLimit {
+    /// Lists at most the given number of commits.
+    pub fn with_max_commits(mut self, max_commits: Option<usize>) -> Self {
+        self.max_commits = max_commits;
+        self
+    }
+
+    /// Leaves out commits older than the given time (Unix seconds).
+    pub fn with_since(mut self, since: Option<i64>) -> Self {
+        self.since = since;
+        self
+    }
+
+    /// Finds where a history is cut.
+    ///
+    /// Commits older than `since` may still be kept when newer ones follow
+    /// them in topological order, so that the kept commits form a prefix.
+    /// Refs whose tip is on the default branch keep their whole history.
+    ///
+    /// # Arguments
+    ///
+    /// * `commits`: History of a ref in topological order, children first
+    /// * `covered`: Commits of the default branch to stop at, with its name
+    ///
+    /// # Returns
+    ///
+    /// Number of leading commits to keep, and why the rest was dropped
+    pub fn cutoff(
+        &self,
+        commits: &[CommitInfo],
+        covered: Option<(&str, &HashSet<String>)>,
+    ) -> (usize, Option<Truncation>) {
+        let mut cutoff = (commits.len(), None);
+
+        if let Some((branch, oids)) = covered
+            && let Some(idx) = commits.iter().position(|c| oids.contains(c.oid()))
+            && idx > 0
+        {
+            cutoff = (idx, Some(Truncation::DefaultBranch(branch.to_string())));
+        }
+
+        if let Some(since) = self.since {
+            let keep = commits
+                .iter()
+                .rposition(|commit| commit.date() >= since)
+                .map_or(0, |idx| idx + 1);
+            if keep < cutoff.0 {
+                cutoff = (keep, Some(Truncation::Since(since)));
+            }
+        }
+
+        if let Some(max) = self.max_commits
+            && max < cutoff.0
+        {
+            cutoff = (max, Some(Truncation::MaxCommits(max)));
+        }
+
+        cutoff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(dates: &[i64]) -> Vec<CommitInfo> {
+        dates
+            .iter()
+            .enumerate()
+            .map(|(idx, &date)| {
+                CommitInfo::new(
+                    format!("{:040x}", idx),
+                    "Change".to_string(),
+                    "Change".to_string(),
+                    "Alice".to_string(),
+                    date,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_unbounded_keeps_everything() {
+        let commits = history(&[30, 20, 10]);
+
+        assert_eq!(HistoryLimit::default().cutoff(&commits, None), (3, None));
+    }
+
+    #[test]
+    fn test_max_commits() {
+        // Arrange
+        let commits = history(&[30, 20, 10]);
+        let limit = HistoryLimit::default().with_max_commits(Some(2));
+
+        // Act
+        let cutoff = limit.cutoff(&commits, None);
+
+        // Assert
+        assert_eq!(cutoff, (2, Some(Truncation::MaxCommits(2))));
+        assert_eq!(
+            limit.cutoff(&commits[..2], None),
+            (2, None),
+            "Not truncated when everything fits"
+        );
+    }
+
+    #[test]
+    fn test_since_keeps_prefix() {
+        // Arrange: an old commit of a merged branch sits between new ones
+        let commits = history(&[40, 5, 30, 10, 4]);
+        let limit = HistoryLimit::default().with_since(Some(20));
+
+        // Act
+        let cutoff = limit.cutoff(&commits, None);
+
+        // Assert
+        assert_eq!(cutoff, (3, Some(Truncation::Since(20))));
+    }
+
+    #[test]
+    fn test_stops_at_default_branch() {
+        // Arrange
+        let commits = history(&[30, 20, 10]);
+        let covered = HashSet::from([commits[1].oid().to_string(), commits[2].oid().to_string()]);
+        let limit = HistoryLimit::default().with_max_commits(Some(2));
+
+        // Act
+        let cutoff = limit.cutoff(&commits, Some(("main", &covered)));
+
+        // Assert
+        assert_eq!(
+            cutoff,
+            (1, Some(Truncation::DefaultBranch("main".to_string())))
+        );
+        assert_eq!(
+            HistoryLimit::default().cutoff(&commits[1..], Some(("main", &covered))),
+            (2, None),
+            "Tip on the default branch keeps its history"
+        );
+    }
+}
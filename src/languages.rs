@@ -0,0 +1,295 @@
+//! Language breakdown of a file tree.
+//!
+//! Counts bytes per language like GitHub's linguist: languages are detected
+//! by the syntax highlighter, and `.gitattributes` files can override them
+//! with `linguist-language=<name>` or leave paths out with
+//! `linguist-vendored`. Plain text and symlinks are not counted.
+
+use anyhow::{Context, Result};
+use gix::bstr::ByteSlice;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::attributes::{
+    AttributeState, attribute_state, attributes_base, is_set, parse_rules, sort_rules,
+};
+use crate::highlight::Highlighter;
+use crate::tree::FileTree;
+
+/// Attribute overriding the detected language.
+const LINGUIST_LANGUAGE: &str = "linguist-language";
+
+/// Attribute excluding third party code from the breakdown.
+const LINGUIST_VENDORED: &str = "linguist-vendored";
+
+/// Colors of common languages, following GitHub's linguist.
+const LANGUAGE_COLORS: &[(&str, &str)] = &[
+    ("Bourne Again Shell (bash)", "#89e051"),
+    ("C", "#555555"),
+    ("C#", "#178600"),
+    ("C++", "#f34b7d"),
+    ("CSS", "#563d7c"),
+    ("Go", "#00add8"),
+    ("HTML", "#e34c26"),
+    ("Haskell", "#5e5086"),
+    ("Java", "#b07219"),
+    ("JavaScript", "#f1e05a"),
+    ("Lua", "#000080"),
+    ("Makefile", "#427819"),
+    ("Markdown", "#083fa1"),
+    ("PHP", "#4f5d95"),
+    ("Perl", "#0298c3"),
+    ("Python", "#3572a5"),
+    ("Ruby", "#701516"),
+    ("Rust", "#dea584"),
+    ("Scala", "#c22d40"),
+    ("TypeScript", "#3178c6"),
+];
+
+/// Bytes of one language in a tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Language {
+    name: String,
+    bytes: u64,
+}
+
+impl Language {
+    /// Language name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Total size of the language's files in bytes.
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// Display color as a CSS color value.
+    pub fn color(&self) -> String {
+        match LANGUAGE_COLORS.iter().find(|(name, _)| *name == self.name) {
+            Some((_, color)) => color.to_string(),
+            None => {
+                let hue = self
+                    .name
+                    .bytes()
+                    .fold(0u32, |h, b| h.wrapping_mul(31).wrapping_add(u32::from(b)));
+                format!("hsl({}, 55%, 55%)", hue % 360)
+            }
+        }
+    }
+}
+
+/// Languages of a tree, largest first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LanguageBreakdown {
+    languages: Vec<Language>,
+    total: u64,
+}
+
+impl LanguageBreakdown {
+    /// Builds a breakdown from bytes per language.
+    pub(crate) fn from_bytes(bytes: HashMap<String, u64>) -> Self {
+        let mut languages: Vec<Language> = bytes
+            .into_iter()
+            .filter(|(_, bytes)| *bytes > 0)
+            .map(|(name, bytes)| Language { name, bytes })
+            .collect();
+        languages.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.name.cmp(&b.name)));
+        let total = languages.iter().map(|language| language.bytes).sum();
+        Self { languages, total }
+    }
+
+    /// Languages ordered by size, largest first.
+    pub fn languages(&self) -> &[Language] {
+        &self.languages
+    }
+
+    /// Total bytes of all counted files.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns true if no file was attributed to a language.
+    pub fn is_empty(&self) -> bool {
+        self.languages.is_empty()
+    }
+
+    /// Share of a language in the total, in percent.
+    pub fn percent(&self, language: &Language) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            language.bytes as f64 * 100.0 / self.total as f64
+        }
+    }
+}
+
+/// Computes bytes per language over a file tree.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository holding the tree's blobs
+/// * `tree`: File tree to measure
+/// * `highlighter`: Highlighter used for language detection
+///
+/// # Returns
+///
+/// Languages ordered by size
+///
+/// # Errors
+///
+/// Returns error if the repository cannot be opened or a blob cannot be read
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::{FileTree, Highlighter, language_breakdown, list_files};
+/// use std::path::Path;
+///
+/// let tree = FileTree::from_files(list_files(Path::new("."), None)?);
+/// let breakdown = language_breakdown(Path::new("."), &tree, &Highlighter::new()?)?;
+/// for language in breakdown.languages() {
+///     println!("{} {:.1}%", language.name(), breakdown.percent(language));
+/// }
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn language_breakdown(
+    repo_path: impl AsRef<Path>,
+    tree: &FileTree,
+    highlighter: &Highlighter,
+) -> Result<LanguageBreakdown> {
+    let repo = gix::open(repo_path.as_ref()).with_context(|| {
+        format!(
+            "Failed to open repository at {}",
+            repo_path.as_ref().display()
+        )
+    })?;
+
+    let files: Vec<(&str, &gix::ObjectId)> = tree
+        .all_files_under("")
+        .into_iter()
+        .filter(|entry| !entry.is_symlink())
+        .filter_map(|entry| Some((entry.path()?.to_str()?, entry.oid())))
+        .collect();
+
+    let mut language_rules = Vec::new();
+    let mut vendored_rules = Vec::new();
+    for (path, oid) in &files {
+        if let Some(base) = attributes_base(path) {
+            let blob = repo
+                .find_object(**oid)
+                .with_context(|| format!("Failed to read {}", path))?;
+            let content = blob.data.to_str_lossy();
+            language_rules.extend(parse_rules(base, &content, LINGUIST_LANGUAGE));
+            vendored_rules.extend(parse_rules(base, &content, LINGUIST_VENDORED));
+        }
+    }
+    sort_rules(&mut language_rules);
+    sort_rules(&mut vendored_rules);
+
+    let mut bytes: HashMap<String, u64> = HashMap::new();
+    for (path, oid) in files {
+        if is_set(path, &vendored_rules) {
+            continue;
+        }
+        let language = match attribute_state(path, false, &language_rules) {
+            Some(AttributeState::Value(name)) => Some(name.as_str()),
+            _ => highlighter.language(Path::new(path)),
+        };
+        let Some(language) = language else {
+            continue;
+        };
+
+        let size = repo
+            .find_header(*oid)
+            .with_context(|| format!("Failed to read {}", path))?
+            .size();
+        *bytes.entry(language.to_string()).or_default() += size;
+    }
+
+    Ok(LanguageBreakdown::from_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_breakdown_orders_by_size() {
+        // Arrange
+        let bytes = HashMap::from([
+            ("Rust".to_string(), 300),
+            ("CSS".to_string(), 100),
+            ("HTML".to_string(), 0),
+        ]);
+
+        // Act
+        let breakdown = LanguageBreakdown::from_bytes(bytes);
+
+        // Assert
+        let names: Vec<&str> = breakdown.languages().iter().map(Language::name).collect();
+        assert_eq!(names, vec!["Rust", "CSS"]);
+        assert_eq!(breakdown.total(), 400);
+        assert_eq!(breakdown.percent(&breakdown.languages()[0]), 75.0);
+    }
+
+    #[test]
+    fn test_language_color() {
+        let rust = Language {
+            name: "Rust".to_string(),
+            bytes: 1,
+        };
+        let unknown = Language {
+            name: "Zig".to_string(),
+            bytes: 1,
+        };
+
+        assert_eq!(rust.color(), "#dea584");
+        assert!(unknown.color().starts_with("hsl("));
+        assert_eq!(unknown.color(), unknown.color(), "Fallback is stable");
+    }
+
+    #[test]
+    fn test_language_breakdown_applies_attributes() {
+        // Arrange
+        let td = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let repo = td.path();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(repo)
+                .output()
+                .expect("Failed to run git");
+            assert!(output.status.success(), "git {:?} failed", args);
+        };
+        git(&["init"]);
+        git(&["config", "user.name", "Test"]);
+        git(&["config", "user.email", "test@test.com"]);
+        std::fs::create_dir_all(repo.join("vendor")).unwrap();
+        std::fs::write(repo.join("main.rs"), "fn main() {}\n").unwrap();
+        std::fs::write(repo.join("lib.py"), "x = 1\n").unwrap();
+        std::fs::write(repo.join("gen.inc"), "int x;\n").unwrap();
+        std::fs::write(repo.join("vendor/dep.py"), "y = 2\n".repeat(100)).unwrap();
+        std::fs::write(repo.join("notes.txt"), "plain\n").unwrap();
+        std::fs::write(
+            repo.join(".gitattributes"),
+            "vendor/** linguist-vendored\n*.inc linguist-language=C\n",
+        )
+        .unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-m", "Add"]);
+        let tree = FileTree::from_files(crate::git::list_files(repo, None).expect("Should list"));
+        let highlighter = Highlighter::new().expect("Should create highlighter");
+
+        // Act
+        let breakdown = language_breakdown(repo, &tree, &highlighter).expect("Should measure");
+
+        // Assert
+        let measured: Vec<(&str, u64)> = breakdown
+            .languages()
+            .iter()
+            .map(|language| (language.name(), language.bytes()))
+            .collect();
+        assert_eq!(measured, vec![("Rust", 13), ("C", 7), ("Python", 6)]);
+    }
+}
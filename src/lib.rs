@@ -1,26 +1,73 @@
 //! Static site generator for Git repositories.
 
+mod activity;
+mod archive;
 mod assets;
+mod attributes;
 pub mod avatar;
+mod blame;
 pub mod components;
 mod config;
+mod contributors;
+mod diff;
+mod dumb_http;
 mod filetype;
 mod git;
+mod glob;
+mod graph;
 mod highlight;
+mod history;
+mod history_limit;
+mod languages;
+mod mailmap;
+mod manifest;
 mod markdown;
+mod notes;
 pub mod pages;
+mod pool;
+mod ref_filter;
+mod release;
+mod repos;
+mod semver;
+mod signature;
+mod trailers;
 mod tree;
 mod util;
 
+pub use activity::Activity;
+pub use archive::{ArchiveFormat, archive_name, write_archive};
 pub use assets::write_css_assets;
 pub use avatar::render;
+pub use blame::{BlameHunk, blame_file};
 pub use components::icons::{is_markdown, is_readme};
 pub use config::Config;
-pub use filetype::{FileType, ImageFormat, detect_file_type};
+pub use contributors::{Contributor, author_slug, collect_contributors, commit_line_stats};
+pub use dumb_http::{PublishedRefs, clone_dir_name, write_dumb_repository};
+pub use filetype::{FileType, ImageFormat, LfsPointer, detect_file_type};
 pub use git::{
-    CommitInfo, FileEntry, PaginatedCommits, RepoInfo, TagInfo, TreeItem, analyze_repository,
-    get_last_commits_batch, list_commits, list_commits_paginated, list_files, list_tags, read_blob,
+    ChangeKind, CommitDetail, CommitInfo, CommitOrder, FileChange, FileEntry, PaginatedCommits,
+    RepoInfo, SubmoduleEntry, TagInfo, TreeItem, analyze_repository, get_commit_detail,
+    get_commit_detail_with_mailmap, get_last_commits_batch, get_last_commits_batch_with_mailmap,
+    get_last_commits_tree, get_last_commits_tree_with_mailmap, lfs_object_states,
+    lfs_store_fingerprint, list_commits, list_commits_paginated,
+    list_commits_paginated_with_mailmap, list_commits_topo, list_commits_with_mailmap, list_files,
+    list_submodules, list_tags, list_tags_with_mailmap, pin_commit, read_blob, read_lfs_object,
+    verify_commit_signatures, verify_tag_signatures,
 };
+pub use graph::{Edge, GraphRow, RefLabel, RefLabels, graph_layout};
 pub use highlight::{Highlighter, highlight};
+pub use history::{list_path_commits, list_path_commits_paginated};
+pub use history_limit::{HistoryLimit, Truncation};
+pub use languages::{Language, LanguageBreakdown, language_breakdown};
+pub use mailmap::Mailmap;
+pub use manifest::{MANIFEST_FILE, Manifest};
 pub use markdown::{LinkResolver, MarkdownRenderer};
-pub use tree::FileTree;
+pub use notes::{Note, Notes};
+pub use pool::parallel_map;
+pub use ref_filter::RefFilter;
+pub use release::{ReleaseChanges, ReleaseFile, latest_release, release_changes};
+pub use repos::{RepoSummary, discover_repositories, summarize_repository};
+pub use semver::Version;
+pub use signature::{Signature, SignatureKind, SignatureVerifier, Verification};
+pub use trailers::Trailer;
+pub use tree::{FileTree, SymlinkTarget};
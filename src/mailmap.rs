@@ -0,0 +1,376 @@
+//! Author identity normalization using `.mailmap`.
+//!
+//! Maps the names and emails recorded in commits to canonical identities,
+//! following the format described in gitmailmap(5):
+//!
+//! ```text
+//! Proper Name <commit@email>
+//! <proper@email> <commit@email>
+//! Proper Name <proper@email> <commit@email>
+//! Proper Name <proper@email> Commit Name <commit@email>
+//! ```
+//!
+//! Emails and names are matched case insensitively. When several lines match
+//! the same identity, the last one wins.
+
+use anyhow::{Context, Result};
+use gix::bstr::ByteSlice;
+use std::path::Path;
+
+/// Single mapping line from a mailmap file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MailmapEntry {
+    proper_name: Option<String>,
+    proper_email: Option<String>,
+    commit_name: Option<String>,
+    commit_email: String,
+}
+
+/// Parsed mailmap mapping commit identities to canonical ones.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Mailmap {
+    entries: Vec<MailmapEntry>,
+    fingerprint: String,
+}
+
+impl Mailmap {
+    /// Parses mailmap content.
+    ///
+    /// Blank lines, comments and lines without a commit email are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gitkyl::Mailmap;
+    ///
+    /// let mailmap = Mailmap::parse("Jane Doe <jane@example.com> <jdoe@old.example.com>\n");
+    /// assert_eq!(
+    ///     mailmap.resolve("jdoe", "JDoe@old.example.com"),
+    ///     ("Jane Doe".to_string(), "jane@example.com".to_string())
+    /// );
+    /// ```
+    pub fn parse(content: &str) -> Self {
+        let entries: Vec<MailmapEntry> = content.lines().filter_map(parse_line).collect();
+
+        let fingerprint = if entries.is_empty() {
+            String::new()
+        } else {
+            gix::objs::compute_hash(
+                gix::hash::Kind::Sha1,
+                gix::objs::Kind::Blob,
+                content.as_bytes(),
+            )
+            .to_hex_with_len(12)
+            .to_string()
+        };
+
+        Self {
+            entries,
+            fingerprint,
+        }
+    }
+
+    /// Loads the mailmap for a repository.
+    ///
+    /// Reads `.mailmap` from the HEAD commit, then appends `file` when given
+    /// so its lines take precedence, like git's `mailmap.file` setting.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_path`: Path to git repository
+    /// * `file`: Additional mailmap file, typically from the command line
+    ///
+    /// # Returns
+    ///
+    /// Parsed mailmap, empty when neither source exists
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the repository cannot be opened or `file` cannot be
+    /// read
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gitkyl::Mailmap;
+    /// use std::path::Path;
+    ///
+    /// let mailmap = Mailmap::load(Path::new("."), None)?;
+    /// let (name, email) = mailmap.resolve("jdoe", "jdoe@old.example.com");
+    /// println!("{} <{}>", name, email);
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn load(repo_path: impl AsRef<Path>, file: Option<&Path>) -> Result<Self> {
+        let repo = gix::open(repo_path.as_ref()).with_context(|| {
+            format!(
+                "Failed to open repository at {}",
+                repo_path.as_ref().display()
+            )
+        })?;
+
+        let mut content = String::new();
+
+        // Unborn HEAD or missing .mailmap simply means no repository mailmap
+        if let Ok(commit) = repo.head_commit()
+            && let Ok(mut tree) = commit.tree()
+            && let Ok(Some(entry)) = tree.peel_to_entry_by_path(".mailmap")
+            && let Ok(object) = entry.object()
+        {
+            content.push_str(&object.data.to_str_lossy());
+            content.push('\n');
+        }
+
+        if let Some(path) = file {
+            let extra = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read mailmap file {}", path.display()))?;
+            content.push_str(&extra);
+        }
+
+        Ok(Self::parse(&content))
+    }
+
+    /// Returns the canonical name and email for a commit identity.
+    ///
+    /// Entries naming both the commit name and email are preferred over
+    /// entries matching the email alone. Identities without a mapping are
+    /// returned unchanged.
+    pub fn resolve(&self, name: &str, email: &str) -> (String, String) {
+        let entry = self
+            .find(|e| {
+                e.commit_name
+                    .as_deref()
+                    .is_some_and(|n| n.eq_ignore_ascii_case(name))
+                    && e.commit_email.eq_ignore_ascii_case(email)
+            })
+            .or_else(|| {
+                self.find(|e| e.commit_name.is_none() && e.commit_email.eq_ignore_ascii_case(email))
+            });
+
+        match entry {
+            Some(entry) => (
+                entry.proper_name.as_deref().unwrap_or(name).to_string(),
+                entry.proper_email.as_deref().unwrap_or(email).to_string(),
+            ),
+            None => (name.to_string(), email.to_string()),
+        }
+    }
+
+    /// Returns true if the mailmap has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Short content hash identifying the mailmap, empty when it has no entries.
+    ///
+    /// Used in the build manifest fingerprint so edits to the mailmap
+    /// regenerate every page showing an author.
+    pub fn fingerprint(&self) -> &str {
+        &self.fingerprint
+    }
+
+    /// Finds the last entry matching a predicate.
+    fn find(&self, predicate: impl Fn(&MailmapEntry) -> bool) -> Option<&MailmapEntry> {
+        self.entries.iter().rev().find(|e| predicate(e))
+    }
+}
+
+/// Parses one mailmap line into an entry.
+///
+/// A line holds an optional name followed by one or two `<email>` parts,
+/// with an optional commit name between them.
+fn parse_line(line: &str) -> Option<MailmapEntry> {
+    let line = match line.find('#') {
+        Some(idx) => &line[..idx],
+        None => line,
+    };
+
+    let (first_name, first_email, rest) = split_identity(line)?;
+    let second = split_identity(rest);
+
+    let non_empty = |s: &str| {
+        let s = s.trim();
+        (!s.is_empty()).then(|| s.to_string())
+    };
+
+    match second {
+        Some((commit_name, commit_email, _)) => Some(MailmapEntry {
+            proper_name: non_empty(first_name),
+            proper_email: non_empty(first_email),
+            commit_name: non_empty(commit_name),
+            commit_email: non_empty(commit_email)?,
+        }),
+        None => Some(MailmapEntry {
+            proper_name: non_empty(first_name),
+            proper_email: None,
+            commit_name: None,
+            commit_email: non_empty(first_email)?,
+        }),
+    }
+}
+
+/// Splits `Name <email> rest` into its name, email and remaining text.
+fn split_identity(text: &str) -> Option<(&str, &str, &str)> {
+    let open = text.find('<')?;
+    let close = open + text[open..].find('>')?;
+    Some((&text[..open], &text[open + 1..close], &text[close + 1..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn resolved(name: &str, email: &str) -> (String, String) {
+        (name.to_string(), email.to_string())
+    }
+
+    #[test]
+    fn test_parse_name_only_form() {
+        let mailmap = Mailmap::parse("Jane Doe <jane@example.com>\n");
+
+        assert_eq!(
+            mailmap.resolve("jdoe", "jane@example.com"),
+            resolved("Jane Doe", "jane@example.com")
+        );
+    }
+
+    #[test]
+    fn test_parse_email_only_form() {
+        let mailmap = Mailmap::parse("<jane@example.com> <jdoe@old.example.com>\n");
+
+        assert_eq!(
+            mailmap.resolve("Jane Doe", "jdoe@old.example.com"),
+            resolved("Jane Doe", "jane@example.com")
+        );
+    }
+
+    #[test]
+    fn test_parse_commit_name_form() {
+        let mailmap = Mailmap::parse(
+            "Jane Doe <jane@example.com> jdoe <shared@example.com>\n\
+             John Roe <john@example.com> jroe <shared@example.com>\n",
+        );
+
+        assert_eq!(
+            mailmap.resolve("jdoe", "shared@example.com"),
+            resolved("Jane Doe", "jane@example.com")
+        );
+        assert_eq!(
+            mailmap.resolve("JROE", "shared@example.com"),
+            resolved("John Roe", "john@example.com")
+        );
+        assert_eq!(
+            mailmap.resolve("someone", "shared@example.com"),
+            resolved("someone", "shared@example.com"),
+            "Name qualified entries should not match other names"
+        );
+    }
+
+    #[test]
+    fn test_resolve_case_insensitive_email() {
+        let mailmap = Mailmap::parse("Jane Doe <jane@example.com> <JDoe@Example.com>\n");
+
+        assert_eq!(
+            mailmap.resolve("jd", "jdoe@example.COM"),
+            resolved("Jane Doe", "jane@example.com")
+        );
+    }
+
+    #[test]
+    fn test_resolve_unmapped_identity() {
+        let mailmap = Mailmap::parse("Jane Doe <jane@example.com>\n");
+
+        assert_eq!(
+            mailmap.resolve("Other", "other@example.com"),
+            resolved("Other", "other@example.com")
+        );
+    }
+
+    #[test]
+    fn test_resolve_last_entry_wins() {
+        let mailmap = Mailmap::parse(
+            "Old Name <jane@example.com>\n\
+             New Name <jane@example.com>\n",
+        );
+
+        assert_eq!(
+            mailmap.resolve("jd", "jane@example.com"),
+            resolved("New Name", "jane@example.com")
+        );
+    }
+
+    #[test]
+    fn test_parse_ignores_comments_and_invalid_lines() {
+        let mailmap = Mailmap::parse(
+            "# Maintainers\n\
+             \n\
+             no email here\n\
+             Jane Doe <jane@example.com> # trailing comment\n\
+             <>\n",
+        );
+
+        assert_eq!(mailmap.entries.len(), 1);
+        assert_eq!(
+            mailmap.resolve("jd", "jane@example.com"),
+            resolved("Jane Doe", "jane@example.com")
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_empty_and_changes() {
+        assert!(Mailmap::default().fingerprint().is_empty());
+        assert!(Mailmap::parse("# only comments\n").fingerprint().is_empty());
+
+        let a = Mailmap::parse("A <a@example.com>\n");
+        let b = Mailmap::parse("B <a@example.com>\n");
+        assert!(!a.fingerprint().is_empty());
+        assert_ne!(a.fingerprint(), b.fingerprint());
+    }
+
+    #[test]
+    fn test_load_from_head_and_file() {
+        let td = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let repo = td.path();
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .current_dir(repo)
+                .output()
+                .expect("Failed to run git");
+        };
+        git(&["init"]);
+        git(&["config", "user.name", "Test"]);
+        git(&["config", "user.email", "test@test.com"]);
+        std::fs::write(
+            repo.join(".mailmap"),
+            "Jane Doe <jane@example.com> <jdoe@old.example.com>\n",
+        )
+        .expect("Failed to write .mailmap");
+        git(&["add", "."]);
+        git(&["commit", "-m", "Add mailmap"]);
+
+        let head_only = Mailmap::load(repo, None).expect("Should load mailmap");
+        assert_eq!(
+            head_only.resolve("jdoe", "jdoe@old.example.com"),
+            resolved("Jane Doe", "jane@example.com")
+        );
+
+        let extra = td.path().join("extra.mailmap");
+        std::fs::write(&extra, "J. Doe <jane@example.com> <jdoe@old.example.com>\n")
+            .expect("Failed to write extra mailmap");
+
+        let layered = Mailmap::load(repo, Some(&extra)).expect("Should load mailmap");
+        assert_eq!(
+            layered.resolve("jdoe", "jdoe@old.example.com"),
+            resolved("J. Doe", "jane@example.com"),
+            "Command line mailmap should override HEAD .mailmap"
+        );
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let repo_path = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let result = Mailmap::load(repo_path, Some(Path::new("/nonexistent/.mailmap")));
+        assert!(result.is_err());
+    }
+}
@@ -24,6 +24,36 @@ impl BranchStats {
     }
 }
 
+/// Manifest entry (page path, input key) produced by a generation job.
+type PageRecord = (String, String);
+
+/// Symlink targets by link path, with their location inside the tree.
+type SymlinkMap = std::collections::HashMap<String, (String, Option<gitkyl::SymlinkTarget>)>;
+
+/// History of a ref shared by all of its file and directory pages.
+struct RefHistory<'a> {
+    /// Last commit of each file and directory path
+    last_commits: &'a std::collections::HashMap<String, gitkyl::CommitInfo>,
+    /// Commits of the ref, children first
+    order: &'a gitkyl::CommitOrder,
+}
+
+/// Facts about a ref's files looked up once for all blob jobs.
+struct BlobLookups<'a> {
+    /// Symlink targets by link path
+    symlinks: &'a SymlinkMap,
+    /// Whether the object of each LFS pointer blob is in the local store
+    lfs_objects: &'a std::collections::HashMap<gix::ObjectId, bool>,
+}
+
+/// Pages written by a blob job and the manifest entries to record.
+#[derive(Debug, Default)]
+struct BlobOutcome {
+    blob_pages: usize,
+    markdown_pages: usize,
+    records: Vec<PageRecord>,
+}
+
 fn validate_tree_path(path: &str) -> Result<()> {
     if path.contains("..") {
         anyhow::bail!("Path contains directory traversal: {}", path);
@@ -57,28 +87,33 @@ fn setup_output_directories(output_dir: &std::path::Path) -> Result<()> {
     Ok(())
 }
 
-/// Builds tree items from file entries and subdirectories.
+/// Builds tree items from file entries, submodules and subdirectories.
 ///
-/// Combines directory and file entries into a unified list of tree items,
-/// each annotated with last commit information from pre-fetched maps.
+/// Combines directory, submodule and file entries into a unified list of
+/// tree items, each annotated with last commit information from the
+/// pre-fetched map.
 ///
 /// # Arguments
 ///
 /// * `file_entries`: File entries at current level
+/// * `submodules`: Submodule entries at current level
 /// * `subdir_names`: Subdirectory names at current level
 /// * `dir_path`: Current directory path for constructing full paths
-/// * `file_commit_map`: Mapping of file paths to last commits
-/// * `dir_commit_map`: Mapping of directory paths to last commits
+/// * `commit_map`: Mapping of file and directory paths to last commits
+/// * `nested_sites`: Submodule paths with a generated nested site
+/// * `symlinks`: Symlink targets by link path
 ///
 /// # Returns
 ///
-/// Vector of tree items combining directories and files with commits
+/// Vector of tree items combining directories, submodules, symlinks and files
 fn build_tree_items(
     file_entries: &[gitkyl::FileEntry],
+    submodules: &[gitkyl::SubmoduleEntry],
     subdir_names: &[&str],
     dir_path: &str,
-    file_commit_map: &std::collections::HashMap<String, gitkyl::CommitInfo>,
-    dir_commit_map: &std::collections::HashMap<String, gitkyl::CommitInfo>,
+    commit_map: &std::collections::HashMap<String, gitkyl::CommitInfo>,
+    nested_sites: &std::collections::HashSet<String>,
+    symlinks: &SymlinkMap,
 ) -> Vec<TreeItem> {
     let mut items = Vec::new();
 
@@ -90,7 +125,7 @@ fn build_tree_items(
             format!("{}/{}", dir_path, subdir)
         };
 
-        if let Some(commit) = dir_commit_map.get(&full_path) {
+        if let Some(commit) = commit_map.get(&full_path) {
             items.push(TreeItem::Directory {
                 name: subdir.to_string(),
                 full_path,
@@ -101,16 +136,48 @@ fn build_tree_items(
         }
     }
 
+    // Build submodule items with pre-fetched commits
+    for submodule in submodules {
+        if let Some(path) = submodule.path()
+            && let Some(path_str) = path.to_str()
+        {
+            if let Some(commit) = commit_map.get(path_str) {
+                let name = path_str.rsplit('/').next().unwrap_or(path_str);
+                items.push(TreeItem::Submodule {
+                    name: name.to_string(),
+                    full_path: path_str.to_string(),
+                    oid: submodule.oid().to_string(),
+                    url: submodule.url().map(String::from),
+                    has_site: nested_sites.contains(path_str),
+                    commit: commit.clone(),
+                });
+            } else {
+                eprintln!("Warning: No commit found for submodule {}", path_str);
+            }
+        }
+    }
+
     // Build file items with pre-fetched commits
     for entry in file_entries {
         if let Some(path) = entry.path()
             && let Some(path_str) = path.to_str()
         {
-            if let Some(commit) = file_commit_map.get(path_str) {
-                items.push(TreeItem::File {
-                    entry: entry.clone(),
-                    commit: commit.clone(),
-                });
+            if let Some(commit) = commit_map.get(path_str) {
+                if entry.is_symlink()
+                    && let Some((target, resolved)) = symlinks.get(path_str)
+                {
+                    items.push(TreeItem::Symlink {
+                        entry: entry.clone(),
+                        target: target.clone(),
+                        resolved: resolved.clone(),
+                        commit: commit.clone(),
+                    });
+                } else {
+                    items.push(TreeItem::File {
+                        entry: entry.clone(),
+                        commit: commit.clone(),
+                    });
+                }
             } else {
                 eprintln!("Warning: No commit found for file {}", path_str);
             }
@@ -120,6 +187,49 @@ fn build_tree_items(
     items
 }
 
+/// Reads and resolves the targets of all symlinks in a tree.
+///
+/// Symlinks whose blob cannot be read are left out with a warning and are
+/// then listed as regular files.
+///
+/// # Arguments
+///
+/// * `config`: Application configuration containing the repository path
+/// * `ref_name`: Branch or tag the tree was read from
+/// * `tree`: File tree of the ref
+///
+/// # Returns
+///
+/// Mapping of symlink paths to their target and resolved location
+fn symlink_targets(config: &Config, ref_name: &str, tree: &gitkyl::FileTree) -> SymlinkMap {
+    let mut symlinks = SymlinkMap::new();
+
+    for entry in tree.all_files_under("") {
+        if !entry.is_symlink() {
+            continue;
+        }
+        let Some(path) = entry.path() else {
+            continue;
+        };
+        let Some(path_str) = path.to_str() else {
+            continue;
+        };
+
+        match gitkyl::read_blob(&config.repo, Some(ref_name), path) {
+            Ok(bytes) => {
+                let target = String::from_utf8_lossy(&bytes).into_owned();
+                let resolved = tree.resolve_symlink(path_str, &target);
+                symlinks.insert(path_str.to_string(), (target, resolved));
+            }
+            Err(e) => {
+                eprintln!("Warning: Failed to read symlink {}: {:#}", path_str, e);
+            }
+        }
+    }
+
+    symlinks
+}
+
 /// Generates tree pages for all directories in a branch.
 ///
 /// Creates index pages for the repository root and tree pages for all
@@ -132,7 +242,9 @@ fn build_tree_items(
 /// * `repo_info`: Repository metadata including name and branches
 /// * `branch`: Branch name to generate tree pages for
 /// * `tree`: File tree structure for the branch
-/// * `file_commit_map`: Pre-fetched mapping of file paths to last commits
+/// * `commit_map`: Pre-fetched mapping of file and directory paths to last commits
+/// * `nested_sites`: Submodule paths with a generated nested site
+/// * `symlinks`: Symlink targets by link path
 ///
 /// # Returns
 ///
@@ -146,61 +258,45 @@ fn generate_tree_pages_for_branch(
     repo_info: &gitkyl::RepoInfo,
     branch: &str,
     tree: &gitkyl::FileTree,
-    file_commit_map: &std::collections::HashMap<String, gitkyl::CommitInfo>,
+    commit_map: &std::collections::HashMap<String, gitkyl::CommitInfo>,
+    nested_sites: &std::collections::HashSet<String>,
+    symlinks: &SymlinkMap,
 ) -> Result<usize> {
     let directories = tree.all_dirs();
-    let mut count = 0;
 
-    let commits = gitkyl::list_commits(&config.repo, Some(branch), Some(DEFAULT_COMMIT_LIMIT))
-        .unwrap_or_else(|e| {
-            eprintln!(
-                "Warning: Failed to list commits for branch {}: {:#}",
-                branch, e
-            );
-            vec![]
-        });
+    let commits = gitkyl::list_commits_with_mailmap(
+        &config.repo,
+        Some(branch),
+        Some(DEFAULT_COMMIT_LIMIT),
+        repo_info.mailmap(),
+    )
+    .unwrap_or_else(|e| {
+        eprintln!(
+            "Warning: Failed to list commits for branch {}: {:#}",
+            branch, e
+        );
+        vec![]
+    });
 
     let latest_commit = commits.first();
+    // Tags are always archived, branches only on request
+    let archive = (config.branch_archives || !repo_info.branches().iter().any(|b| b == branch))
+        .then(|| gitkyl::archive_name(repo_info.name(), branch));
 
-    for dir_path in directories {
-        validate_tree_path(&dir_path)
-            .with_context(|| format!("Invalid tree path: {}", dir_path))?;
+    let results = gitkyl::parallel_map(&directories, config.job_count(), |dir_path| {
+        validate_tree_path(dir_path).with_context(|| format!("Invalid tree path: {}", dir_path))?;
 
-        let entries_at_this_level = tree.files_at(&dir_path);
-        let subdirs_at_this_level = tree.subdirs_at(&dir_path);
-
-        let full_dir_paths: Vec<String> = subdirs_at_this_level
-            .iter()
-            .map(|subdir| {
-                if dir_path.is_empty() {
-                    subdir.to_string()
-                } else {
-                    format!("{}/{}", dir_path, subdir)
-                }
-            })
-            .collect();
-
-        let dir_path_refs: Vec<&str> = full_dir_paths.iter().map(|s| s.as_str()).collect();
-
-        let level_dir_commit_map = if !dir_path_refs.is_empty() {
-            gitkyl::get_last_commits_batch(&config.repo, Some(branch), &dir_path_refs)
-                .unwrap_or_else(|e| {
-                    eprintln!(
-                        "Warning: Failed to batch lookup directory commits for {}: {:#}",
-                        dir_path, e
-                    );
-                    std::collections::HashMap::new()
-                })
-        } else {
-            std::collections::HashMap::new()
-        };
+        let entries_at_this_level = tree.files_at(dir_path);
+        let subdirs_at_this_level = tree.subdirs_at(dir_path);
 
         let tree_items_for_page = build_tree_items(
             entries_at_this_level,
+            tree.submodules_at(dir_path),
             &subdirs_at_this_level,
-            &dir_path,
-            file_commit_map,
-            &level_dir_commit_map,
+            dir_path,
+            commit_map,
+            nested_sites,
+            symlinks,
         );
 
         let html_result = if dir_path.is_empty() {
@@ -221,16 +317,22 @@ fn generate_tree_pages_for_branch(
                 branches: repo_info.branches(),
                 commit_count: commits.len(),
                 tag_count: 0,
+                contributor_count: 0,
                 latest_commit,
                 items: &tree_items_for_page,
                 readme_html: readme_html.as_deref(),
                 depth,
+                archive: archive.as_deref(),
+                activity: None,
+                languages: None,
+                clone_url: None,
+                latest_release: None,
             }))
         } else {
             gitkyl::pages::tree::generate(
                 &config.repo,
                 branch,
-                &dir_path,
+                dir_path,
                 repo_info.name(),
                 &tree_items_for_page,
             )
@@ -256,16 +358,19 @@ fn generate_tree_pages_for_branch(
                     format!("Failed to write tree page {}", tree_path.display())
                 })?;
 
-                count += 1;
+                Ok(())
             }
             Err(e) => {
-                return Err(e)
-                    .with_context(|| format!("Failed to generate tree page for {}", dir_path));
+                Err(e).with_context(|| format!("Failed to generate tree page for {}", dir_path))
             }
         }
+    });
+
+    for result in results {
+        result?;
     }
 
-    Ok(count)
+    Ok(directories.len())
 }
 
 /// Generates blob pages for all files in a branch.
@@ -274,7 +379,8 @@ fn generate_tree_pages_for_branch(
 /// handling for markdown files. README files are rendered with full markdown
 /// processing, while code files receive syntax highlighting. Image files
 /// are copied as raw files alongside their HTML viewer pages for use in
-/// markdown image references.
+/// markdown image references. Files are processed in parallel; manifest
+/// entries are applied once all jobs finish.
 ///
 /// # Arguments
 ///
@@ -282,6 +388,9 @@ fn generate_tree_pages_for_branch(
 /// * `repo_info`: Repository metadata including name
 /// * `branch`: Branch name to generate blob pages for
 /// * `files`: File entries to process
+/// * `history`: Last commits and commit order of the branch
+/// * `symlinks`: Symlink targets by link path
+/// * `manifest`: Build manifest used to skip unchanged blobs
 ///
 /// # Returns
 ///
@@ -295,133 +404,402 @@ fn generate_blob_pages_for_branch(
     repo_info: &gitkyl::RepoInfo,
     branch: &str,
     files: &[gitkyl::FileEntry],
+    history: &RefHistory,
+    symlinks: &SymlinkMap,
+    manifest: &mut gitkyl::Manifest,
 ) -> Result<(usize, usize)> {
+    let lfs_objects =
+        gitkyl::lfs_object_states(&config.repo, files.iter().map(|f| f.oid().as_ref()))
+            .unwrap_or_else(|e| {
+                eprintln!("Warning: Failed to check LFS pointers: {:#}", e);
+                std::collections::HashMap::new()
+            });
+    let lookups = BlobLookups {
+        symlinks,
+        lfs_objects: &lfs_objects,
+    };
+
+    let shared: &gitkyl::Manifest = manifest;
+    let outcomes = gitkyl::parallel_map(files, config.job_count(), |entry| {
+        generate_blob_page(config, repo_info, branch, entry, history, &lookups, shared)
+    });
+
     let mut blob_count = 0;
     let mut markdown_count = 0;
 
-    for entry in files {
-        if let Some(path) = entry.path() {
-            if path.to_str().is_none() {
-                eprintln!(
-                    "Warning: Skipping file with invalid UTF-8 path: {}",
-                    path.display()
-                );
-                continue;
+    for outcome in outcomes {
+        let outcome = outcome?;
+        blob_count += outcome.blob_pages;
+        markdown_count += outcome.markdown_pages;
+        for (page, key) in &outcome.records {
+            manifest.record(page, key);
+        }
+    }
+
+    Ok((blob_count, markdown_count))
+}
+
+/// Generates the blob and blame pages of a single file.
+///
+/// Symlinks get a page showing their target and no blame page.
+///
+/// # Arguments
+///
+/// * `config`: Application configuration including output path and theme
+/// * `repo_info`: Repository metadata including name
+/// * `branch`: Branch name to generate blob pages for
+/// * `entry`: File entry to render
+/// * `history`: Last commits and commit order of the branch
+/// * `lookups`: Symlink targets and LFS object states of the branch
+/// * `manifest`: Build manifest used to skip unchanged pages
+///
+/// # Returns
+///
+/// Pages written and the manifest entries to record for them
+///
+/// # Errors
+///
+/// Returns error if page generation or file writing fails
+fn generate_blob_page(
+    config: &Config,
+    repo_info: &gitkyl::RepoInfo,
+    branch: &str,
+    entry: &gitkyl::FileEntry,
+    history: &RefHistory,
+    lookups: &BlobLookups,
+    manifest: &gitkyl::Manifest,
+) -> Result<BlobOutcome> {
+    let mut outcome = BlobOutcome::default();
+
+    let Some(path) = entry.path() else {
+        return Ok(outcome);
+    };
+    let Some(path_str) = path.to_str() else {
+        eprintln!(
+            "Warning: Skipping file with invalid UTF-8 path: {}",
+            path.display()
+        );
+        return Ok(outcome);
+    };
+
+    // Blob pages depend only on content; blame also on the last change
+    let blob_page = format!("blob/{}/{}.html", branch, path_str);
+    // LFS pointers keep their OID when the object is fetched, so the key
+    // also records whether the object is local; objects are content addressed
+    let blob_key = match lookups.lfs_objects.get(entry.oid()) {
+        Some(true) => format!("{} lfs=fetched", entry.oid()),
+        Some(false) => format!("{} lfs=missing", entry.oid()),
+        None => entry.oid().to_string(),
+    };
+    let blame_key = match history.last_commits.get(path_str) {
+        Some(commit) => format!("{} {}", blob_key, commit.oid()),
+        None => String::new(),
+    };
+
+    if entry.is_symlink() {
+        // Symlink pages also depend on where the target resolves in the tree
+        let resolved = lookups
+            .symlinks
+            .get(path_str)
+            .and_then(|(_, resolved)| resolved.as_ref());
+        let symlink_key = match resolved {
+            Some(gitkyl::SymlinkTarget::File(target)) => format!("{} file:{}", blob_key, target),
+            Some(gitkyl::SymlinkTarget::Directory(target)) => {
+                format!("{} dir:{}", blob_key, target)
             }
+            None => format!("{} external", blob_key),
+        };
 
-            let result = if gitkyl::is_markdown(path) {
-                markdown_count += 1;
+        if !manifest.is_fresh(&blob_page, &symlink_key) {
+            let html = gitkyl::pages::blob::generate_symlink(
+                &config.repo,
+                branch,
+                path,
+                repo_info.name(),
+                resolved,
+            )?;
 
-                // Generate rendered markdown view
-                let rendered = gitkyl::pages::blob::generate_markdown(
-                    &config.repo,
-                    branch,
-                    path,
-                    repo_info.name(),
-                )?;
+            let blob_path = config
+                .output
+                .join("blob")
+                .join(branch)
+                .join(format!("{}.html", path.display()));
 
-                let blob_path = config
-                    .output
-                    .join("blob")
-                    .join(branch)
-                    .join(format!("{}.html", path.display()));
+            if let Some(parent) = blob_path.parent() {
+                fs::create_dir_all(parent).context("Failed to create blob directory")?;
+            }
 
-                if let Some(parent) = blob_path.parent() {
-                    fs::create_dir_all(parent).context("Failed to create blob directory")?;
-                }
+            fs::write(&blob_path, html.into_string())
+                .with_context(|| format!("Failed to write blob page {}", blob_path.display()))?;
 
-                fs::write(&blob_path, rendered.into_string()).with_context(|| {
-                    format!("Failed to write blob page {}", blob_path.display())
-                })?;
+            outcome.records.push((blob_page, symlink_key));
+            outcome.blob_pages += 1;
+        }
+        return Ok(outcome);
+    }
 
-                // Generate source view for markdown files
-                let source = gitkyl::pages::blob::generate_markdown_source(
-                    &config.repo,
-                    branch,
-                    path,
-                    repo_info.name(),
-                    &config.theme,
-                )?;
+    if manifest.is_fresh(&blob_page, &blob_key) {
+        outcome.records.extend(write_blame_page(
+            config,
+            repo_info,
+            branch,
+            history.order,
+            path,
+            &blame_key,
+            manifest,
+        )?);
+        outcome
+            .records
+            .extend(write_raw_file(config, branch, path, &blob_key, manifest)?);
+        return Ok(outcome);
+    }
 
-                let source_path = config
-                    .output
-                    .join("blob")
-                    .join(branch)
-                    .join(format!("{}.source.html", path.display()));
+    let result = if gitkyl::is_markdown(path) {
+        outcome.markdown_pages += 1;
 
-                fs::write(&source_path, source.into_string()).with_context(|| {
-                    format!("Failed to write source page {}", source_path.display())
-                })?;
+        // Generate rendered markdown view
+        let rendered =
+            gitkyl::pages::blob::generate_markdown(&config.repo, branch, path, repo_info.name())?;
 
-                blob_count += 1;
-                continue;
-            } else {
-                gitkyl::pages::blob::generate(
-                    &config.repo,
-                    branch,
-                    path,
-                    repo_info.name(),
-                    &config.theme,
-                )
-            };
+        let blob_path = config
+            .output
+            .join("blob")
+            .join(branch)
+            .join(format!("{}.html", path.display()));
 
-            match result {
-                Ok(html) => {
-                    let blob_path = config
-                        .output
-                        .join("blob")
-                        .join(branch)
-                        .join(format!("{}.html", path.display()));
+        if let Some(parent) = blob_path.parent() {
+            fs::create_dir_all(parent).context("Failed to create blob directory")?;
+        }
 
-                    if let Some(parent) = blob_path.parent() {
-                        fs::create_dir_all(parent).context("Failed to create blob directory")?;
-                    }
+        fs::write(&blob_path, rendered.into_string())
+            .with_context(|| format!("Failed to write blob page {}", blob_path.display()))?;
 
-                    fs::write(&blob_path, html.into_string()).with_context(|| {
-                        format!("Failed to write blob page {}", blob_path.display())
-                    })?;
+        // Generate source view for markdown files
+        let source = gitkyl::pages::blob::generate_markdown_source(
+            &config.repo,
+            branch,
+            path,
+            repo_info.name(),
+            &config.theme,
+        )?;
+
+        let source_path = config
+            .output
+            .join("blob")
+            .join(branch)
+            .join(format!("{}.source.html", path.display()));
+
+        fs::write(&source_path, source.into_string())
+            .with_context(|| format!("Failed to write source page {}", source_path.display()))?;
+
+        outcome.records.extend(write_blame_page(
+            config,
+            repo_info,
+            branch,
+            history.order,
+            path,
+            &blame_key,
+            manifest,
+        )?);
+        outcome
+            .records
+            .extend(write_raw_file(config, branch, path, &blob_key, manifest)?);
+        outcome.records.push((blob_page, blob_key));
+
+        outcome.blob_pages += 1;
+        return Ok(outcome);
+    } else {
+        gitkyl::pages::blob::generate(&config.repo, branch, path, repo_info.name(), &config.theme)
+    };
 
-                    // Copy raw image files for markdown image references
-                    if let Ok(bytes) = gitkyl::read_blob(&config.repo, Some(branch), path)
-                        && let gitkyl::FileType::Image(_) = gitkyl::detect_file_type(&bytes, path)
-                    {
-                        let raw_path = config.output.join("blob").join(branch).join(path);
+    match result {
+        Ok(html) => {
+            let blob_path = config
+                .output
+                .join("blob")
+                .join(branch)
+                .join(format!("{}.html", path.display()));
 
-                        if let Some(parent) = raw_path.parent() {
-                            fs::create_dir_all(parent)
-                                .context("Failed to create raw image directory")?;
-                        }
+            if let Some(parent) = blob_path.parent() {
+                fs::create_dir_all(parent).context("Failed to create blob directory")?;
+            }
 
-                        fs::write(&raw_path, &bytes).with_context(|| {
-                            format!("Failed to write raw image {}", raw_path.display())
-                        })?;
-                    }
+            fs::write(&blob_path, html.into_string())
+                .with_context(|| format!("Failed to write blob page {}", blob_path.display()))?;
 
-                    blob_count += 1;
-                }
-                Err(e) => {
-                    let err_msg = format!("{:?}", e);
-                    if err_msg.contains("not a blob") {
-                        continue;
-                    }
-                    return Err(e).with_context(|| {
-                        format!("Failed to generate blob page for {}", path.display())
-                    });
-                }
+            outcome.records.extend(write_blame_page(
+                config,
+                repo_info,
+                branch,
+                history.order,
+                path,
+                &blame_key,
+                manifest,
+            )?);
+            outcome
+                .records
+                .extend(write_raw_file(config, branch, path, &blob_key, manifest)?);
+
+            outcome.records.push((blob_page, blob_key));
+            outcome.blob_pages += 1;
+            Ok(outcome)
+        }
+        Err(e) => {
+            let err_msg = format!("{:?}", e);
+            if err_msg.contains("not a blob") {
+                return Ok(outcome);
             }
+            Err(e).with_context(|| format!("Failed to generate blob page for {}", path.display()))
         }
     }
+}
 
-    Ok((blob_count, markdown_count))
+/// Writes the raw content of a file to `raw/<ref>/<path>`.
+///
+/// Serves the Raw and Download buttons of blob pages and markdown image
+/// references. Git LFS pointers are replaced by their local object, and
+/// get no raw file when the object has not been fetched.
+///
+/// # Arguments
+///
+/// * `config`: Application configuration
+/// * `branch`: Branch name
+/// * `path`: File path within repository tree
+/// * `key`: Manifest key (blob OID and LFS object state)
+/// * `manifest`: Build manifest used to skip unchanged files
+///
+/// # Returns
+///
+/// Manifest entry to record if a raw file was written
+///
+/// # Errors
+///
+/// Returns error if the blob cannot be read or the file cannot be written
+fn write_raw_file(
+    config: &Config,
+    branch: &str,
+    path: &std::path::Path,
+    key: &str,
+    manifest: &gitkyl::Manifest,
+) -> Result<Option<PageRecord>> {
+    let page = format!("raw/{}/{}", branch, path.display());
+    if manifest.is_fresh(&page, key) {
+        return Ok(None);
+    }
+
+    let bytes = gitkyl::read_blob(&config.repo, Some(branch), path)
+        .with_context(|| format!("Failed to read blob {}", path.display()))?;
+    let Some(bytes) = resolve_lfs_content(&config.repo, bytes) else {
+        return Ok(None);
+    };
+
+    let raw_path = config.output.join("raw").join(branch).join(path);
+    if let Some(parent) = raw_path.parent() {
+        fs::create_dir_all(parent).context("Failed to create raw directory")?;
+    }
+
+    fs::write(&raw_path, &bytes)
+        .with_context(|| format!("Failed to write raw file {}", raw_path.display()))?;
+
+    Ok(Some((page, key.to_string())))
+}
+
+/// Replaces Git LFS pointer content with the object from the local store.
+///
+/// Returns content unchanged when it is not a pointer, and None when the
+/// pointer's object has not been fetched.
+fn resolve_lfs_content(repo: &std::path::Path, bytes: Vec<u8>) -> Option<Vec<u8>> {
+    match gitkyl::LfsPointer::parse(&bytes) {
+        Some(pointer) => gitkyl::read_lfs_object(repo, &pointer).ok().flatten(),
+        None => Some(bytes),
+    }
+}
+
+/// Generates blame page for a text file on a branch.
+///
+/// Binary files, images and blobs with invalid UTF-8 are skipped since
+/// they have no meaningful line attribution. Pages whose manifest key is
+/// unchanged are left as they are.
+///
+/// # Arguments
+///
+/// * `config`: Application configuration
+/// * `repo_info`: Repository metadata
+/// * `branch`: Branch name
+/// * `order`: Commits of the branch, children first
+/// * `path`: File path within repository tree
+/// * `key`: Manifest key combining blob OID and last commit
+/// * `manifest`: Build manifest used to skip unchanged pages
+///
+/// # Returns
+///
+/// Manifest entry to record if a blame page was written
+///
+/// # Errors
+///
+/// Returns error if blame computation or file writes fail
+fn write_blame_page(
+    config: &Config,
+    repo_info: &gitkyl::RepoInfo,
+    branch: &str,
+    order: &gitkyl::CommitOrder,
+    path: &std::path::Path,
+    key: &str,
+    manifest: &gitkyl::Manifest,
+) -> Result<Option<PageRecord>> {
+    let page = format!("blame/{}/{}.html", branch, path.display());
+    if manifest.is_fresh(&page, key) {
+        return Ok(None);
+    }
+
+    let bytes = gitkyl::read_blob(&config.repo, Some(branch), path)
+        .with_context(|| format!("Failed to read blob {}", path.display()))?;
+
+    if gitkyl::detect_file_type(&bytes, path) != gitkyl::FileType::Text
+        || std::str::from_utf8(&bytes).is_err()
+    {
+        return Ok(None);
+    }
+
+    let html = gitkyl::pages::blame::generate(
+        &config.repo,
+        branch,
+        order,
+        path,
+        repo_info.name(),
+        &config.theme,
+        repo_info.mailmap(),
+    )
+    .with_context(|| format!("Failed to generate blame page for {}", path.display()))?;
+
+    let blame_path = config
+        .output
+        .join("blame")
+        .join(branch)
+        .join(format!("{}.html", path.display()));
+
+    if let Some(parent) = blame_path.parent() {
+        fs::create_dir_all(parent).context("Failed to create blame directory")?;
+    }
+
+    fs::write(&blame_path, html.into_string())
+        .with_context(|| format!("Failed to write blame page {}", blame_path.display()))?;
+
+    Ok(Some((page, key.to_string())))
 }
 
 /// Generates commits log page for a branch with pagination.
 ///
+/// The commit graph is laid out over the whole history before it is split
+/// into pages, so lanes continue across page boundaries. When the history
+/// is limited, the last page ends with a marker saying why.
+///
 /// # Arguments
 ///
 /// * `config`: Application configuration containing output path
 /// * `repo_info`: Repository metadata including name and commit count
 /// * `branch`: Branch name to generate commits page for
+/// * `covered`: Commits of the default branch to stop at, if enabled
 ///
 /// # Errors
 ///
@@ -430,17 +808,39 @@ fn generate_commits_page_for_branch(
     config: &Config,
     repo_info: &gitkyl::RepoInfo,
     branch: &str,
+    covered: Option<&std::collections::HashSet<String>>,
 ) -> Result<()> {
     let commits_dir = config.output.join("commits").join(branch);
     fs::create_dir_all(&commits_dir).context("Failed to create commits directory")?;
 
-    let total_commits = repo_info.commit_count();
-    let mut page = 1;
+    let mut commits = gitkyl::list_commits_topo(&config.repo, Some(branch), repo_info.mailmap())
+        .context("Failed to list commits")?;
 
-    loop {
-        let paginated =
-            gitkyl::list_commits_paginated(&config.repo, Some(branch), page, DEFAULT_COMMIT_LIMIT)
-                .context("Failed to list paginated commits")?;
+    // Lanes are laid out over the full history so cut edges still point down
+    let mut graph = gitkyl::graph_layout(&commits, repo_info.ref_labels());
+    let (kept, truncation) = config.history_limit().cutoff(
+        &commits,
+        covered.map(|oids| (repo_info.default_branch(), oids)),
+    );
+    commits.truncate(kept);
+    graph.truncate(kept);
+    annotate_commits(config, repo_info, &mut commits);
+
+    let total_commits = repo_info.commit_count();
+    let page_count = commits.len().div_ceil(DEFAULT_COMMIT_LIMIT).max(1);
+    let mut commits = commits.into_iter();
+
+    for page in 1..=page_count {
+        let page_commits: Vec<_> = commits.by_ref().take(DEFAULT_COMMIT_LIMIT).collect();
+        let page_graph: Vec<_> = graph.drain(..page_commits.len()).collect();
+        let paginated = gitkyl::PaginatedCommits::new(
+            page_commits,
+            page,
+            DEFAULT_COMMIT_LIMIT,
+            page < page_count,
+        )
+        .with_graph(page_graph)
+        .with_truncation(truncation.clone().filter(|_| page == page_count));
 
         let commits_html =
             gitkyl::pages::commits::generate(&paginated, branch, repo_info.name(), total_commits);
@@ -448,186 +848,1289 @@ fn generate_commits_page_for_branch(
         let page_path = commits_dir.join(format!("page-{}.html", page));
         fs::write(&page_path, commits_html.into_string())
             .with_context(|| format!("Failed to write commits page to {}", page_path.display()))?;
+    }
+
+    Ok(())
+}
+
+/// Generates history pages for every file and directory in a branch.
+///
+/// Each path gets paginated pages at `history/<branch>/<path>/page-N.html`
+/// listing the commits that changed it. Renames are followed for files when
+/// enabled in the configuration. Paths whose last commit is unchanged since
+/// the previous run keep their existing pages.
+///
+/// # Arguments
+///
+/// * `config`: Application configuration
+/// * `repo_info`: Repository metadata including name
+/// * `branch`: Branch name to generate history pages for
+/// * `tree`: File tree of the branch
+/// * `history`: Last commits and commit order of the branch
+/// * `manifest`: Build manifest used to skip unchanged paths
+///
+/// # Returns
+///
+/// Count of paths whose history pages were written
+///
+/// # Errors
+///
+/// Returns error if history listing or page writing fails
+fn generate_history_pages_for_branch(
+    config: &Config,
+    repo_info: &gitkyl::RepoInfo,
+    branch: &str,
+    tree: &gitkyl::FileTree,
+    history: &RefHistory,
+    manifest: &mut gitkyl::Manifest,
+) -> Result<usize> {
+    let dirs: Vec<String> = tree
+        .all_dirs()
+        .into_iter()
+        .filter(|dir| !dir.is_empty())
+        .collect();
+
+    let files = tree
+        .all_files_under("")
+        .into_iter()
+        .filter_map(|entry| entry.path()?.to_str().map(String::from));
+    let paths: Vec<(String, bool)> = dirs
+        .into_iter()
+        .map(|dir| (dir, true))
+        .chain(files.map(|file| (file, false)))
+        .collect();
+
+    let shared: &gitkyl::Manifest = manifest;
+    let results = gitkyl::parallel_map(
+        &paths,
+        config.job_count(),
+        |(path, is_dir)| -> Result<Option<PageRecord>> {
+            let last_commit = history
+                .last_commits
+                .get(path)
+                .map(|commit| commit.oid().to_string())
+                .unwrap_or_default();
+            let first_page = format!("history/{}/{}/page-1.html", branch, path);
+            if repo_info.notes().is_empty() && shared.is_fresh(&first_page, &last_commit) {
+                return Ok(None);
+            }
+
+            let follow_renames = config.follow_renames && !is_dir;
+            let mut commits = gitkyl::list_path_commits(
+                &config.repo,
+                history.order,
+                path,
+                follow_renames,
+                None,
+                repo_info.mailmap(),
+            )
+            .with_context(|| format!("Failed to list history for {}", path))?;
+
+            // Listed commits are only known after the walk, so pages showing
+            // notes are checked again once their notes can be keyed
+            let key = format!(
+                "{}{}",
+                last_commit,
+                notes_key(repo_info, commits.iter().map(|commit| commit.oid()))
+            );
+            if shared.is_fresh(&first_page, &key) {
+                return Ok(None);
+            }
+            annotate_commits(config, repo_info, &mut commits);
+
+            let history_dir = config.output.join("history").join(branch).join(path);
+            fs::create_dir_all(&history_dir).context("Failed to create history directory")?;
+
+            let page_count = commits.len().div_ceil(DEFAULT_COMMIT_LIMIT).max(1);
+            let mut chunks = commits.chunks(DEFAULT_COMMIT_LIMIT);
+
+            for page in 1..=page_count {
+                let chunk = chunks.next().unwrap_or_default().to_vec();
+                let paginated = gitkyl::PaginatedCommits::new(
+                    chunk,
+                    page,
+                    DEFAULT_COMMIT_LIMIT,
+                    page < page_count,
+                );
+
+                let html = gitkyl::pages::history::generate(
+                    &paginated,
+                    branch,
+                    path,
+                    *is_dir,
+                    repo_info.name(),
+                );
+
+                let page_path = history_dir.join(format!("page-{}.html", page));
+                fs::write(&page_path, html.into_string()).with_context(|| {
+                    format!("Failed to write history page to {}", page_path.display())
+                })?;
+            }
+
+            Ok(Some((first_page, key)))
+        },
+    );
+
+    let mut count = 0;
+    for result in results {
+        if let Some((page, key)) = result? {
+            manifest.record(&page, &key);
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
+
+/// Generates all pages for a single branch.
+///
+/// Orchestrates generation of tree pages, blob pages, and history pages for
+/// the specified branch. Returns statistics for reporting.
+///
+/// # Arguments
+///
+/// * `config`: CLI configuration
+/// * `repo_info`: Repository metadata
+/// * `branch`: Branch name to generate for
+/// * `history`: Last commits and commit order of the branch
+/// * `nested_sites`: Submodule paths with a generated nested site
+/// * `manifest`: Build manifest used to skip unchanged pages
+///
+/// # Returns
+///
+/// Statistics about generated pages
+///
+/// # Errors
+///
+/// Returns error if any critical generation step fails
+fn generate_all_pages_for_branch(
+    config: &Config,
+    repo_info: &gitkyl::RepoInfo,
+    branch: &str,
+    history: &RefHistory,
+    nested_sites: &std::collections::HashSet<String>,
+    manifest: &mut gitkyl::Manifest,
+) -> Result<BranchStats> {
+    let files = gitkyl::list_files(&config.repo, Some(branch)).context("Failed to list files")?;
+    let submodules =
+        gitkyl::list_submodules(&config.repo, Some(branch)).context("Failed to list submodules")?;
+
+    let tree = gitkyl::FileTree::from_files(files.clone()).with_submodules(submodules);
+    let symlinks = symlink_targets(config, branch, &tree);
+
+    let tree_pages = generate_tree_pages_for_branch(
+        config,
+        repo_info,
+        branch,
+        &tree,
+        history.last_commits,
+        nested_sites,
+        &symlinks,
+    )?;
+
+    let (blob_pages, markdown_pages) = generate_blob_pages_for_branch(
+        config, repo_info, branch, &files, history, &symlinks, manifest,
+    )?;
+
+    generate_history_pages_for_branch(config, repo_info, branch, &tree, history, manifest)?;
+
+    Ok(BranchStats {
+        tree_pages,
+        blob_pages,
+        markdown_pages,
+    })
+}
+
+/// Generates pages for a ref unless they are current in the manifest.
+///
+/// The ref key combines the tip commit with the branch list, which tree
+/// pages show in their branch selector, the nested submodule sites they
+/// link to and the local LFS objects blob pages show. When the key matches
+/// the previous run every page of the ref is left untouched. Commits pages
+/// are checked on their own, since the graph labels the tips of other
+/// branches and tags as well and the list shows the notes of the ref's
+/// commits. History pages key notes per path.
+///
+/// # Arguments
+///
+/// * `config`: CLI configuration
+/// * `repo_info`: Repository metadata
+/// * `ref_name`: Branch or tag name to generate for
+/// * `commit_map`: Last commits already computed for the ref, if any
+/// * `nested_sites`: Submodule paths with a generated nested site
+/// * `default_history`: Default branch tip and commits, when other refs stop there
+/// * `manifest`: Build manifest recording previous runs
+///
+/// # Returns
+///
+/// Statistics about generated pages, or None if the ref was up to date
+///
+/// # Errors
+///
+/// Returns error if page generation fails
+fn generate_ref_if_changed(
+    config: &Config,
+    repo_info: &gitkyl::RepoInfo,
+    ref_name: &str,
+    commit_map: Option<&std::collections::HashMap<String, gitkyl::CommitInfo>>,
+    nested_sites: &std::collections::HashSet<String>,
+    default_history: Option<&(String, std::collections::HashSet<String>)>,
+    manifest: &mut gitkyl::Manifest,
+) -> Result<Option<BranchStats>> {
+    let mut sites: Vec<&str> = nested_sites.iter().map(String::as_str).collect();
+    sites.sort_unstable();
+    let sites = if sites.is_empty() {
+        String::new()
+    } else {
+        format!(" submodules={}", sites.join(","))
+    };
+
+    // Fetching LFS objects changes pages without moving the tip
+    let lfs = match gitkyl::lfs_store_fingerprint(&config.repo) {
+        Ok(fingerprint) if !fingerprint.is_empty() => format!(" lfs={}", fingerprint),
+        Ok(_) => String::new(),
+        Err(e) => {
+            eprintln!("Warning: Failed to read LFS store: {:#}", e);
+            String::new()
+        }
+    };
+
+    let ref_key = gitkyl::list_commits_with_mailmap(
+        &config.repo,
+        Some(ref_name),
+        Some(1),
+        repo_info.mailmap(),
+    )
+    .ok()
+    .and_then(|commits| commits.into_iter().next())
+    .map(|tip| {
+        format!(
+            "{} {}{}{}",
+            tip.oid(),
+            repo_info.branches().join(","),
+            sites,
+            lfs
+        )
+    });
+
+    // Walking the history is only needed up front to key the notes shown
+    // on commits pages; otherwise it waits until pages are regenerated
+    let load_order = || {
+        gitkyl::CommitOrder::load(&config.repo, Some(ref_name))
+            .with_context(|| format!("Failed to walk history of {}", ref_name))
+    };
+    let mut order = None;
+    let commits_notes = if repo_info.notes().is_empty() {
+        String::new()
+    } else {
+        let walked = load_order()?;
+        let key = notes_key(repo_info, walked.oids());
+        order = Some(walked);
+        key
+    };
+
+    // Commits pages also label other refs, so they are keyed separately
+    let commits_page = format!("commits/{}/page-1.html", ref_name);
+    let commits_key = ref_key.as_ref().map(|key| {
+        format!(
+            "{} labels={} stop={}{}",
+            key,
+            repo_info.ref_labels().fingerprint(),
+            default_history.map_or("", |(tip, _)| tip.as_str()),
+            commits_notes
+        )
+    });
+    if !commits_key
+        .as_ref()
+        .is_some_and(|key| manifest.is_fresh(&commits_page, key))
+    {
+        generate_commits_page_for_branch(
+            config,
+            repo_info,
+            ref_name,
+            default_history.map(|(_, oids)| oids),
+        )?;
+        if let Some(key) = &commits_key {
+            manifest.record(&commits_page, key);
+        }
+    }
+
+    // History pages show notes as well, which the ref key leaves out so a
+    // note edit only refreshes the history of the paths listing the commit
+    let history_dir = format!("history/{}", ref_name);
+    let history_key = ref_key
+        .as_ref()
+        .map(|key| format!("{}{}", key, commits_notes));
+    let ref_fresh = ref_key
+        .as_ref()
+        .is_some_and(|key| manifest.is_ref_fresh(ref_name, key));
+    let history_fresh = history_key
+        .as_ref()
+        .is_some_and(|key| manifest.is_fresh(&history_dir, key));
+    if ref_fresh && history_fresh {
+        return Ok(None);
+    }
+
+    // History is walked once here and shared by every path of the ref
+    let order = match order {
+        Some(order) => order,
+        None => load_order()?,
+    };
+    let computed;
+    let last_commits = match commit_map {
+        Some(commit_map) => commit_map,
+        None => {
+            computed = last_commits_for_ref(config, repo_info, ref_name);
+            &computed
+        }
+    };
+    let history = RefHistory {
+        last_commits,
+        order: &order,
+    };
+
+    let stats = if ref_fresh {
+        let files =
+            gitkyl::list_files(&config.repo, Some(ref_name)).context("Failed to list files")?;
+        let tree = gitkyl::FileTree::from_files(files);
+        generate_history_pages_for_branch(config, repo_info, ref_name, &tree, &history, manifest)?;
+        None
+    } else {
+        Some(generate_all_pages_for_branch(
+            config,
+            repo_info,
+            ref_name,
+            &history,
+            nested_sites,
+            manifest,
+        )?)
+    };
+
+    if let Some(key) = &ref_key {
+        manifest.record_ref(ref_name, key);
+    }
+    if let Some(key) = &history_key {
+        manifest.record(&history_dir, key);
+    }
+
+    Ok(stats)
+}
+
+/// Computes the shared last commit map for a ref.
+///
+/// Failures are reported as a warning and yield an empty map, leaving
+/// pages without commit information rather than aborting the ref.
+fn last_commits_for_ref(
+    config: &Config,
+    repo_info: &gitkyl::RepoInfo,
+    ref_name: &str,
+) -> std::collections::HashMap<String, gitkyl::CommitInfo> {
+    gitkyl::get_last_commits_tree_with_mailmap(&config.repo, Some(ref_name), repo_info.mailmap())
+        .unwrap_or_else(|e| {
+            eprintln!(
+                "Warning: Failed to look up last commits for {}: {:#}",
+                ref_name, e
+            );
+            std::collections::HashMap::new()
+        })
+}
+
+/// Attaches git notes and verifies commit signatures for display.
+///
+/// Signatures are only verified when allowed signers are configured.
+/// Verification failures are reported and leave the signatures shown as
+/// signed but unchecked.
+///
+/// # Arguments
+///
+/// * `config`: Application configuration containing repository path
+/// * `repo_info`: Repository metadata holding notes and signature verifier
+/// * `commits`: Commits to annotate in place
+fn annotate_commits(
+    config: &Config,
+    repo_info: &gitkyl::RepoInfo,
+    commits: &mut [gitkyl::CommitInfo],
+) {
+    repo_info.notes().apply(commits);
+    if let Some(verifier) = repo_info.signature_verifier()
+        && let Err(e) = gitkyl::verify_commit_signatures(&config.repo, commits, verifier)
+    {
+        eprintln!("Warning: Failed to verify commit signatures: {:#}", e);
+    }
+}
+
+/// Manifest key suffix identifying the notes of the commits on a page.
+///
+/// Empty when none of the commits has a note, so keys stay unchanged for
+/// repositories that do not use them.
+fn notes_key<S: AsRef<str>>(
+    repo_info: &gitkyl::RepoInfo,
+    oids: impl IntoIterator<Item = S>,
+) -> String {
+    let fingerprint = repo_info.notes().fingerprint_for(oids);
+    if fingerprint.is_empty() {
+        String::new()
+    } else {
+        format!(" notes={}", fingerprint)
+    }
+}
+
+/// Prints generation statistics for a ref.
+fn report_ref(ref_name: &str, stats: Option<&BranchStats>) {
+    match stats {
+        Some(stats) => println!(
+            "→ {}: {} trees, {} blobs ({} md)",
+            ref_name,
+            stats.tree_pages,
+            stats.total_blobs(),
+            stats.markdown_pages
+        ),
+        None => println!("→ {}: up to date", ref_name),
+    }
+}
+
+/// Generates source archives for refs.
+///
+/// Writes `archive/<repo>-<ref>.zip` and `.tar.gz` for each ref, with
+/// entries under a `<repo>-<ref>/` directory. Archives whose commit has
+/// not changed since the last build are kept.
+///
+/// # Arguments
+///
+/// * `config`: Application configuration containing repository and output paths
+/// * `repo_info`: Repository metadata including name
+/// * `refs`: Branch and tag names to archive
+/// * `manifest`: Build manifest used to skip unchanged archives
+///
+/// # Returns
+///
+/// Archive name (without extension) by ref, for refs archived successfully
+///
+/// # Errors
+///
+/// Returns error if the archive directory cannot be created
+fn generate_archives(
+    config: &Config,
+    repo_info: &gitkyl::RepoInfo,
+    refs: &[&str],
+    manifest: &mut gitkyl::Manifest,
+) -> Result<std::collections::HashMap<String, String>> {
+    if refs.is_empty() {
+        return Ok(std::collections::HashMap::new());
+    }
+
+    let archive_dir = config.output.join("archive");
+    fs::create_dir_all(&archive_dir).context("Failed to create archive directory")?;
+
+    let shared: &gitkyl::Manifest = manifest;
+    let outcomes = gitkyl::parallel_map(refs, config.job_count(), |ref_name| {
+        let name = gitkyl::archive_name(repo_info.name(), ref_name);
+        let commit = gitkyl::list_commits_with_mailmap(
+            &config.repo,
+            Some(ref_name),
+            Some(1),
+            repo_info.mailmap(),
+        )?
+        .into_iter()
+        .next()
+        .with_context(|| format!("No commits for {}", ref_name))?;
+
+        let mut records = Vec::new();
+        for format in gitkyl::ArchiveFormat::ALL {
+            let file_name = format!("{}.{}", name, format.extension());
+            let page = format!("archive/{}", file_name);
+            if shared.is_fresh(&page, commit.oid()) {
+                continue;
+            }
+
+            let path = archive_dir.join(&file_name);
+            let file = fs::File::create(&path)
+                .with_context(|| format!("Failed to create archive {}", path.display()))?;
+            if let Err(e) = gitkyl::write_archive(
+                &config.repo,
+                ref_name,
+                format,
+                &name,
+                std::io::BufWriter::new(file),
+            ) {
+                let _ = fs::remove_file(&path);
+                return Err(e);
+            }
+            records.push((page, commit.oid().to_string()));
+        }
+        Ok::<_, anyhow::Error>((ref_name.to_string(), name, records))
+    });
+
+    let mut archives = std::collections::HashMap::new();
+    for (ref_name, outcome) in refs.iter().zip(outcomes) {
+        match outcome {
+            Ok((ref_name, name, records)) => {
+                for (page, key) in &records {
+                    manifest.record(page, key);
+                }
+                archives.insert(ref_name, name);
+            }
+            Err(e) => eprintln!("Warning: Failed to archive {}: {:#}", ref_name, e),
+        }
+    }
+
+    Ok(archives)
+}
+
+/// Generates tags listing and detail pages.
+///
+/// Creates a tags index page listing the published tags, plus a release
+/// page for each tag with the commits and file changes since the tag
+/// published before it.
+///
+/// # Arguments
+///
+/// * `config`: Application configuration containing repository and output paths
+/// * `repo_info`: Repository metadata including name
+/// * `tags`: Published tags, most recent first
+/// * `archives`: Source archive names by tag, linked from detail pages
+/// * `manifest`: Build manifest used to skip unchanged tag pages
+///
+/// # Returns
+///
+/// Count of tags processed
+///
+/// # Errors
+///
+/// Returns error if page generation fails
+fn generate_tags_pages(
+    config: &Config,
+    repo_info: &gitkyl::RepoInfo,
+    tags: &[gitkyl::TagInfo],
+    archives: &std::collections::HashMap<String, String>,
+    manifest: &mut gitkyl::Manifest,
+) -> Result<usize> {
+    let mut tags = tags.to_vec();
+
+    if let Some(verifier) = repo_info.signature_verifier()
+        && let Err(e) = gitkyl::verify_tag_signatures(&config.repo, &mut tags, verifier)
+    {
+        eprintln!("Warning: Failed to verify tag signatures: {:#}", e);
+    }
+
+    if tags.is_empty() {
+        return Ok(0);
+    }
+
+    let tags_dir = config.output.join("tags");
+    fs::create_dir_all(&tags_dir).context("Failed to create tags directory")?;
+
+    let tags_index_html = gitkyl::pages::tags::generate_list(repo_info.name(), &tags);
+    let index_path = tags_dir.join("index.html");
+    fs::write(&index_path, tags_index_html.into_string())
+        .with_context(|| format!("Failed to write tags index to {}", index_path.display()))?;
+
+    for (idx, tag) in tags.iter().enumerate() {
+        // Versioned tags form one release chain, other tags another
+        let same_kind = |other: &&gitkyl::TagInfo| other.version.is_some() == tag.version.is_some();
+        let previous = tags[idx + 1..].iter().find(same_kind);
+        let next = tags[..idx].iter().rev().find(same_kind);
+
+        // Release pages list changes since the previous tag, link both
+        // neighbours and offer the archive when one was written
+        let tag_page = format!("tags/{}.html", tag.name);
+        let archive = archives.get(&tag.name).map(String::as_str);
+        let tag_key = format!(
+            "{} previous={} next={} archive={}",
+            tag.target_oid,
+            previous.map_or(String::new(), |t| format!("{}@{}", t.name, t.target_oid)),
+            next.map_or("", |t| t.name.as_str()),
+            archive.unwrap_or("")
+        );
+        if manifest.is_fresh(&tag_page, &tag_key) {
+            continue;
+        }
+
+        let commits = gitkyl::list_commits_with_mailmap(
+            &config.repo,
+            Some(&tag.name),
+            Some(1),
+            repo_info.mailmap(),
+        )
+        .unwrap_or_else(|e| {
+            eprintln!(
+                "Warning: Failed to get commit for tag {}: {:#}",
+                tag.name, e
+            );
+            vec![]
+        });
+
+        if let Some(commit) = commits.first() {
+            let changes = gitkyl::release_changes(
+                &config.repo,
+                &tag.name,
+                previous.map(|t| t.name.as_str()),
+                repo_info.mailmap(),
+            )
+            .unwrap_or_else(|e| {
+                eprintln!(
+                    "Warning: Failed to compare tag {} with previous release: {:#}",
+                    tag.name, e
+                );
+                gitkyl::ReleaseChanges::default()
+            });
+
+            let tag_html =
+                gitkyl::pages::tags::generate_detail(gitkyl::pages::tags::ReleasePageData {
+                    repo_name: repo_info.name(),
+                    tag,
+                    commit,
+                    archive,
+                    changes: &changes,
+                    previous,
+                    next,
+                });
+
+            let tag_path = tags_dir.join(format!("{}.html", tag.name));
+            fs::write(&tag_path, tag_html.into_string())
+                .with_context(|| format!("Failed to write tag page to {}", tag_path.display()))?;
+
+            manifest.record(&tag_page, &tag_key);
+        }
+    }
+
+    Ok(tags.len())
+}
+
+/// Lists the commits reachable from refs, each once.
+///
+/// # Arguments
+///
+/// * `config`: Application configuration containing repository path
+/// * `repo_info`: Repository metadata holding the mailmap
+/// * `refs`: Branch and tag names whose history should be covered
+///
+/// # Returns
+///
+/// Commits of the first ref newest first, followed by commits only
+/// reachable from later refs
+fn list_history(
+    config: &Config,
+    repo_info: &gitkyl::RepoInfo,
+    refs: &[&str],
+) -> Vec<gitkyl::CommitInfo> {
+    let mut seen = std::collections::HashSet::new();
+    let mut history = Vec::new();
+
+    for ref_name in refs {
+        let commits = gitkyl::list_commits_with_mailmap(
+            &config.repo,
+            Some(ref_name),
+            None,
+            repo_info.mailmap(),
+        )
+        .unwrap_or_else(|e| {
+            eprintln!("Warning: Failed to list commits for {}: {:#}", ref_name, e);
+            vec![]
+        });
+        history.extend(
+            commits
+                .into_iter()
+                .filter(|commit| seen.insert(commit.oid().to_string())),
+        );
+    }
+
+    history
+}
+
+/// Generates commit detail pages for all commits reachable from refs.
+///
+/// Each page is written to `commit/<oid>.html` and shows the diff against
+/// the first parent.
+///
+/// # Arguments
+///
+/// * `config`: Application configuration including output path and theme
+/// * `repo_info`: Repository metadata including name
+/// * `history`: Commits reachable from branches and tags, each listed once
+/// * `manifest`: Build manifest used to skip existing commit pages
+///
+/// # Returns
+///
+/// Count of commit pages generated
+///
+/// # Errors
+///
+/// Returns error if the commit directory cannot be created or a page fails
+/// to render or write
+fn generate_commit_pages(
+    config: &Config,
+    repo_info: &gitkyl::RepoInfo,
+    history: &[gitkyl::CommitInfo],
+    manifest: &mut gitkyl::Manifest,
+) -> Result<usize> {
+    let commit_dir = config.output.join("commit");
+    fs::create_dir_all(&commit_dir).context("Failed to create commit directory")?;
+
+    let mut pending = Vec::new();
+
+    for commit in history {
+        // Commits are immutable, so only their notes can make a page stale
+        let commit_page = format!("commit/{}.html", commit.oid());
+        let key = format!("{}{}", commit.oid(), notes_key(repo_info, [commit.oid()]));
+        if manifest.is_fresh(&commit_page, &key) {
+            continue;
+        }
+
+        pending.push((commit_page, commit.oid().to_string(), key));
+    }
+
+    let results = gitkyl::parallel_map(&pending, config.job_count(), |(_, oid, _)| {
+        let mut detail =
+            gitkyl::get_commit_detail_with_mailmap(&config.repo, oid, repo_info.mailmap())
+                .with_context(|| format!("Failed to read commit {}", oid))?;
+        annotate_commits(config, repo_info, std::slice::from_mut(&mut detail.commit));
+        let html = gitkyl::pages::commit::generate(repo_info.name(), &detail, &config.theme)
+            .with_context(|| format!("Failed to generate commit page {}", oid))?;
+
+        let page_path = commit_dir.join(format!("{}.html", oid));
+        fs::write(&page_path, html.into_string())
+            .with_context(|| format!("Failed to write commit page {}", page_path.display()))
+    });
+
+    for ((commit_page, _, key), result) in pending.iter().zip(results) {
+        result?;
+        manifest.record(commit_page, key);
+    }
+
+    Ok(pending.len())
+}
+
+/// Generates the contributors ranking and per-author commit pages.
+///
+/// The ranking is written to `contributors/index.html` and each author's
+/// commits to paginated pages at `contributors/<slug>/page-N.html`. Line
+/// counts require diffing every commit, so all pages are skipped while the
+/// branch and tag tips and the notes are unchanged since the previous run.
+///
+/// # Arguments
+///
+/// * `config`: Application configuration including output path
+/// * `repo_info`: Repository metadata including name and ref labels
+/// * `history`: Commits reachable from branches and tags, each listed once
+/// * `manifest`: Build manifest used to skip unchanged pages
+///
+/// # Errors
+///
+/// Returns error if a page cannot be written
+fn generate_contributor_pages(
+    config: &Config,
+    repo_info: &gitkyl::RepoInfo,
+    history: &[gitkyl::CommitInfo],
+    manifest: &mut gitkyl::Manifest,
+) -> Result<()> {
+    let index_page = "contributors/index.html";
+    let key = format!(
+        "labels={}{}",
+        repo_info.ref_labels().fingerprint(),
+        notes_key(repo_info, history.iter().map(|commit| commit.oid()))
+    );
+    if manifest.is_fresh(index_page, &key) {
+        return Ok(());
+    }
+
+    let stats = gitkyl::parallel_map(history, config.job_count(), |commit| {
+        gitkyl::commit_line_stats(&config.repo, commit)
+    });
+    let line_stats: std::collections::HashMap<String, (usize, usize)> = history
+        .iter()
+        .zip(stats)
+        .filter_map(|(commit, result)| match result {
+            Ok(counts) => Some((commit.oid().to_string(), counts)),
+            Err(e) => {
+                eprintln!(
+                    "Warning: Failed to count lines of {}: {:#}",
+                    commit.oid(),
+                    e
+                );
+                None
+            }
+        })
+        .collect();
+    let contributors = gitkyl::collect_contributors(history, &line_stats);
+
+    let contributors_dir = config.output.join("contributors");
+    fs::create_dir_all(&contributors_dir).context("Failed to create contributors directory")?;
+
+    let list_html = gitkyl::pages::contributors::generate_list(repo_info.name(), &contributors);
+    let list_path = contributors_dir.join("index.html");
+    fs::write(&list_path, list_html.into_string()).with_context(|| {
+        format!(
+            "Failed to write contributors page to {}",
+            list_path.display()
+        )
+    })?;
+
+    let by_oid: std::collections::HashMap<&str, &gitkyl::CommitInfo> = history
+        .iter()
+        .map(|commit| (commit.oid(), commit))
+        .collect();
+
+    for contributor in &contributors {
+        let author_dir = contributors_dir.join(contributor.slug());
+        fs::create_dir_all(&author_dir).context("Failed to create author directory")?;
+
+        let page_count = contributor
+            .commit_count()
+            .div_ceil(DEFAULT_COMMIT_LIMIT)
+            .max(1);
+        let mut oids = contributor.commits().chunks(DEFAULT_COMMIT_LIMIT);
+
+        for page in 1..=page_count {
+            let mut page_commits: Vec<gitkyl::CommitInfo> = oids
+                .next()
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|oid| by_oid.get(oid.as_str()).map(|commit| (*commit).clone()))
+                .collect();
+            annotate_commits(config, repo_info, &mut page_commits);
+            let paginated = gitkyl::PaginatedCommits::new(
+                page_commits,
+                page,
+                DEFAULT_COMMIT_LIMIT,
+                page < page_count,
+            );
 
-        if !paginated.has_more {
-            break;
+            let author_html = gitkyl::pages::contributors::generate_author(
+                repo_info.name(),
+                contributor,
+                &paginated,
+            );
+            let page_path = author_dir.join(format!("page-{}.html", page));
+            fs::write(&page_path, author_html.into_string()).with_context(|| {
+                format!("Failed to write author page to {}", page_path.display())
+            })?;
         }
-
-        page += 1;
     }
 
+    manifest.record(index_page, &key);
     Ok(())
 }
 
-/// Generates all pages for a single branch.
+/// Generates the activity page of the default branch.
 ///
-/// Orchestrates generation of tree pages, blob pages, and commits page for
-/// the specified branch. Returns statistics for reporting.
+/// The calendar ends with the day of generation, so the page is rewritten
+/// on every run like the index page.
 ///
 /// # Arguments
 ///
-/// * `config`: CLI configuration
-/// * `repo_info`: Repository metadata
-/// * `branch`: Branch name to generate for
+/// * `config`: Application configuration including output path
+/// * `repo_info`: Repository metadata including name and default branch
 ///
 /// # Returns
 ///
-/// Statistics about generated pages
+/// Commit activity of the default branch, for the index page
 ///
 /// # Errors
 ///
-/// Returns error if any critical generation step fails
-fn generate_all_pages_for_branch(
+/// Returns error if the page cannot be written
+fn generate_activity_page(
     config: &Config,
     repo_info: &gitkyl::RepoInfo,
-    branch: &str,
-) -> Result<BranchStats> {
-    let files = gitkyl::list_files(&config.repo, Some(branch)).context("Failed to list files")?;
-
-    let tree = gitkyl::FileTree::from_files(files.clone());
-
-    let file_paths: Vec<&str> = files.iter().filter_map(|f| f.path()?.to_str()).collect();
+) -> Result<gitkyl::Activity> {
+    let branch = repo_info.default_branch();
+    let commits =
+        gitkyl::list_commits_with_mailmap(&config.repo, Some(branch), None, repo_info.mailmap())
+            .unwrap_or_else(|e| {
+                eprintln!("Warning: Failed to list commits for {}: {:#}", branch, e);
+                vec![]
+            });
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64);
+    let activity = gitkyl::Activity::from_commits(&commits, now);
+
+    let activity_dir = config.output.join("activity");
+    fs::create_dir_all(&activity_dir).context("Failed to create activity directory")?;
+    let html = gitkyl::pages::activity::generate(repo_info.name(), branch, &activity);
+    let page_path = activity_dir.join("index.html");
+    fs::write(&page_path, html.into_string())
+        .with_context(|| format!("Failed to write activity page to {}", page_path.display()))?;
+
+    Ok(activity)
+}
 
-    let commit_map = gitkyl::get_last_commits_batch(&config.repo, Some(branch), &file_paths)
+/// Generates the language breakdown page of the default branch.
+///
+/// Measuring the tree only reads blob headers, so the page is rewritten on
+/// every run like the activity page.
+///
+/// # Arguments
+///
+/// * `config`: Application configuration including output path
+/// * `repo_info`: Repository metadata including name and default branch
+/// * `tree`: File tree of the default branch
+///
+/// # Returns
+///
+/// Language breakdown of the default branch, for the index page
+///
+/// # Errors
+///
+/// Returns error if the page cannot be written
+fn generate_languages_page(
+    config: &Config,
+    repo_info: &gitkyl::RepoInfo,
+    tree: &gitkyl::FileTree,
+) -> Result<gitkyl::LanguageBreakdown> {
+    let branch = repo_info.default_branch();
+    let languages = gitkyl::Highlighter::new()
+        .and_then(|highlighter| gitkyl::language_breakdown(&config.repo, tree, &highlighter))
         .unwrap_or_else(|e| {
             eprintln!(
-                "Warning: Failed to batch lookup commits for branch {}: {:#}",
+                "Warning: Failed to measure languages of {}: {:#}",
                 branch, e
             );
-            std::collections::HashMap::new()
+            gitkyl::LanguageBreakdown::default()
         });
 
-    let tree_pages = generate_tree_pages_for_branch(config, repo_info, branch, &tree, &commit_map)?;
-
-    let (blob_pages, markdown_pages) =
-        generate_blob_pages_for_branch(config, repo_info, branch, &files)?;
+    let languages_dir = config.output.join("languages");
+    fs::create_dir_all(&languages_dir).context("Failed to create languages directory")?;
+    let html = gitkyl::pages::languages::generate(repo_info.name(), branch, &languages);
+    let page_path = languages_dir.join("index.html");
+    fs::write(&page_path, html.into_string())
+        .with_context(|| format!("Failed to write languages page to {}", page_path.display()))?;
 
-    generate_commits_page_for_branch(config, repo_info, branch)?;
-
-    Ok(BranchStats {
-        tree_pages,
-        blob_pages,
-        markdown_pages,
-    })
+    Ok(languages)
 }
 
-/// Generates tags listing and detail pages.
+/// Writes the dumb HTTP repository of the published branches and tags.
 ///
-/// Creates a tags index page listing all repository tags, plus individual
-/// detail pages for each tag showing commit information.
+/// The pack is only rebuilt when a published ref moved.
 ///
 /// # Arguments
 ///
-/// * `config`: Application configuration containing repository and output paths
-/// * `repo_info`: Repository metadata including name
+/// * `config`: Application configuration including output path
+/// * `repo_info`: Repository metadata including branches
+/// * `project_name`: Project name, naming the `<name>.git` directory
+/// * `tags`: Published tags
+/// * `manifest`: Build manifest for incremental generation
 ///
 /// # Returns
 ///
-/// Count of tags processed
-///
-/// # Errors
-///
-/// Returns error if tag listing or page generation fails
-fn generate_tags_pages(config: &Config, repo_info: &gitkyl::RepoInfo) -> Result<usize> {
-    let tags = gitkyl::list_tags(&config.repo).context("Failed to list tags")?;
-
-    if tags.is_empty() {
-        return Ok(0);
+/// Clone URL, absolute when `--base-url` is given, or None if the
+/// repository could not be written
+fn generate_dumb_repository(
+    config: &Config,
+    repo_info: &gitkyl::RepoInfo,
+    project_name: &str,
+    tags: &[gitkyl::TagInfo],
+    manifest: &mut gitkyl::Manifest,
+) -> Option<String> {
+    let dir_name = gitkyl::clone_dir_name(project_name);
+    let branches: Vec<&str> = repo_info.branches().iter().map(String::as_str).collect();
+    let tag_names: Vec<&str> = tags.iter().map(|tag| tag.name.as_str()).collect();
+
+    let written = gitkyl::PublishedRefs::resolve(
+        &config.repo,
+        &branches,
+        &tag_names,
+        repo_info.default_branch(),
+    )
+    .and_then(|refs| {
+        let page = format!("{}/info/refs", dir_name);
+        if !manifest.is_fresh(&page, refs.fingerprint()) {
+            let written =
+                gitkyl::write_dumb_repository(&config.repo, &refs, &config.output.join(&dir_name))?;
+            if let Some(count) = written {
+                println!("→ {}: {} objects", dir_name, count);
+            }
+            manifest.record(&page, refs.fingerprint());
+        }
+        Ok(())
+    });
+    if let Err(e) = written {
+        eprintln!("Warning: Failed to write {}: {:#}", dir_name, e);
+        return None;
     }
 
-    let tags_dir = config.output.join("tags");
-    fs::create_dir_all(&tags_dir).context("Failed to create tags directory")?;
-
-    let tags_index_html = gitkyl::pages::tags::generate_list(repo_info.name(), &tags);
-    let index_path = tags_dir.join("index.html");
-    fs::write(&index_path, tags_index_html.into_string())
-        .with_context(|| format!("Failed to write tags index to {}", index_path.display()))?;
+    Some(match &config.base_url {
+        Some(base) => format!("{}/{}", base.trim_end_matches('/'), dir_name),
+        None => dir_name,
+    })
+}
 
-    for tag in &tags {
-        let commits =
-            gitkyl::list_commits(&config.repo, Some(&tag.name), Some(1)).unwrap_or_else(|e| {
+/// Generates nested sites for the submodules pinned by each ref.
+///
+/// Each submodule whose working tree exists inside the repository is
+/// rendered at the commit a ref pins, as a complete site under
+/// `submodules/<path>/<oid>/` in the output directory, so tree pages can
+/// link there instead of to the remote. Refs pinning the same commit share
+/// its site. Failures are reported and leave the submodule linked to its
+/// remote.
+///
+/// # Arguments
+///
+/// * `config`: Configuration of the parent site
+/// * `refs`: Branches and tags whose tree pages are generated
+///
+/// # Returns
+///
+/// Paths of submodules that received a nested site, by ref
+fn generate_submodule_sites(
+    config: &Config,
+    refs: &[&str],
+) -> std::collections::HashMap<String, std::collections::HashSet<String>> {
+    let mut generated = std::collections::HashMap::new();
+    let mut nested_sites = std::collections::HashMap::new();
+
+    for ref_name in refs {
+        let submodules =
+            gitkyl::list_submodules(&config.repo, Some(ref_name)).unwrap_or_else(|e| {
                 eprintln!(
-                    "Warning: Failed to get commit for tag {}: {:#}",
-                    tag.name, e
+                    "Warning: Failed to list submodules of {}: {:#}",
+                    ref_name, e
                 );
                 vec![]
             });
 
-        if let Some(commit) = commits.first() {
-            let tag_html = gitkyl::pages::tags::generate_detail(
-                repo_info.name(),
-                tag,
-                commit.message(),
-                commit.author(),
-                commit.date(),
-            );
+        let mut sites = std::collections::HashSet::new();
+        for submodule in &submodules {
+            let Some(path) = submodule.path().and_then(|p| p.to_str()) else {
+                continue;
+            };
+            if validate_tree_path(path).is_err() {
+                continue;
+            }
 
-            let tag_path = tags_dir.join(format!("{}.html", tag.name));
-            fs::write(&tag_path, tag_html.into_string())
-                .with_context(|| format!("Failed to write tag page to {}", tag_path.display()))?;
+            // Initialized submodules have a .git file pointing into the parent
+            let checkout = config.repo.join(path);
+            if !checkout.join(".git").exists() {
+                continue;
+            }
+
+            let oid = submodule.oid().to_string();
+            let has_site = *generated
+                .entry((path.to_string(), oid.clone()))
+                .or_insert_with(|| {
+                    println!("→ submodule {} at {}", path, &oid[..7]);
+                    match generate_submodule_site(config, &checkout, path, submodule.oid()) {
+                        Ok(()) => true,
+                        Err(e) => {
+                            eprintln!("✗ submodule {}: {:#}", path, e);
+                            false
+                        }
+                    }
+                });
+            if has_site {
+                sites.insert(path.to_string());
+            }
         }
+        nested_sites.insert(ref_name.to_string(), sites);
     }
 
-    Ok(tags.len())
+    nested_sites
 }
 
-fn main() -> Result<()> {
-    let config = Config::parse();
-    config.validate().context("Invalid configuration")?;
+/// Generates the nested site of a submodule at a pinned commit.
+///
+/// The site is built from a temporary repository holding only the pinned
+/// commit, so it shows what the parent pins rather than whatever the
+/// checkout has moved on to. Only output and job settings carry over from
+/// the parent: its mailmap, ref filters, history limits and allowed signers
+/// describe the parent's history, not the submodule's.
+///
+/// # Arguments
+///
+/// * `config`: Configuration of the parent site
+/// * `checkout`: Submodule working tree inside the parent
+/// * `path`: Submodule path relative to the parent's root
+/// * `oid`: Commit pinned by the parent
+///
+/// # Errors
+///
+/// Returns error if the commit is not fetched into the checkout or the
+/// site cannot be generated
+fn generate_submodule_site(
+    config: &Config,
+    checkout: &std::path::Path,
+    path: &str,
+    oid: &gix::oid,
+) -> Result<()> {
+    let pinned = tempfile::Builder::new()
+        .prefix("gitkyl-submodule-")
+        .tempdir()
+        .context("Failed to create temporary repository")?;
+    gitkyl::pin_commit(checkout, oid, pinned.path())?;
+
+    let site_path = format!("submodules/{}/{}", path, oid);
+    let nested = Config {
+        repo: pinned.path().to_path_buf(),
+        output: config.output.join(&site_path),
+        base_url: config
+            .base_url
+            .as_ref()
+            .map(|base| format!("{}/{}", base.trim_end_matches('/'), site_path)),
+        name: Some(path.rsplit('/').next().unwrap_or(path).to_string()),
+        theme: config.theme.clone(),
+        no_open: true,
+        force: config.force,
+        jobs: config.jobs,
+        ..Config::default()
+    };
 
-    let repo_info = gitkyl::analyze_repository(&config.repo, config.owner.clone())
-        .context("Failed to analyze repository")?;
+    generate_site(&nested)
+}
 
-    setup_output_directories(&config.output)?;
+/// Picks a site directory not used by another repository.
+///
+/// Dropping the `.git` suffix maps `foo.git` and `foo` to the same
+/// directory, so a repository whose preferred path is taken keeps its
+/// directory name as is, with a numeric suffix as the last resort.
+///
+/// # Arguments
+///
+/// * `site_path`: Preferred site path, without a `.git` suffix
+/// * `relative`: Repository path relative to the scanned directory
+/// * `used`: Site paths already assigned
+///
+/// # Returns
+///
+/// Site path unique among `used`
+fn unique_site_path(
+    site_path: &str,
+    relative: &str,
+    used: &std::collections::HashSet<String>,
+) -> String {
+    for candidate in [site_path, relative] {
+        if !used.contains(candidate) {
+            return candidate.to_string();
+        }
+    }
 
-    let latest_commit =
-        gitkyl::list_commits(&config.repo, Some(repo_info.default_branch()), Some(1))
-            .ok()
-            .and_then(|commits| commits.into_iter().next());
+    let mut suffix = 2;
+    loop {
+        let candidate = format!("{}-{}", site_path, suffix);
+        if !used.contains(&candidate) {
+            return candidate;
+        }
+        suffix += 1;
+    }
+}
 
-    let files =
-        gitkyl::list_files(&config.repo, Some(repo_info.default_branch())).unwrap_or_else(|e| {
-            eprintln!("Warning: Failed to list files: {:#}", e);
+/// Generates the complete static site for one repository.
+///
+/// # Arguments
+///
+/// * `config`: Validated configuration
+///
+/// # Errors
+///
+/// Returns error if the repository cannot be analyzed or a critical page
+/// cannot be written
+fn generate_site(config: &Config) -> Result<()> {
+    let mailmap = gitkyl::Mailmap::load(&config.repo, config.mailmap.as_deref())
+        .context("Failed to load mailmap")?;
+    let mut repo_info = gitkyl::analyze_repository(&config.repo, config.owner.clone())
+        .context("Failed to analyze repository")?
+        .with_mailmap(mailmap);
+
+    let ref_filter = config.ref_filter();
+    let branches = ref_filter.filter_branches(repo_info.branches(), repo_info.default_branch());
+    repo_info = repo_info.with_branches(branches);
+    let tags = gitkyl::list_tags_with_mailmap(&config.repo, repo_info.mailmap())
+        .map(|tags| ref_filter.filter_tags(tags))
+        .unwrap_or_else(|e| {
+            eprintln!("Warning: Failed to list tags: {:#}", e);
             vec![]
         });
 
-    let tree = gitkyl::FileTree::from_files(files.clone());
+    match gitkyl::RefLabels::load(&config.repo) {
+        Ok(labels) => {
+            let labels = labels.retain(|label| match label {
+                gitkyl::RefLabel::Branch(name) => repo_info.branches().contains(name),
+                gitkyl::RefLabel::Tag(name) => tags.iter().any(|tag| tag.name == *name),
+            });
+            repo_info = repo_info.with_ref_labels(labels);
+        }
+        Err(e) => eprintln!("Warning: Failed to read graph labels: {:#}", e),
+    }
+    match gitkyl::Notes::load(&config.repo, &config.notes_refs) {
+        Ok(notes) => repo_info = repo_info.with_notes(notes),
+        Err(e) => eprintln!("Warning: Failed to read git notes: {:#}", e),
+    }
+    if let Some(allowed_signers) = &config.allowed_signers {
+        let verifier = gitkyl::SignatureVerifier::new(allowed_signers)
+            .context("Failed to load allowed signers")?;
+        repo_info = repo_info.with_signature_verifier(verifier);
+    }
+
+    setup_output_directories(&config.output)?;
 
-    let all_file_paths: Vec<&str> = files.iter().filter_map(|f| f.path()?.to_str()).collect();
+    let project_name = config
+        .project_name()
+        .context("Failed to determine project name")?;
+    let fingerprint = format!(
+        "{} theme={} name={} owner={} follow_renames={} mailmap={} allowed_signers={} branch_archives={} history={:?}/{:?}/{}",
+        env!("CARGO_PKG_VERSION"),
+        config.theme,
+        project_name,
+        repo_info.owner().unwrap_or(""),
+        config.follow_renames,
+        repo_info.mailmap().fingerprint(),
+        repo_info
+            .signature_verifier()
+            .map_or("", |verifier| verifier.fingerprint()),
+        config.branch_archives,
+        config.max_commits,
+        config.since,
+        config.stop_at_default_branch
+    );
+    let mut manifest = if config.force {
+        gitkyl::Manifest::empty(&config.output, &fingerprint)
+    } else {
+        gitkyl::Manifest::load(&config.output, &fingerprint)
+            .context("Failed to load build manifest")?
+    };
 
-    let commit_map = gitkyl::get_last_commits_batch(
+    let latest_commit = gitkyl::list_commits_with_mailmap(
         &config.repo,
         Some(repo_info.default_branch()),
-        &all_file_paths,
+        Some(1),
+        repo_info.mailmap(),
     )
-    .unwrap_or_else(|e| {
-        eprintln!("Warning: Failed to batch lookup commits: {:#}", e);
-        std::collections::HashMap::new()
-    });
+    .ok()
+    .and_then(|commits| commits.into_iter().next());
 
-    let top_level_files = tree.files_at("");
-    let top_level_subdirs = tree.subdirs_at("");
+    let files =
+        gitkyl::list_files(&config.repo, Some(repo_info.default_branch())).unwrap_or_else(|e| {
+            eprintln!("Warning: Failed to list files: {:#}", e);
+            vec![]
+        });
 
-    let root_dir_commit_map = if !top_level_subdirs.is_empty() {
-        gitkyl::get_last_commits_batch(
-            &config.repo,
-            Some(repo_info.default_branch()),
-            &top_level_subdirs,
-        )
+    let submodules = gitkyl::list_submodules(&config.repo, Some(repo_info.default_branch()))
         .unwrap_or_else(|e| {
-            eprintln!("Warning: Failed to batch lookup directory commits: {:#}", e);
-            std::collections::HashMap::new()
-        })
-    } else {
-        std::collections::HashMap::new()
-    };
+            eprintln!("Warning: Failed to list submodules: {:#}", e);
+            vec![]
+        });
+    let site_refs: Vec<&str> = repo_info
+        .branches()
+        .iter()
+        .map(String::as_str)
+        .chain(tags.iter().map(|t| t.name.as_str()))
+        .collect();
+    let nested_sites = generate_submodule_sites(config, &site_refs);
+    let no_sites = std::collections::HashSet::new();
+    let sites_of = |ref_name: &str| nested_sites.get(ref_name).unwrap_or(&no_sites);
+
+    let tree = gitkyl::FileTree::from_files(files.clone()).with_submodules(submodules);
+
+    let commit_map = last_commits_for_ref(config, &repo_info, repo_info.default_branch());
+    let symlinks = symlink_targets(config, repo_info.default_branch(), &tree);
 
     let tree_items = build_tree_items(
-        top_level_files,
-        &top_level_subdirs,
+        tree.files_at(""),
+        tree.submodules_at(""),
+        &tree.subdirs_at(""),
         "",
         &commit_map,
-        &root_dir_commit_map,
+        sites_of(repo_info.default_branch()),
+        &symlinks,
     );
 
     let readme_html =
@@ -637,39 +2140,95 @@ fn main() -> Result<()> {
                 None
             });
 
-    let tag_count = gitkyl::list_tags(&config.repo)
-        .map(|tags| tags.len())
-        .unwrap_or(0);
+    let tag_count = tags.len();
+
+    let commit_refs: Vec<&str> = repo_info
+        .branches()
+        .iter()
+        .map(|b| b.as_str())
+        .chain(tags.iter().map(|t| t.name.as_str()))
+        .collect();
+    let history = list_history(config, &repo_info, &commit_refs);
+    let contributor_count =
+        gitkyl::collect_contributors(&history, &std::collections::HashMap::new()).len();
+    let activity = generate_activity_page(config, &repo_info)?;
+    let languages = generate_languages_page(config, &repo_info, &tree)?;
+
+    let archive_refs: Vec<&str> = repo_info
+        .branches()
+        .iter()
+        .map(String::as_str)
+        .filter(|_| config.branch_archives)
+        .chain(tags.iter().map(|t| t.name.as_str()))
+        .collect();
+    let archives = generate_archives(config, &repo_info, &archive_refs, &mut manifest)?;
+    // Offer the default branch when branches are archived, else the latest tag
+    let index_archive = if config.branch_archives {
+        archives.get(repo_info.default_branch())
+    } else {
+        tags.first().and_then(|tag| archives.get(&tag.name))
+    };
+
+    let clone_url = config
+        .dumb_http
+        .then(|| generate_dumb_repository(config, &repo_info, &project_name, &tags, &mut manifest))
+        .flatten();
 
     let html = index_page(IndexPageData {
-        name: &config
-            .project_name()
-            .context("Failed to determine project name")?,
+        name: &project_name,
         owner: repo_info.owner(),
         default_branch: repo_info.default_branch(),
         branches: repo_info.branches(),
         commit_count: repo_info.commit_count(),
         tag_count,
+        contributor_count,
         latest_commit: latest_commit.as_ref(),
         items: &tree_items,
         readme_html: readme_html.as_deref(),
         depth: 0,
+        archive: index_archive.map(String::as_str),
+        activity: Some(&activity),
+        languages: Some(&languages),
+        clone_url: clone_url.as_deref(),
+        latest_release: gitkyl::latest_release(&tags),
     });
 
     let index_path = config.output.join("index.html");
     fs::write(&index_path, html.into_string())
         .with_context(|| format!("Failed to write index page to {}", index_path.display()))?;
 
-    let default_stats =
-        generate_all_pages_for_branch(&config, &repo_info, repo_info.default_branch())?;
-
-    println!(
-        "→ {}: {} trees, {} blobs ({} md)",
+    let default_stats = generate_ref_if_changed(
+        config,
+        &repo_info,
         repo_info.default_branch(),
-        default_stats.tree_pages,
-        default_stats.total_blobs(),
-        default_stats.markdown_pages
-    );
+        Some(&commit_map),
+        sites_of(repo_info.default_branch()),
+        None,
+        &mut manifest,
+    )?;
+
+    report_ref(repo_info.default_branch(), default_stats.as_ref());
+
+    let default_history = if config.stop_at_default_branch {
+        match gitkyl::list_commits_with_mailmap(
+            &config.repo,
+            Some(repo_info.default_branch()),
+            None,
+            repo_info.mailmap(),
+        ) {
+            Ok(commits) => commits.first().map(|tip| {
+                let oids = commits.iter().map(|c| c.oid().to_string()).collect();
+                (tip.oid().to_string(), oids)
+            }),
+            Err(e) => {
+                eprintln!("Warning: Failed to list default branch history: {:#}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+    let default_stats = default_stats.unwrap_or_default();
 
     let mut total_trees = default_stats.tree_pages;
     let mut total_blobs = default_stats.total_blobs();
@@ -680,15 +2239,18 @@ fn main() -> Result<()> {
             continue;
         }
 
-        match generate_all_pages_for_branch(&config, &repo_info, branch) {
+        match generate_ref_if_changed(
+            config,
+            &repo_info,
+            branch,
+            None,
+            sites_of(branch),
+            default_history.as_ref(),
+            &mut manifest,
+        ) {
             Ok(stats) => {
-                println!(
-                    "→ {}: {} trees, {} blobs ({} md)",
-                    branch,
-                    stats.tree_pages,
-                    stats.total_blobs(),
-                    stats.markdown_pages
-                );
+                report_ref(branch, stats.as_ref());
+                let stats = stats.unwrap_or_default();
                 total_trees += stats.tree_pages;
                 total_blobs += stats.total_blobs();
                 branch_count += 1;
@@ -700,17 +2262,19 @@ fn main() -> Result<()> {
     }
 
     // Generate tree and blob pages for tags to enable file browsing
-    let tags = gitkyl::list_tags(&config.repo).unwrap_or_default();
     for tag in &tags {
-        match generate_all_pages_for_branch(&config, &repo_info, &tag.name) {
+        match generate_ref_if_changed(
+            config,
+            &repo_info,
+            &tag.name,
+            None,
+            sites_of(&tag.name),
+            default_history.as_ref(),
+            &mut manifest,
+        ) {
             Ok(stats) => {
-                println!(
-                    "→ {}: {} trees, {} blobs ({} md)",
-                    tag.name,
-                    stats.tree_pages,
-                    stats.total_blobs(),
-                    stats.markdown_pages
-                );
+                report_ref(&tag.name, stats.as_ref());
+                let stats = stats.unwrap_or_default();
                 total_trees += stats.tree_pages;
                 total_blobs += stats.total_blobs();
             }
@@ -720,16 +2284,120 @@ fn main() -> Result<()> {
         }
     }
 
-    let tags_count = generate_tags_pages(&config, &repo_info).unwrap_or_else(|e| {
-        eprintln!("Warning: Failed to generate tags pages: {:#}", e);
-        0
-    });
+    let tags_count = generate_tags_pages(config, &repo_info, &tags, &archives, &mut manifest)
+        .unwrap_or_else(|e| {
+            eprintln!("Warning: Failed to generate tags pages: {:#}", e);
+            0
+        });
+
+    let commit_pages = generate_commit_pages(config, &repo_info, &history, &mut manifest)?;
+    generate_contributor_pages(config, &repo_info, &history, &mut manifest)?;
+
+    manifest.save().context("Failed to save build manifest")?;
 
     println!(
-        "✓ Generated {} trees, {} blobs ({} branches, {} tags)",
-        total_trees, total_blobs, branch_count, tags_count
+        "✓ Generated {} trees, {} blobs, {} commits ({} branches, {} tags)",
+        total_trees, total_blobs, commit_pages, branch_count, tags_count
     );
 
+    Ok(())
+}
+
+/// Generates a site for every repository in a directory plus a landing page.
+///
+/// Each repository is built with the regular single repository pipeline
+/// under `<output>/<path>/`, where `path` is its location relative to the
+/// scanned directory without a `.git` suffix. Repositories that fail to
+/// generate are reported and left off the landing page.
+///
+/// # Arguments
+///
+/// * `config`: Configuration whose repository path is the directory to scan
+///
+/// # Errors
+///
+/// Returns error if the directory cannot be scanned or the landing page
+/// cannot be written
+fn generate_multi_site(config: &Config) -> Result<()> {
+    let paths =
+        gitkyl::discover_repositories(&config.repo).context("Failed to scan for repositories")?;
+
+    setup_output_directories(&config.output)?;
+
+    let ref_filter = config.ref_filter();
+    let mut repos = Vec::new();
+    let mut site_paths = std::collections::HashSet::new();
+    for path in paths {
+        let mut summary = match gitkyl::summarize_repository(
+            &config.repo,
+            &path,
+            config.owner.as_deref(),
+            &ref_filter,
+        ) {
+            Ok(summary) => summary,
+            Err(e) => {
+                eprintln!("✗ {}: {:#}", path.display(), e);
+                continue;
+            }
+        };
+        if let Err(e) = validate_tree_path(&summary.site_path) {
+            eprintln!("Warning: Skipping {}: {:#}", path.display(), e);
+            continue;
+        }
+
+        let relative = path
+            .strip_prefix(&config.repo)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        summary.site_path = unique_site_path(&summary.site_path, &relative, &site_paths);
+        site_paths.insert(summary.site_path.clone());
+
+        let nested = Config {
+            repo: path,
+            output: config.output.join(&summary.site_path),
+            base_url: config
+                .base_url
+                .as_ref()
+                .map(|base| format!("{}/{}", base.trim_end_matches('/'), summary.site_path)),
+            name: Some(summary.name.clone()),
+            owner: summary.owner.clone(),
+            no_open: true,
+            multi: false,
+            ..config.clone()
+        };
+
+        println!("→ repository {}", summary.site_path);
+        match generate_site(&nested) {
+            Ok(()) => repos.push(summary),
+            Err(e) => eprintln!("✗ {}: {:#}", summary.site_path, e),
+        }
+    }
+
+    let title = config
+        .name
+        .clone()
+        .unwrap_or_else(|| "Repositories".to_string());
+    let html = gitkyl::pages::repos::generate(&title, &repos);
+    let index_path = config.output.join("index.html");
+    fs::write(&index_path, html.into_string())
+        .with_context(|| format!("Failed to write index page to {}", index_path.display()))?;
+
+    println!("✓ Generated {} repositories", repos.len());
+
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let config = Config::parse();
+    config.validate().context("Invalid configuration")?;
+
+    if config.multi {
+        generate_multi_site(&config)?;
+    } else {
+        generate_site(&config)?;
+    }
+
     if !config.no_open {
         let index_path = config.output.join("index.html");
         if index_path.exists()
@@ -910,40 +2578,106 @@ mod tests {
 
     #[test]
     fn test_build_tree_items_empty() {
-        use std::collections::HashMap;
+        use std::collections::{HashMap, HashSet};
 
         // Arrange: empty inputs
         let file_entries = vec![];
         let subdir_names = vec![];
         let dir_path = "";
-        let file_commit_map = HashMap::new();
-        let dir_commit_map = HashMap::new();
+        let commit_map = HashMap::new();
 
         // Act: build tree items
         let items = build_tree_items(
             &file_entries,
+            &[],
             &subdir_names,
             dir_path,
-            &file_commit_map,
-            &dir_commit_map,
+            &commit_map,
+            &HashSet::new(),
+            &SymlinkMap::new(),
         );
 
         // Assert: should return empty vector
         assert_eq!(items.len(), 0, "Expected empty tree items");
     }
 
+    #[test]
+    fn test_build_tree_items_with_symlink() {
+        use std::collections::{HashMap, HashSet};
+
+        // Arrange: one symlink with a known target, one without
+        let file_entries = vec![
+            gitkyl::FileEntry::new(
+                "docs",
+                gix::ObjectId::null(gix::hash::Kind::Sha1),
+                gix::object::tree::EntryKind::Link.into(),
+            ),
+            gitkyl::FileEntry::new(
+                "broken",
+                gix::ObjectId::null(gix::hash::Kind::Sha1),
+                gix::object::tree::EntryKind::Link.into(),
+            ),
+        ];
+        let commit = gitkyl::CommitInfo::new(
+            "abc123".to_string(),
+            "Add links".to_string(),
+            "Add links".to_string(),
+            "Test Author".to_string(),
+            1704067200,
+        );
+        let commit_map: HashMap<String, gitkyl::CommitInfo> = [
+            ("docs".to_string(), commit.clone()),
+            ("broken".to_string(), commit),
+        ]
+        .into();
+        let mut symlinks = SymlinkMap::new();
+        symlinks.insert(
+            "docs".to_string(),
+            (
+                "site/docs".to_string(),
+                Some(gitkyl::SymlinkTarget::Directory("site/docs".to_string())),
+            ),
+        );
+
+        // Act
+        let items = build_tree_items(
+            &file_entries,
+            &[],
+            &[],
+            "",
+            &commit_map,
+            &HashSet::new(),
+            &symlinks,
+        );
+
+        // Assert: unreadable symlinks fall back to plain file rows
+        assert_eq!(items.len(), 2);
+        match &items[0] {
+            TreeItem::Symlink {
+                target, resolved, ..
+            } => {
+                assert_eq!(target, "site/docs");
+                assert_eq!(
+                    resolved,
+                    &Some(gitkyl::SymlinkTarget::Directory("site/docs".to_string()))
+                );
+            }
+            _ => panic!("Expected symlink item"),
+        }
+        assert!(matches!(items[1], TreeItem::File { .. }));
+    }
+
     #[test]
     fn test_build_tree_items_with_dirs() {
-        use std::collections::HashMap;
+        use std::collections::{HashMap, HashSet};
 
         // Arrange: directories only
         let file_entries = vec![];
         let subdir_names = vec!["src", "docs"];
         let dir_path = "";
-        let file_commit_map = HashMap::new();
 
-        let mut dir_commit_map = HashMap::new();
-        dir_commit_map.insert(
+        let mut commit_map = HashMap::new();
+        commit_map.insert(
             "src".to_string(),
             gitkyl::CommitInfo::new(
                 "abc123".to_string(),
@@ -953,7 +2687,7 @@ mod tests {
                 1704067200,
             ),
         );
-        dir_commit_map.insert(
+        commit_map.insert(
             "docs".to_string(),
             gitkyl::CommitInfo::new(
                 "def456".to_string(),
@@ -967,10 +2701,12 @@ mod tests {
         // Act: build tree items
         let items = build_tree_items(
             &file_entries,
+            &[],
             &subdir_names,
             dir_path,
-            &file_commit_map,
-            &dir_commit_map,
+            &commit_map,
+            &HashSet::new(),
+            &SymlinkMap::new(),
         );
 
         // Assert: should have two directory items
@@ -1005,16 +2741,15 @@ mod tests {
 
     #[test]
     fn test_build_tree_items_nested_path() {
-        use std::collections::HashMap;
+        use std::collections::{HashMap, HashSet};
 
         // Arrange: nested directory path
         let file_entries = vec![];
         let subdir_names = vec!["utils"];
         let dir_path = "src/lib";
-        let file_commit_map = HashMap::new();
 
-        let mut dir_commit_map = HashMap::new();
-        dir_commit_map.insert(
+        let mut commit_map = HashMap::new();
+        commit_map.insert(
             "src/lib/utils".to_string(),
             gitkyl::CommitInfo::new(
                 "nested123".to_string(),
@@ -1028,10 +2763,12 @@ mod tests {
         // Act: build tree items
         let items = build_tree_items(
             &file_entries,
+            &[],
             &subdir_names,
             dir_path,
-            &file_commit_map,
-            &dir_commit_map,
+            &commit_map,
+            &HashSet::new(),
+            &SymlinkMap::new(),
         );
 
         // Assert: full path should be constructed correctly
@@ -1050,4 +2787,22 @@ mod tests {
             _ => panic!("Expected directory item"),
         }
     }
+
+    #[test]
+    fn test_unique_site_path_disambiguates_git_suffix() {
+        // Arrange: foo and foo.git both prefer the foo directory
+        let mut used = std::collections::HashSet::new();
+
+        // Act
+        let plain = unique_site_path("foo", "foo", &used);
+        used.insert(plain.clone());
+        let bare = unique_site_path("foo", "foo.git", &used);
+        used.insert(bare.clone());
+        let third = unique_site_path("foo", "foo.git", &used);
+
+        // Assert
+        assert_eq!(plain, "foo");
+        assert_eq!(bare, "foo.git");
+        assert_eq!(third, "foo-2");
+    }
 }
@@ -0,0 +1,248 @@
+//! Build manifest for incremental regeneration.
+//!
+//! The manifest lives in the output directory and records, for every page
+//! written, a key describing the inputs it was rendered from (blob OID,
+//! last commit, ref tip, ...). Later runs compare keys and skip pages whose
+//! inputs did not change. A fingerprint of the generator version and output
+//! affecting options invalidates everything when those change.
+
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Manifest file name inside the output directory.
+pub const MANIFEST_FILE: &str = ".gitkyl-manifest";
+
+/// Format version written on the first line of the manifest.
+const FORMAT_HEADER: &str = "gitkyl-manifest 1";
+
+/// Record of generated pages and the inputs they were built from.
+#[derive(Debug, Clone)]
+pub struct Manifest {
+    output_dir: PathBuf,
+    fingerprint: String,
+    refs: BTreeMap<String, String>,
+    pages: BTreeMap<String, String>,
+}
+
+impl Manifest {
+    /// Creates an empty manifest that treats every page as stale.
+    ///
+    /// # Arguments
+    ///
+    /// * `output_dir`: Output directory the manifest describes
+    /// * `fingerprint`: Generator version and options affecting output
+    pub fn empty(output_dir: impl AsRef<Path>, fingerprint: &str) -> Self {
+        Self {
+            output_dir: output_dir.as_ref().to_path_buf(),
+            fingerprint: fingerprint.to_string(),
+            refs: BTreeMap::new(),
+            pages: BTreeMap::new(),
+        }
+    }
+
+    /// Loads the manifest from a previous run.
+    ///
+    /// A missing manifest, an unknown format, or a fingerprint different
+    /// from `fingerprint` yields an empty manifest so everything is rebuilt.
+    ///
+    /// # Arguments
+    ///
+    /// * `output_dir`: Output directory containing the manifest
+    /// * `fingerprint`: Generator version and options affecting output
+    ///
+    /// # Returns
+    ///
+    /// Manifest with previous entries, or an empty one
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the manifest exists but cannot be read
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gitkyl::Manifest;
+    ///
+    /// let manifest = Manifest::load("dist", "0.1.1 Catppuccin-Latte")?;
+    /// if manifest.is_fresh("blob/main/README.md.html", "3b18e51") {
+    ///     println!("README page is up to date");
+    /// }
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn load(output_dir: impl AsRef<Path>, fingerprint: &str) -> Result<Self> {
+        let mut manifest = Self::empty(&output_dir, fingerprint);
+        let path = output_dir.as_ref().join(MANIFEST_FILE);
+
+        if !path.exists() {
+            return Ok(manifest);
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read manifest {}", path.display()))?;
+        let mut lines = content.lines();
+
+        if lines.next() != Some(FORMAT_HEADER)
+            || lines.next() != Some(&format!("fingerprint\t{}", fingerprint))
+        {
+            return Ok(manifest);
+        }
+
+        for line in lines {
+            let mut fields = line.splitn(3, '\t');
+            match (fields.next(), fields.next(), fields.next()) {
+                (Some("ref"), Some(name), Some(key)) => {
+                    manifest.refs.insert(name.to_string(), key.to_string());
+                }
+                (Some("page"), Some(page), Some(key)) => {
+                    manifest.pages.insert(page.to_string(), key.to_string());
+                }
+                _ => {}
+            }
+        }
+
+        Ok(manifest)
+    }
+
+    /// Writes the manifest into the output directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the manifest file cannot be written
+    pub fn save(&self) -> Result<()> {
+        let mut content = format!("{}\nfingerprint\t{}\n", FORMAT_HEADER, self.fingerprint);
+
+        for (name, key) in &self.refs {
+            content.push_str(&format!("ref\t{}\t{}\n", name, key));
+        }
+        for (page, key) in &self.pages {
+            content.push_str(&format!("page\t{}\t{}\n", page, key));
+        }
+
+        let path = self.output_dir.join(MANIFEST_FILE);
+        fs::write(&path, content)
+            .with_context(|| format!("Failed to write manifest {}", path.display()))
+    }
+
+    /// Checks whether a ref was fully generated from the same inputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: Branch or tag name
+    /// * `key`: Current ref key, typically the tip commit OID
+    pub fn is_ref_fresh(&self, name: &str, key: &str) -> bool {
+        self.refs.get(name).is_some_and(|previous| previous == key)
+    }
+
+    /// Records that all pages of a ref were generated from `key`.
+    pub fn record_ref(&mut self, name: &str, key: &str) {
+        if is_storable(name) && is_storable(key) {
+            self.refs.insert(name.to_string(), key.to_string());
+        }
+    }
+
+    /// Checks whether a page exists and was rendered from the same inputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `page`: Page path relative to the output directory
+    /// * `key`: Current input key of the page
+    pub fn is_fresh(&self, page: &str, key: &str) -> bool {
+        self.pages.get(page).is_some_and(|previous| previous == key)
+            && self.output_dir.join(page).exists()
+    }
+
+    /// Records that a page was rendered from `key`.
+    pub fn record(&mut self, page: &str, key: &str) {
+        if is_storable(page) && is_storable(key) {
+            self.pages.insert(page.to_string(), key.to_string());
+        }
+    }
+}
+
+/// Empty entries or ones containing separators are never cached.
+fn is_storable(value: &str) -> bool {
+    !value.is_empty() && !value.contains(['\t', '\n', '\r'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_round_trip() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("page.html"), "x").unwrap();
+        let mut manifest = Manifest::empty(dir.path(), "v1");
+        manifest.record("page.html", "abc");
+        manifest.record_ref("main", "def");
+
+        // Act
+        manifest.save().unwrap();
+        let loaded = Manifest::load(dir.path(), "v1").unwrap();
+
+        // Assert
+        assert!(loaded.is_fresh("page.html", "abc"));
+        assert!(!loaded.is_fresh("page.html", "changed"));
+        assert!(loaded.is_ref_fresh("main", "def"));
+        assert!(!loaded.is_ref_fresh("dev", "def"));
+    }
+
+    #[test]
+    fn test_fingerprint_change_invalidates() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("page.html"), "x").unwrap();
+        let mut manifest = Manifest::empty(dir.path(), "v1");
+        manifest.record("page.html", "abc");
+        manifest.save().unwrap();
+
+        // Act
+        let loaded = Manifest::load(dir.path(), "v2").unwrap();
+
+        // Assert
+        assert!(!loaded.is_fresh("page.html", "abc"));
+    }
+
+    #[test]
+    fn test_missing_page_is_stale() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let mut manifest = Manifest::empty(dir.path(), "v1");
+
+        // Act
+        manifest.record("deleted.html", "abc");
+
+        // Assert
+        assert!(!manifest.is_fresh("deleted.html", "abc"));
+    }
+
+    #[test]
+    fn test_load_missing_manifest() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+
+        // Act
+        let manifest = Manifest::load(dir.path(), "v1").unwrap();
+
+        // Assert
+        assert!(!manifest.is_ref_fresh("main", "abc"));
+    }
+
+    #[test]
+    fn test_unstorable_entries_are_skipped() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("a\tb.html"), "x").unwrap();
+        let mut manifest = Manifest::empty(dir.path(), "v1");
+
+        // Act
+        manifest.record("a\tb.html", "abc");
+
+        // Assert
+        assert!(!manifest.is_fresh("a\tb.html", "abc"));
+    }
+}
@@ -59,11 +59,12 @@ impl LinkResolver {
     /// - Relative paths (./file.rs) resolve to blob/branch/path.html
     /// - Parent paths (../file.rs) resolve relative to current file
     /// - Directory links (./dir/) resolve to tree/branch/dir.html
+    /// - Images resolve to the raw file copy at raw/branch/path
     ///
     /// # Arguments
     ///
     /// * `link`: Link href from markdown
-    /// * `is_image`: Whether link is for image (skips .html extension)
+    /// * `is_image`: Whether link is for image (points at the raw file)
     ///
     /// # Returns
     ///
@@ -104,8 +105,8 @@ impl LinkResolver {
 
         // Regular file: blob page
         if is_image {
-            // Images: raw file path without .html
-            Ok(format!("{}blob/{}/{}", prefix, self.branch, path_str))
+            // Images: raw file copy
+            Ok(format!("{}raw/{}/{}", prefix, self.branch, path_str))
         } else {
             // Links: HTML page
             Ok(format!("{}blob/{}/{}.html", prefix, self.branch, path_str))
@@ -224,8 +225,8 @@ mod tests {
 
         // Assert
         assert_eq!(
-            result, "blob/main/docs/assets/logo.png",
-            "Images should not have .html extension"
+            result, "raw/main/docs/assets/logo.png",
+            "Images should point at the raw file"
         );
     }
 
@@ -378,6 +379,6 @@ mod tests {
             .expect("Should resolve image with depth");
 
         // Assert
-        assert_eq!(result, "../../../blob/dev/assets/icon.png");
+        assert_eq!(result, "../../../raw/dev/assets/icon.png");
     }
 }
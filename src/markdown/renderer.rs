@@ -792,8 +792,8 @@ const x = "<script>alert('xss')</script>";
             html
         );
         assert!(
-            html.contains("src=\"blob/main/assets/logo.png\""),
-            "Should resolve image without .html extension: {}",
+            html.contains("src=\"raw/main/assets/logo.png\""),
+            "Should resolve image to its raw file: {}",
             html
         );
     }
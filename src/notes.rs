@@ -0,0 +1,417 @@
+//! Git notes attached to commits.
+//!
+//! Notes are stored on their own refs (`refs/notes/commits` unless
+//! configured otherwise). Each notes ref points at a commit whose tree maps
+//! annotated object ids to note blobs; large notes trees split the ids into
+//! fanout directories (`ab/cdef...`), which are flattened when reading.
+
+use anyhow::{Context, Result};
+use gix::bstr::ByteSlice;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::git::CommitInfo;
+use crate::glob::glob_match;
+
+/// Namespace of notes refs.
+const NOTES_PREFIX: &str = "refs/notes/";
+
+/// Note attached to a commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Note {
+    notes_ref: String,
+    text: String,
+}
+
+impl Note {
+    /// Creates a note.
+    ///
+    /// # Arguments
+    ///
+    /// * `notes_ref`: Notes ref name without `refs/notes/` (e.g. "ci")
+    /// * `text`: Note content
+    pub fn new(notes_ref: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            notes_ref: notes_ref.into(),
+            text: text.into(),
+        }
+    }
+
+    /// Notes ref the note was read from, without `refs/notes/`.
+    pub fn notes_ref(&self) -> &str {
+        &self.notes_ref
+    }
+
+    /// Note content.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Notes of a repository, indexed by annotated commit.
+#[derive(Debug, Clone, Default)]
+pub struct Notes {
+    by_commit: HashMap<String, Vec<Note>>,
+    fingerprint: String,
+}
+
+impl Notes {
+    /// Loads the notes from the refs matching the given patterns.
+    ///
+    /// Patterns are full ref names or globs (`refs/notes/*`); names without
+    /// a `refs/` prefix are taken relative to `refs/notes/`, like git's
+    /// `--notes=<ref>`. A commit annotated on several refs keeps one note
+    /// per ref, in pattern order and then by ref name.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_path`: Path to git repository
+    /// * `patterns`: Notes refs to read
+    ///
+    /// # Returns
+    ///
+    /// Loaded notes, empty when no notes ref matches
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the repository or a notes tree cannot be read
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gitkyl::Notes;
+    /// use std::path::Path;
+    ///
+    /// let notes = Notes::load(Path::new("."), &["refs/notes/*".to_string()])?;
+    /// for note in notes.for_commit("0123456789abcdef0123456789abcdef01234567") {
+    ///     println!("{}: {}", note.notes_ref(), note.text());
+    /// }
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn load(repo_path: impl AsRef<Path>, patterns: &[String]) -> Result<Self> {
+        let repo = gix::open(repo_path.as_ref()).with_context(|| {
+            format!(
+                "Failed to open repository at {}",
+                repo_path.as_ref().display()
+            )
+        })?;
+
+        let mut available: Vec<(String, gix::ObjectId)> = Vec::new();
+        let references = repo.references().context("Failed to read references")?;
+        for reference in references
+            .prefixed(NOTES_PREFIX)
+            .context("Failed to list notes refs")?
+            .flatten()
+        {
+            let name = reference.name().as_bstr().to_str_lossy().to_string();
+            let mut reference = reference;
+            if let Ok(id) = reference.peel_to_id_in_place() {
+                available.push((name, id.detach()));
+            }
+        }
+        available.sort();
+
+        let mut selected: Vec<&(String, gix::ObjectId)> = Vec::new();
+        for pattern in patterns {
+            let pattern = if pattern.starts_with("refs/") {
+                pattern.clone()
+            } else {
+                format!("{}{}", NOTES_PREFIX, pattern)
+            };
+            for entry in &available {
+                if glob_match(&pattern, &entry.0) && !selected.contains(&entry) {
+                    selected.push(entry);
+                }
+            }
+        }
+
+        let mut notes = Self::default();
+        let mut tips = String::new();
+        for (name, id) in selected {
+            tips.push_str(&format!("{} {}\n", name, id));
+            let short_name = name.strip_prefix(NOTES_PREFIX).unwrap_or(name);
+            let tree = repo
+                .find_object(*id)
+                .with_context(|| format!("Failed to find notes commit for {}", name))?
+                .peel_to_tree()
+                .with_context(|| format!("Failed to read notes tree of {}", name))?;
+
+            for entry in tree
+                .traverse()
+                .breadthfirst
+                .files()
+                .with_context(|| format!("Failed to traverse notes tree of {}", name))?
+            {
+                if !entry.mode.is_blob() {
+                    continue;
+                }
+                // Fanout directories split the annotated id into path components
+                let target: String = entry
+                    .filepath
+                    .to_str_lossy()
+                    .chars()
+                    .filter(|&c| c != '/')
+                    .collect();
+                if gix::ObjectId::from_hex(target.as_bytes()).is_err() {
+                    continue;
+                }
+
+                let blob = repo
+                    .find_object(entry.oid)
+                    .with_context(|| format!("Failed to read note for {}", target))?;
+                let text = blob.data.to_str_lossy().trim_end().to_string();
+                notes
+                    .by_commit
+                    .entry(target.to_ascii_lowercase())
+                    .or_default()
+                    .push(Note::new(short_name, text));
+            }
+        }
+
+        if !tips.is_empty() {
+            notes.fingerprint = gix::objs::compute_hash(
+                gix::hash::Kind::Sha1,
+                gix::objs::Kind::Blob,
+                tips.as_bytes(),
+            )
+            .to_hex_with_len(12)
+            .to_string();
+        }
+
+        Ok(notes)
+    }
+
+    /// Notes attached to a commit.
+    pub fn for_commit(&self, oid: &str) -> &[Note] {
+        self.by_commit.get(oid).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns true if no commit has a note.
+    pub fn is_empty(&self) -> bool {
+        self.by_commit.is_empty()
+    }
+
+    /// Short hash of the loaded notes refs, empty when none were read.
+    pub fn fingerprint(&self) -> &str {
+        &self.fingerprint
+    }
+
+    /// Short hash of the notes attached to the given commits.
+    ///
+    /// Lets a page be keyed on the notes it shows, so editing one note
+    /// only invalidates pages listing that commit.
+    ///
+    /// # Arguments
+    ///
+    /// * `oids`: Full hashes of the commits shown on a page
+    ///
+    /// # Returns
+    ///
+    /// Hash of the notes in commit order, empty when none has a note
+    pub fn fingerprint_for<S: AsRef<str>>(&self, oids: impl IntoIterator<Item = S>) -> String {
+        if self.is_empty() {
+            return String::new();
+        }
+
+        let mut content = String::new();
+        for oid in oids {
+            let oid = oid.as_ref();
+            for note in self.for_commit(oid) {
+                content.push_str(&format!("{} {}\n{}\0", oid, note.notes_ref, note.text));
+            }
+        }
+        if content.is_empty() {
+            return String::new();
+        }
+
+        gix::objs::compute_hash(
+            gix::hash::Kind::Sha1,
+            gix::objs::Kind::Blob,
+            content.as_bytes(),
+        )
+        .to_hex_with_len(12)
+        .to_string()
+    }
+
+    /// Attaches the notes of each commit to it.
+    pub fn apply(&self, commits: &mut [CommitInfo]) {
+        if self.is_empty() {
+            return;
+        }
+        for commit in commits {
+            let notes = self.for_commit(commit.oid()).to_vec();
+            commit.set_notes(notes);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("Failed to run git");
+        assert!(output.status.success(), "git {:?} failed", args);
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    fn mktree(dir: &Path, listing: &str) -> String {
+        use std::io::Write;
+        use std::process::Stdio;
+
+        let mut child = Command::new("git")
+            .args(["mktree"])
+            .current_dir(dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("Failed to run git mktree");
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(listing.as_bytes())
+            .unwrap();
+        let output = child.wait_with_output().unwrap();
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    fn notes_repo() -> (tempfile::TempDir, String, String) {
+        let td = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let repo = td.path();
+        git(repo, &["init"]);
+        git(repo, &["config", "user.name", "Test"]);
+        git(repo, &["config", "user.email", "test@test.com"]);
+        git(repo, &["commit", "--allow-empty", "-m", "First"]);
+        let first = git(repo, &["rev-parse", "HEAD"]);
+        git(repo, &["commit", "--allow-empty", "-m", "Second"]);
+        let second = git(repo, &["rev-parse", "HEAD"]);
+
+        git(repo, &["notes", "add", "-m", "Reviewed-by: Alice", &first]);
+        git(
+            repo,
+            &[
+                "notes",
+                "--ref",
+                "ci",
+                "add",
+                "-m",
+                "**Build passed**",
+                &first,
+            ],
+        );
+        git(
+            repo,
+            &["notes", "--ref", "ci", "add", "-m", "Build failed", &second],
+        );
+        (td, first, second)
+    }
+
+    #[test]
+    fn test_load_default_notes_ref() {
+        // Arrange
+        let (td, first, second) = notes_repo();
+
+        // Act
+        let notes = Notes::load(td.path(), &["commits".to_string()]).expect("Should load");
+
+        // Assert
+        assert_eq!(
+            notes.for_commit(&first),
+            &[Note::new("commits", "Reviewed-by: Alice")]
+        );
+        assert!(notes.for_commit(&second).is_empty());
+        assert!(!notes.fingerprint().is_empty());
+    }
+
+    #[test]
+    fn test_load_notes_glob_orders_by_ref() {
+        // Arrange
+        let (td, first, second) = notes_repo();
+
+        // Act
+        let notes = Notes::load(td.path(), &["refs/notes/*".to_string()]).expect("Should load");
+
+        // Assert
+        let refs: Vec<&str> = notes
+            .for_commit(&first)
+            .iter()
+            .map(Note::notes_ref)
+            .collect();
+        assert_eq!(refs, vec!["ci", "commits"]);
+        assert_eq!(notes.for_commit(&second)[0].text(), "Build failed");
+    }
+
+    #[test]
+    fn test_fingerprint_for_covers_only_given_commits() {
+        // Arrange
+        let (td, first, second) = notes_repo();
+        let before = Notes::load(td.path(), &["refs/notes/*".to_string()]).expect("Should load");
+        git(
+            td.path(),
+            &[
+                "notes",
+                "--ref",
+                "ci",
+                "add",
+                "-f",
+                "-m",
+                "Build fixed",
+                &second,
+            ],
+        );
+
+        // Act
+        let after = Notes::load(td.path(), &["refs/notes/*".to_string()]).expect("Should load");
+
+        // Assert
+        assert_eq!(
+            before.fingerprint_for([first.as_str()]),
+            after.fingerprint_for([first.as_str()])
+        );
+        assert_ne!(
+            before.fingerprint_for([second.as_str()]),
+            after.fingerprint_for([second.as_str()])
+        );
+        assert_eq!(after.fingerprint_for([second.as_str()]).len(), 12);
+        assert!(after.fingerprint_for(["0".repeat(40)]).is_empty());
+    }
+
+    #[test]
+    fn test_load_fanout_notes_tree() {
+        // Arrange: a notes tree with the id split into a fanout directory
+        let (td, first, _) = notes_repo();
+        let repo = td.path();
+        let blob = git(repo, &["hash-object", "-w", "--stdin"]);
+        let inner = mktree(repo, &format!("100644 blob {}\t{}\n", blob, &first[2..]));
+        let outer = mktree(repo, &format!("040000 tree {}\t{}\n", inner, &first[..2]));
+        let commit = git(repo, &["commit-tree", &outer, "-m", "Notes"]);
+        git(repo, &["update-ref", "refs/notes/fanout", &commit]);
+
+        // Act
+        let notes = Notes::load(repo, &["fanout".to_string()]).expect("Should load");
+
+        // Assert
+        assert_eq!(notes.for_commit(&first).len(), 1);
+        assert_eq!(notes.for_commit(&first)[0].notes_ref(), "fanout");
+    }
+
+    #[test]
+    fn test_load_without_notes_refs() {
+        // Arrange
+        let td = tempfile::TempDir::new().expect("Failed to create temp dir");
+        git(td.path(), &["init"]);
+
+        // Act
+        let notes = Notes::load(td.path(), &["commits".to_string()]).expect("Should load");
+
+        // Assert
+        assert!(notes.is_empty());
+        assert!(notes.fingerprint().is_empty());
+    }
+}
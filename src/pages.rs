@@ -4,8 +4,15 @@
 //! tree, etc.). Each page module handles its specific view logic and
 //! utilizes shared components from the components module.
 
+pub mod activity;
+pub mod blame;
 pub mod blob;
+pub mod commit;
 pub mod commits;
+pub mod contributors;
+pub mod history;
 pub mod index;
+pub mod languages;
+pub mod repos;
 pub mod tags;
 pub mod tree;
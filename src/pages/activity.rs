@@ -0,0 +1,118 @@
+//! Repository activity page generation
+
+use maud::{Markup, html};
+
+use crate::activity::Activity;
+use crate::components::activity::{
+    CALENDAR_WEEKS, calendar, calendar_legend, commits_label, punch_card,
+};
+use crate::components::layout::page_wrapper;
+use crate::components::nav::breadcrumb;
+use crate::util::{format_date, format_timestamp};
+
+/// Generates the repository activity page
+///
+/// Shows a year-long contribution calendar and a weekday by hour punch card
+/// of the commits on the default branch.
+///
+/// # Arguments
+///
+/// * `repo_name`: Repository name for page title and breadcrumb
+/// * `branch`: Branch whose commits were counted
+/// * `activity`: Commit activity of the branch
+///
+/// # Returns
+///
+/// Complete HTML page as Markup
+pub fn generate(repo_name: &str, branch: &str, activity: &Activity) -> Markup {
+    let css_path = "../assets/activity.css";
+    let index_path = "../index.html";
+    let year_start = activity.today() - 7 * CALENDAR_WEEKS as i64;
+
+    page_wrapper(
+        &format!("{}: activity", repo_name),
+        &[css_path],
+        html! {
+            (breadcrumb(repo_name, index_path, &[("Activity", None)], branch))
+
+            main.repo-card {
+                div.repo-controls {
+                    div.control-left {
+                        h1.page-title {
+                            i.ph.ph-pulse {}
+                            "Activity"
+                        }
+                    }
+                    div.control-right {
+                        span.badge { (commits_label(activity.commits_since(year_start))) " in the past year" }
+                    }
+                }
+
+                div.activity-content {
+                    p.activity-summary {
+                        @if let Some(last) = activity.last_commit() {
+                            "Last commit on " (format_date(last)) " (" (format_timestamp(last)) "), "
+                            (commits_label(activity.total())) " in total."
+                        } @else {
+                            "No commits on " (branch) "."
+                        }
+                    }
+
+                    section.activity-section {
+                        h2.section-title { "Commit calendar" }
+                        div.activity-scroll {
+                            (calendar(activity, CALENDAR_WEEKS, true))
+                        }
+                        (calendar_legend())
+                    }
+
+                    section.activity-section {
+                        h2.section-title { "Punch card" }
+                        div.activity-scroll {
+                            (punch_card(activity))
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::CommitInfo;
+
+    #[test]
+    fn test_generate_shows_calendar_and_punch_card() {
+        // Arrange
+        let now = 1_700_000_000;
+        let commits = vec![CommitInfo::new(
+            "abc1234".to_string(),
+            "Change".to_string(),
+            "Change".to_string(),
+            "Alice".to_string(),
+            now - 86_400,
+        )];
+        let activity = Activity::from_commits(&commits, now);
+
+        // Act
+        let html = generate("test-repo", "main", &activity).into_string();
+
+        // Assert
+        assert!(html.contains("../assets/activity.css"));
+        assert!(html.contains("1 commit in the past year"));
+        assert!(html.contains("Last commit on 2023-11-13"));
+        assert!(html.contains(r#"class="activity-calendar""#));
+        assert!(html.contains(r#"class="activity-punch-card""#));
+    }
+
+    #[test]
+    fn test_generate_empty_branch() {
+        // Act
+        let html = generate("test-repo", "main", &Activity::default()).into_string();
+
+        // Assert
+        assert!(html.contains("No commits on main."));
+    }
+}
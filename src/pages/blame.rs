@@ -0,0 +1,285 @@
+//! Blame page generation for line level attribution
+
+use anyhow::{Context, Result};
+use maud::{Markup, PreEscaped, html};
+use std::path::Path;
+
+use crate::avatar;
+use crate::blame::{BlameHunk, blame_file};
+use crate::components::layout::page_wrapper;
+use crate::components::nav::{breadcrumb, extract_breadcrumb_components};
+use crate::git::{CommitOrder, read_blob};
+use crate::highlight::Highlighter;
+use crate::mailmap::Mailmap;
+use crate::util::{calculate_depth, format_timestamp};
+
+/// Generates blame page annotating each line with its last commit
+///
+/// Lines are grouped into hunks of consecutive lines introduced by the same
+/// commit. Each hunk shows the short commit hash linking to the commit page,
+/// the author avatar and name, and the relative commit date. Source lines
+/// keep the syntax highlighting of the blob view.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `ref_name`: Git reference (branch/tag/commit)
+/// * `order`: History of the ref, shared by all files of the ref
+/// * `file_path`: File path within repository tree
+/// * `repo_name`: Repository name for breadcrumb navigation
+/// * `theme`: Syntax highlighting theme name
+/// * `mailmap`: Mailmap applied to commit identities
+///
+/// # Returns
+///
+/// HTML markup ready for writing to `blame/<ref>/<path>.html`
+///
+/// # Errors
+///
+/// Returns error if:
+/// - Blob cannot be read or contains invalid UTF-8
+/// - History cannot be walked
+/// - Highlighting fails
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::pages::blame::generate;
+/// use gitkyl::{CommitOrder, Mailmap};
+/// use std::path::Path;
+///
+/// let order = CommitOrder::load(Path::new("."), Some("main"))?;
+/// let html = generate(
+///     Path::new("."),
+///     "main",
+///     &order,
+///     Path::new("src/lib.rs"),
+///     "my-repo",
+///     "Catppuccin-Latte",
+///     &Mailmap::default()
+/// )?;
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn generate(
+    repo_path: impl AsRef<Path>,
+    ref_name: &str,
+    order: &CommitOrder,
+    file_path: impl AsRef<Path>,
+    repo_name: &str,
+    theme: &str,
+    mailmap: &Mailmap,
+) -> Result<Markup> {
+    let path_str = file_path.as_ref().display().to_string();
+
+    let content_bytes = read_blob(&repo_path, Some(ref_name), &file_path)
+        .with_context(|| format!("Failed to read blob from repository: {}", path_str))?;
+    let content = String::from_utf8(content_bytes)
+        .with_context(|| format!("Blob contains invalid UTF8: {}", path_str))?;
+
+    let hunks = blame_file(&repo_path, order, &file_path, mailmap)
+        .with_context(|| format!("Failed to blame: {}", path_str))?;
+
+    let highlighter = Highlighter::with_theme(theme)
+        .or_else(|_| Highlighter::new())
+        .context("Failed to create highlighter")?;
+    let highlighted_lines = highlighter
+        .highlight(&content, file_path.as_ref())
+        .with_context(|| format!("Failed to highlight: {}", path_str))?;
+
+    Ok(blame_page_markup(
+        &path_str,
+        ref_name,
+        repo_name,
+        &hunks,
+        &highlighted_lines,
+    ))
+}
+
+/// Renders blame page HTML structure
+fn blame_page_markup(
+    file_path: &str,
+    ref_name: &str,
+    repo_name: &str,
+    hunks: &[BlameHunk],
+    highlighted_lines: &[String],
+) -> Markup {
+    let depth = calculate_depth(ref_name, file_path);
+    let root = "../".repeat(depth);
+    let index_path = format!("{}index.html", root);
+    let css_path = format!("{}assets/blame.css", root);
+    let blob_link = format!("{}blob/{}/{}.html", root, ref_name, file_path);
+
+    let breadcrumb_components = extract_breadcrumb_components(file_path);
+    let breadcrumb_data: Vec<(&str, Option<String>)> = breadcrumb_components
+        .iter()
+        .enumerate()
+        .map(|(idx, &component)| {
+            if idx == breadcrumb_components.len() - 1 {
+                (component, Some(blob_link.clone()))
+            } else {
+                let partial_path = breadcrumb_components[..=idx].join("/");
+                let link = format!("{}tree/{}/{}.html", root, ref_name, partial_path);
+                (component, Some(link))
+            }
+        })
+        .chain(std::iter::once(("blame", None)))
+        .collect();
+
+    let title = format!("{}/{}: {} (blame)", repo_name, ref_name, file_path);
+
+    let file_name = Path::new(file_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(file_path);
+
+    page_wrapper(
+        &title,
+        &[&css_path],
+        html! {
+            (breadcrumb(repo_name, &index_path, &breadcrumb_data, ref_name))
+            div class="blob-card" {
+                div class="blob-header" {
+                    div class="blob-header-left" {
+                        i class="ph ph-file-code" {}
+                        span class="blob-filename" { (file_name) }
+                        span class="blob-lines" { (highlighted_lines.len()) " lines" }
+                    }
+                    div class="view-toggle" {
+                        a href=(blob_link) class="view-tab" {
+                            i class="ph ph-code" {}
+                            " Code"
+                        }
+                        span class="view-tab active" {
+                            i class="ph ph-user-list" {}
+                            " Blame"
+                        }
+                    }
+                }
+                div class="blob-code-wrapper" {
+                    table class="blob-code blame-code" {
+                        tbody {
+                            @for hunk in hunks {
+                                @for offset in 0..hunk.line_count {
+                                    @let line_num = hunk.start_line + offset;
+                                    @let line = highlighted_lines.get(line_num - 1).map(String::as_str).unwrap_or("");
+                                    tr id=(format!("L{}", line_num)) class=(if offset == 0 { "code-line hunk-start" } else { "code-line" }) {
+                                        @if offset == 0 {
+                                            td class="blame-info" rowspan=(hunk.line_count) {
+                                                (blame_info(hunk, &root))
+                                            }
+                                        }
+                                        td class="line-number" data-line=(line_num) {
+                                            a href=(format!("#L{}", line_num)) { (line_num) }
+                                        }
+                                        td class="line-content" {
+                                            (PreEscaped(line))
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
+
+/// Renders the commit annotation shown at the start of a blame hunk
+fn blame_info(hunk: &BlameHunk, root: &str) -> Markup {
+    let commit = &hunk.commit;
+    html! {
+        div class="blame-commit" {
+            (avatar::render(commit.author(), 16))
+            a class="blame-hash" href=(format!("{}commit/{}.html", root, commit.oid())) title=(commit.message()) {
+                (commit.short_oid())
+            }
+            span class="blame-date" { (format_timestamp(commit.date())) }
+        }
+        div class="blame-author" { (commit.author()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::CommitInfo;
+
+    fn hunk(oid: &str, author: &str, start_line: usize, line_count: usize) -> BlameHunk {
+        BlameHunk {
+            commit: CommitInfo::new(
+                oid.to_string(),
+                format!("Commit by {}", author),
+                format!("Commit by {}", author),
+                author.to_string(),
+                1704067200,
+            ),
+            start_line,
+            line_count,
+        }
+    }
+
+    #[test]
+    fn test_blame_page_markup_hunks() {
+        // Arrange
+        let hunks = vec![
+            hunk("aaaaaaa111", "Alice", 1, 2),
+            hunk("bbbbbbb222", "Bob", 3, 1),
+        ];
+        let lines = vec!["one".to_string(), "two".to_string(), "three".to_string()];
+
+        // Act
+        let html =
+            blame_page_markup("src/lib.rs", "main", "test-repo", &hunks, &lines).into_string();
+
+        // Assert
+        assert_eq!(
+            html.matches("hunk-start").count(),
+            2,
+            "One row per hunk start"
+        );
+        assert!(html.contains("rowspan=\"2\""), "First hunk spans two lines");
+        assert!(html.contains("../../../commit/aaaaaaa111.html"));
+        assert!(html.contains("Alice"));
+        assert!(html.contains("Bob"));
+        assert!(html.contains("id=\"L3\""), "Should anchor every line");
+    }
+
+    #[test]
+    fn test_blame_page_links_back_to_blob() {
+        // Arrange
+        let hunks = vec![hunk("aaaaaaa111", "Alice", 1, 1)];
+        let lines = vec!["one".to_string()];
+
+        // Act
+        let html = blame_page_markup("README.md", "dev", "test-repo", &hunks, &lines).into_string();
+
+        // Assert
+        assert!(html.contains("../../blob/dev/README.md.html"));
+        assert!(html.contains("assets/blame.css"));
+    }
+
+    #[test]
+    fn test_generate_from_repository() {
+        // Arrange
+        let repo_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let order = CommitOrder::load(&repo_path, Some("master")).expect("Should walk history");
+
+        // Act
+        let html = generate(
+            &repo_path,
+            "master",
+            &order,
+            "Cargo.toml",
+            "gitkyl",
+            "InspiredGitHub",
+            &Mailmap::default(),
+        )
+        .expect("Should generate blame page")
+        .into_string();
+
+        // Assert
+        assert!(html.contains("blame-info"));
+        assert!(html.contains("[package]"));
+    }
+}
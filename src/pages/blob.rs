@@ -5,14 +5,22 @@ use base64::{Engine, engine::general_purpose::STANDARD};
 use maud::{Markup, PreEscaped, html};
 use std::path::Path;
 
+use crate::components::file_list::symlink_href;
 use crate::components::layout::page_wrapper;
 use crate::components::nav::{breadcrumb, extract_breadcrumb_components};
-use crate::filetype::{FileType, ImageFormat, detect_file_type};
-use crate::git::read_blob;
+use crate::filetype::{FileType, ImageFormat, LfsPointer, detect_file_type};
+use crate::git::{read_blob, read_lfs_object};
 use crate::highlight::Highlighter;
 use crate::markdown::MarkdownRenderer;
+use crate::tree::SymlinkTarget;
 use crate::util::{calculate_depth, format_file_size};
 
+/// Largest image embedded in its blob page as a data URL
+///
+/// Bigger images are loaded from their raw copy instead, keeping blob pages
+/// small; tiny icons stay inline so the page renders on its own.
+const INLINE_IMAGE_LIMIT: usize = 32 * 1024;
+
 /// File metadata for display in blob header
 struct FileMetadata {
     line_count: usize,
@@ -48,8 +56,12 @@ impl FileMetadata {
 ///
 /// Detects file type (text, image, or binary) and dispatches to the appropriate
 /// renderer. Text files get syntax highlighting, images are displayed inline,
-/// and binary files show an informative message. All file types return success
-/// to prevent warnings for normal repository content.
+/// and binary files show an informative message. Git LFS pointers are
+/// replaced by the real object when it exists in the local LFS store, and
+/// otherwise show the object size and OID. Text from the LFS store gets no
+/// Blame link, since the file's history only holds pointers. All file types
+/// return success to
+/// prevent warnings for normal repository content.
 ///
 /// # Arguments
 ///
@@ -108,15 +120,34 @@ pub fn generate(
     let content_bytes = read_blob(&repo_path, Some(ref_name), &file_path)
         .with_context(|| format!("Failed to read blob from repository: {}", path_str))?;
 
-    let file_type = detect_file_type(&content_bytes, file_path.as_ref());
+    let mut file_type = detect_file_type(&content_bytes, file_path.as_ref());
+    let mut content_bytes = content_bytes;
+    let from_lfs = file_type == FileType::LfsPointer;
+
+    if from_lfs {
+        let pointer = LfsPointer::parse(&content_bytes)
+            .with_context(|| format!("Invalid LFS pointer: {}", path_str))?;
+
+        match read_lfs_object(&repo_path, &pointer)? {
+            Some(object) => {
+                file_type = detect_file_type(&object, file_path.as_ref());
+                content_bytes = object;
+            }
+            None => {
+                return generate_lfs_blob(&pointer, file_path.as_ref(), ref_name, repo_name);
+            }
+        }
+    }
 
     match file_type {
-        FileType::Text => generate_text_blob(
+        // An LFS object that is itself pointer-shaped is shown verbatim
+        FileType::Text | FileType::LfsPointer => generate_text_blob(
             &content_bytes,
             file_path.as_ref(),
             ref_name,
             repo_name,
             theme,
+            !from_lfs,
         ),
         FileType::Image(format) => generate_image_blob(
             &content_bytes,
@@ -134,8 +165,8 @@ pub fn generate(
 /// Generates HTML blob page with rendered markdown
 ///
 /// Reads markdown content from repository and renders using GitHub Flavored
-/// Markdown with tables, strikethrough, autolinks, and task lists. Link
-/// resolution is not yet implemented (links remain as is).
+/// Markdown with tables, strikethrough, autolinks, and task lists. Relative
+/// links resolve to blob and tree pages, and images to their raw files.
 ///
 /// # Arguments
 ///
@@ -183,7 +214,8 @@ pub fn generate_markdown(
     let content = String::from_utf8(content_bytes)
         .with_context(|| format!("Blob contains invalid UTF8: {}", path_str))?;
 
-    let renderer = MarkdownRenderer::new();
+    let depth = calculate_depth(ref_name, &path_str);
+    let renderer = MarkdownRenderer::with_link_resolver_depth(ref_name, &path_str, depth);
     let rendered_html = renderer
         .render(&content)
         .with_context(|| format!("Failed to render markdown: {}", path_str))?;
@@ -199,10 +231,75 @@ pub fn generate_markdown(
     ))
 }
 
+/// Generates HTML page for a symbolic link
+///
+/// Symlink blobs hold the link target instead of file content, so the page
+/// shows the target path, linked to its blob or tree page when it resolves
+/// inside the repository.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `ref_name`: Git reference (branch/tag/commit)
+/// * `file_path`: Symlink path within repository tree
+/// * `repo_name`: Repository name for breadcrumb navigation
+/// * `resolved`: Target location inside the repository, if any
+///
+/// # Returns
+///
+/// HTML markup ready for writing to disk
+///
+/// # Errors
+///
+/// Returns error if the symlink blob cannot be read
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::SymlinkTarget;
+/// use gitkyl::pages::blob::generate_symlink;
+/// use std::path::Path;
+///
+/// let target = SymlinkTarget::File("README.md".to_string());
+/// let html = generate_symlink(
+///     Path::new("."),
+///     "main",
+///     Path::new("docs/README.md"),
+///     "my-repo",
+///     Some(&target)
+/// )?;
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn generate_symlink(
+    repo_path: impl AsRef<Path>,
+    ref_name: &str,
+    file_path: impl AsRef<Path>,
+    repo_name: &str,
+    resolved: Option<&SymlinkTarget>,
+) -> Result<Markup> {
+    let path_str = file_path.as_ref().display().to_string();
+
+    let target_bytes = read_blob(&repo_path, Some(ref_name), &file_path)
+        .with_context(|| format!("Failed to read blob from repository: {}", path_str))?;
+    let target = String::from_utf8_lossy(&target_bytes);
+
+    let path_components = extract_breadcrumb_components(&path_str);
+
+    Ok(symlink_blob_page_markup(
+        &path_str,
+        &path_components,
+        ref_name,
+        repo_name,
+        &target,
+        resolved,
+    ))
+}
+
 /// Generates text blob with syntax highlighting.
 ///
 /// Converts blob bytes to UTF-8 string and applies syntect syntax highlighting
-/// based on file extension. Returns formatted HTML with line numbers.
+/// based on file extension. Returns formatted HTML with line numbers, linking
+/// to the blame page when `has_blame` is set.
 ///
 /// # Errors
 ///
@@ -213,6 +310,7 @@ fn generate_text_blob(
     ref_name: &str,
     repo_name: &str,
     theme: &str,
+    has_blame: bool,
 ) -> Result<Markup> {
     let raw_size = bytes.len();
     let content = String::from_utf8(bytes.to_vec())
@@ -238,13 +336,14 @@ fn generate_text_blob(
         repo_name,
         &highlighted_lines,
         &metadata,
+        has_blame,
     ))
 }
 
 /// Renders image blob page with embedded image display
 ///
-/// Creates HTML page displaying the image with metadata. Image data is
-/// embedded as base64 data URL for self-contained static HTML.
+/// Creates HTML page displaying the image with metadata. Small images are
+/// embedded as base64 data URLs; larger ones load from their raw copy.
 fn generate_image_blob(
     bytes: &[u8],
     format: ImageFormat,
@@ -287,6 +386,59 @@ fn generate_binary_blob(
     ))
 }
 
+/// Renders LFS pointer page when the object is not available locally
+///
+/// Creates HTML page showing the size and OID of the object the pointer
+/// refers to, instead of the raw pointer text.
+fn generate_lfs_blob(
+    pointer: &LfsPointer,
+    file_path: &Path,
+    ref_name: &str,
+    repo_name: &str,
+) -> Result<Markup> {
+    let path_str = file_path.display().to_string();
+    let path_components = extract_breadcrumb_components(&path_str);
+
+    Ok(lfs_blob_page_markup(
+        &path_str,
+        &path_components,
+        ref_name,
+        repo_name,
+        pointer,
+    ))
+}
+
+/// Returns the relative link to a file's raw copy under `raw/<ref>/<path>`
+fn raw_link(depth: usize, ref_name: &str, file_path: &str) -> String {
+    format!("{}raw/{}/{}", "../".repeat(depth), ref_name, file_path)
+}
+
+/// Renders Raw and Download header buttons for a file
+fn raw_actions(raw_link: &str, file_name: &str) -> Markup {
+    html! {
+        a class="action-btn" href=(raw_link) title="Raw" {
+            i class="ph ph-file-text" {}
+        }
+        a class="action-btn" href=(raw_link) download=(file_name) title="Download" {
+            i class="ph ph-download-simple" {}
+        }
+    }
+}
+
+/// Renders Raw and Download links for pages without a code header
+fn raw_links(raw_link: &str, file_name: &str) -> Markup {
+    html! {
+        a class="history-link" href=(raw_link) {
+            i class="ph ph-file-text" {}
+            " Raw"
+        }
+        a class="history-link" href=(raw_link) download=(file_name) {
+            i class="ph ph-download-simple" {}
+            " Download"
+        }
+    }
+}
+
 /// Renders blob page HTML structure
 fn blob_page_markup(
     file_path: &str,
@@ -295,6 +447,7 @@ fn blob_page_markup(
     repo_name: &str,
     highlighted_lines: &[String],
     metadata: &FileMetadata,
+    has_blame: bool,
 ) -> Markup {
     let depth = calculate_depth(ref_name, file_path);
     let index_path = "../".repeat(depth) + "index.html";
@@ -320,6 +473,18 @@ fn blob_page_markup(
         .collect();
 
     let title = format!("{}/{}: {}", repo_name, ref_name, file_path);
+    let history_link = format!(
+        "{}history/{}/{}/page-1.html",
+        "../".repeat(depth),
+        ref_name,
+        file_path
+    );
+    let blame_link = format!(
+        "{}blame/{}/{}.html",
+        "../".repeat(depth),
+        ref_name,
+        file_path
+    );
 
     let file_name = Path::new(file_path)
         .file_name()
@@ -339,6 +504,15 @@ fn blob_page_markup(
                         span class="blob-meta" { (metadata.display()) }
                     }
                     div class="blob-actions" {
+                        a class="action-btn" href=(history_link) title="History" {
+                            i class="ph ph-clock-counter-clockwise" {}
+                        }
+                        @if has_blame {
+                            a class="action-btn" href=(blame_link) title="Blame" {
+                                i class="ph ph-user-list" {}
+                            }
+                        }
+                        (raw_actions(&raw_link(depth, ref_name, file_path), file_name))
                         button class="action-btn copy-btn" type="button" title="Copy file contents" {
                             i class="ph ph-copy" {}
                         }
@@ -419,6 +593,18 @@ fn markdown_blob_page_markup(
         .collect();
 
     let title = format!("{}/{}: {}", repo_name, ref_name, file_path);
+    let history_link = format!(
+        "{}history/{}/{}/page-1.html",
+        "../".repeat(depth),
+        ref_name,
+        file_path
+    );
+    let blame_link = format!(
+        "{}blame/{}/{}.html",
+        "../".repeat(depth),
+        ref_name,
+        file_path
+    );
 
     let file_name = Path::new(file_path)
         .file_name()
@@ -437,14 +623,24 @@ fn markdown_blob_page_markup(
                         i class="ph ph-file-md" {}
                         span class="blob-filename" { (file_name) }
                     }
-                    div class="view-toggle" {
-                        span class="view-tab active" {
-                            i class="ph ph-eye" {}
-                            " Preview"
+                    div class="blob-actions" {
+                        a class="action-btn" href=(history_link) title="History" {
+                            i class="ph ph-clock-counter-clockwise" {}
                         }
-                        a href=(source_link) class="view-tab" {
-                            i class="ph ph-code" {}
-                            " Code"
+                        (raw_actions(&raw_link(depth, ref_name, file_path), file_name))
+                        div class="view-toggle" {
+                            span class="view-tab active" {
+                                i class="ph ph-eye" {}
+                                " Preview"
+                            }
+                            a href=(source_link) class="view-tab" {
+                                i class="ph ph-code" {}
+                                " Code"
+                            }
+                            a href=(blame_link) class="view-tab" {
+                                i class="ph ph-user-list" {}
+                                " Blame"
+                            }
                         }
                     }
                 }
@@ -546,6 +742,18 @@ fn markdown_source_page_markup(
         .collect();
 
     let title = format!("{}/{}: {} (source)", repo_name, ref_name, file_path);
+    let history_link = format!(
+        "{}history/{}/{}/page-1.html",
+        "../".repeat(depth),
+        ref_name,
+        file_path
+    );
+    let blame_link = format!(
+        "{}blame/{}/{}.html",
+        "../".repeat(depth),
+        ref_name,
+        file_path
+    );
 
     // Rendered file link: README.md.html (we're at README.md.source.html)
     let file_name = Path::new(file_path)
@@ -566,14 +774,24 @@ fn markdown_source_page_markup(
                         span class="blob-filename" { (file_name) }
                         span class="blob-lines" { (line_count) " lines" }
                     }
-                    div class="view-toggle" {
-                        a href=(rendered_link) class="view-tab" {
-                            i class="ph ph-eye" {}
-                            " Preview"
+                    div class="blob-actions" {
+                        a class="action-btn" href=(history_link) title="History" {
+                            i class="ph ph-clock-counter-clockwise" {}
                         }
-                        span class="view-tab active" {
-                            i class="ph ph-code" {}
-                            " Code"
+                        (raw_actions(&raw_link(depth, ref_name, file_path), file_name))
+                        div class="view-toggle" {
+                            a href=(rendered_link) class="view-tab" {
+                                i class="ph ph-eye" {}
+                                " Preview"
+                            }
+                            span class="view-tab active" {
+                                i class="ph ph-code" {}
+                                " Code"
+                            }
+                            a href=(blame_link) class="view-tab" {
+                                i class="ph ph-user-list" {}
+                                " Blame"
+                            }
                         }
                     }
                 }
@@ -599,7 +817,7 @@ fn markdown_source_page_markup(
     )
 }
 
-/// Renders image blob page with embedded data URL
+/// Renders image blob page, inlining small images as data URLs
 fn image_blob_page_markup(
     file_path: &str,
     breadcrumb_components: &[&str],
@@ -631,13 +849,28 @@ fn image_blob_page_markup(
         })
         .collect();
 
-    let data_url = format!(
-        "data:{};base64,{}",
-        format.mime_type(),
-        STANDARD.encode(image_bytes)
-    );
+    let raw = raw_link(depth, ref_name, file_path);
+    let image_src = if image_bytes.len() <= INLINE_IMAGE_LIMIT {
+        format!(
+            "data:{};base64,{}",
+            format.mime_type(),
+            STANDARD.encode(image_bytes)
+        )
+    } else {
+        raw.clone()
+    };
+    let file_name = Path::new(file_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(file_path);
     let file_size = format_file_size(image_bytes.len());
     let title = format!("{}/{}: {}", repo_name, ref_name, file_path);
+    let history_link = format!(
+        "{}history/{}/{}/page-1.html",
+        "../".repeat(depth),
+        ref_name,
+        file_path
+    );
 
     page_wrapper(
         &title,
@@ -651,9 +884,16 @@ fn image_blob_page_markup(
                         " · "
                         (file_size)
                     }
+                    div class="blob-links" {
+                        a class="history-link" href=(history_link) {
+                            i class="ph ph-clock-counter-clockwise" {}
+                            " History"
+                        }
+                        (raw_links(&raw, file_name))
+                    }
                 }
                 div class="image-display" {
-                    img src=(data_url) alt=(file_path) loading="lazy" {}
+                    img src=(image_src) alt=(file_path) loading="lazy" {}
                 }
             }
         },
@@ -692,7 +932,17 @@ fn binary_blob_page_markup(
         .collect();
 
     let file_size = format_file_size(file_size_bytes);
+    let file_name = Path::new(file_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(file_path);
     let title = format!("{}/{}: {}", repo_name, ref_name, file_path);
+    let history_link = format!(
+        "{}history/{}/{}/page-1.html",
+        "../".repeat(depth),
+        ref_name,
+        file_path
+    );
 
     page_wrapper(
         &title,
@@ -712,6 +962,142 @@ fn binary_blob_page_markup(
                         strong { "Size: " }
                         (file_size)
                     }
+                    div class="blob-links" {
+                        a class="history-link" href=(history_link) {
+                            i class="ph ph-clock-counter-clockwise" {}
+                            " History"
+                        }
+                        (raw_links(&raw_link(depth, ref_name, file_path), file_name))
+                    }
+                }
+            }
+        },
+    )
+}
+
+/// Renders LFS pointer page with object information
+fn lfs_blob_page_markup(
+    file_path: &str,
+    breadcrumb_components: &[&str],
+    ref_name: &str,
+    repo_name: &str,
+    pointer: &LfsPointer,
+) -> Markup {
+    let depth = calculate_depth(ref_name, file_path);
+    let root = "../".repeat(depth);
+    let index_path = format!("{}index.html", root);
+    let css_path = format!("{}assets/blob.css", root);
+
+    let breadcrumb_data: Vec<(&str, Option<String>)> = breadcrumb_components
+        .iter()
+        .enumerate()
+        .map(|(idx, &component)| {
+            if idx == breadcrumb_components.len() - 1 {
+                (component, None)
+            } else {
+                let partial_path = breadcrumb_components[..=idx].join("/");
+                let link = format!("{}tree/{}/{}.html", root, ref_name, partial_path);
+                (component, Some(link))
+            }
+        })
+        .collect();
+
+    let file_size = format_file_size(usize::try_from(pointer.size()).unwrap_or(usize::MAX));
+    let title = format!("{}/{}: {}", repo_name, ref_name, file_path);
+    let history_link = format!("{}history/{}/{}/page-1.html", root, ref_name, file_path);
+
+    page_wrapper(
+        &title,
+        &[&css_path],
+        html! {
+            (breadcrumb(repo_name, &index_path, &breadcrumb_data, ref_name))
+            main class="blob-container binary-blob lfs-blob" {
+                div class="binary-message" {
+                    div class="binary-icon" {
+                        i class="ph ph-cloud-arrow-down" {}
+                    }
+                    h2 { "Stored with Git LFS" }
+                    p class="binary-info" {
+                        "The object for this file is not available in the local LFS store."
+                    }
+                    p class="file-details" {
+                        strong { "Size: " }
+                        (file_size)
+                    }
+                    p class="file-details lfs-oid" {
+                        strong { "OID: " }
+                        code { "sha256:" (pointer.oid()) }
+                    }
+                    a class="history-link" href=(history_link) {
+                        i class="ph ph-clock-counter-clockwise" {}
+                        " History"
+                    }
+                }
+            }
+        },
+    )
+}
+
+/// Renders symlink page HTML structure
+fn symlink_blob_page_markup(
+    file_path: &str,
+    breadcrumb_components: &[&str],
+    ref_name: &str,
+    repo_name: &str,
+    target: &str,
+    resolved: Option<&SymlinkTarget>,
+) -> Markup {
+    let depth = calculate_depth(ref_name, file_path);
+    let root = "../".repeat(depth);
+    let index_path = format!("{}index.html", root);
+    let css_path = format!("{}assets/blob.css", root);
+
+    let breadcrumb_data: Vec<(&str, Option<String>)> = breadcrumb_components
+        .iter()
+        .enumerate()
+        .map(|(idx, &component)| {
+            if idx == breadcrumb_components.len() - 1 {
+                (component, None)
+            } else {
+                let partial_path = breadcrumb_components[..=idx].join("/");
+                let link = format!("{}tree/{}/{}.html", root, ref_name, partial_path);
+                (component, Some(link))
+            }
+        })
+        .collect();
+
+    let title = format!("{}/{}: {}", repo_name, ref_name, file_path);
+    let history_link = format!("{}history/{}/{}/page-1.html", root, ref_name, file_path);
+    let target_href = resolved.map(|_| symlink_href(&root, ref_name, file_path, resolved));
+
+    page_wrapper(
+        &title,
+        &[&css_path],
+        html! {
+            (breadcrumb(repo_name, &index_path, &breadcrumb_data, ref_name))
+            main class="blob-container binary-blob symlink-blob" {
+                div class="binary-message" {
+                    div class="binary-icon" {
+                        i class="ph ph-link" {}
+                    }
+                    h2 { "Symbolic link" }
+                    p class="symlink-target" {
+                        "→ "
+                        @if let Some(href) = target_href {
+                            a href=(href) { code { (target) } }
+                        } @else {
+                            code { (target) }
+                        }
+                    }
+                    @if resolved.is_none() {
+                        p class="binary-info" {
+                            "The link target is outside this repository."
+                        }
+                    }
+                    a class="history-link" href=(history_link) {
+                        i class="ph ph-clock-counter-clockwise" {}
+                        " History"
+                    }
                 }
             }
         },
@@ -772,6 +1158,10 @@ mod tests {
         let html_str = html.into_string();
         assert!(html_str.contains("test-repo"));
         assert!(html_str.contains("test.rs"));
+        assert!(html_str.contains("../../blame/HEAD/test.rs.html"));
+        assert!(html_str.contains("../../history/HEAD/test.rs/page-1.html"));
+        assert!(html_str.contains(r#"href="../../raw/HEAD/test.rs""#));
+        assert!(html_str.contains(r#"download="test.rs""#));
     }
 
     #[test]
@@ -813,6 +1203,33 @@ mod tests {
         assert!(html_str.contains("PNG"));
     }
 
+    #[test]
+    fn test_generate_large_image_uses_raw_file() {
+        // Arrange: a PNG just over the inline limit
+        let repo = create_test_repo().unwrap();
+        let mut png = vec![0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A];
+        png.resize(INLINE_IMAGE_LIMIT + 1, 0);
+        fs::create_dir_all(repo.path().join("img")).unwrap();
+        fs::write(repo.path().join("img/big.png"), png).unwrap();
+        git_commit(repo.path()).unwrap();
+
+        // Act
+        let html = generate(
+            repo.path(),
+            "HEAD",
+            Path::new("img/big.png"),
+            "test-repo",
+            "base16-ocean.dark",
+        )
+        .unwrap();
+
+        // Assert: image served from the raw copy instead of a data URI
+        let html_str = html.into_string();
+        assert!(html_str.contains(r#"src="../../../raw/HEAD/img/big.png""#));
+        assert!(!html_str.contains("data:image/png;base64,"));
+        assert!(html_str.contains(" Download"));
+    }
+
     #[test]
     fn test_generate_binary_file() {
         let repo = create_test_repo().unwrap();
@@ -837,4 +1254,112 @@ mod tests {
         assert!(html_str.contains("binary data"));
         assert!(html_str.contains("7 bytes"));
     }
+
+    const LFS_OID: &str = "4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393";
+
+    fn write_lfs_pointer(repo: &Path, path: &str, oid: &str, size: usize) {
+        let pointer = format!(
+            "version https://git-lfs.github.com/spec/v1\noid sha256:{}\nsize {}\n",
+            oid, size
+        );
+        fs::write(repo.join(path), pointer).unwrap();
+    }
+
+    #[test]
+    fn test_generate_lfs_pointer_missing_object() {
+        let repo = create_test_repo().unwrap();
+        write_lfs_pointer(repo.path(), "logo.png", LFS_OID, 2048);
+        git_commit(repo.path()).unwrap();
+
+        let html = generate(
+            repo.path(),
+            "HEAD",
+            Path::new("logo.png"),
+            "test-repo",
+            "base16-ocean.dark",
+        )
+        .unwrap();
+
+        let html_str = html.into_string();
+        assert!(html_str.contains("lfs-blob"));
+        assert!(html_str.contains("Stored with Git LFS"));
+        assert!(html_str.contains(LFS_OID));
+        assert!(html_str.contains("2.00 KB"));
+        assert!(!html_str.contains("git-lfs.github.com/spec"));
+    }
+
+    #[test]
+    fn test_generate_lfs_pointer_local_object() {
+        let repo = create_test_repo().unwrap();
+        let content = "id,name\n1,widget\n";
+        write_lfs_pointer(repo.path(), "data.csv", LFS_OID, content.len());
+        git_commit(repo.path()).unwrap();
+
+        let store = repo
+            .path()
+            .join(".git/lfs/objects")
+            .join(&LFS_OID[0..2])
+            .join(&LFS_OID[2..4]);
+        fs::create_dir_all(&store).unwrap();
+        fs::write(store.join(LFS_OID), content).unwrap();
+
+        let html = generate(
+            repo.path(),
+            "HEAD",
+            Path::new("data.csv"),
+            "test-repo",
+            "base16-ocean.dark",
+        )
+        .unwrap();
+
+        let html_str = html.into_string();
+        assert!(html_str.contains("widget"));
+        assert!(!html_str.contains("lfs-blob"));
+        assert!(
+            !html_str.contains("blame/"),
+            "LFS content has no line history to blame"
+        );
+        assert!(!html_str.contains("git-lfs.github.com/spec"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_generate_symlink() {
+        let repo = create_test_repo().unwrap();
+        fs::create_dir(repo.path().join("docs")).unwrap();
+        fs::write(repo.path().join("README.md"), "# Title").unwrap();
+        std::os::unix::fs::symlink("../README.md", repo.path().join("docs/README.md")).unwrap();
+        git_commit(repo.path()).unwrap();
+        let target = SymlinkTarget::File("README.md".to_string());
+
+        let html = generate_symlink(
+            repo.path(),
+            "HEAD",
+            Path::new("docs/README.md"),
+            "test-repo",
+            Some(&target),
+        )
+        .unwrap();
+
+        let html_str = html.into_string();
+        assert!(html_str.contains("Symbolic link"));
+        assert!(html_str.contains("../README.md"));
+        assert!(html_str.contains("href=\"../../../blob/HEAD/README.md.html\""));
+        assert!(!html_str.contains("outside this repository"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_generate_symlink_outside_repository() {
+        let repo = create_test_repo().unwrap();
+        std::os::unix::fs::symlink("/etc/hosts", repo.path().join("hosts")).unwrap();
+        git_commit(repo.path()).unwrap();
+
+        let html =
+            generate_symlink(repo.path(), "HEAD", Path::new("hosts"), "test-repo", None).unwrap();
+
+        let html_str = html.into_string();
+        assert!(html_str.contains("/etc/hosts"));
+        assert!(html_str.contains("outside this repository"));
+    }
 }
@@ -0,0 +1,502 @@
+//! Commit detail page generation with highlighted diffs
+
+use anyhow::{Context, Result};
+use maud::{Markup, PreEscaped, html};
+use std::path::Path;
+
+use crate::components::commit::{
+    attribution, author_avatar, change_icon, commit_notes, signature_badge, trailer_chips,
+};
+use crate::components::layout::page_wrapper;
+use crate::components::nav::breadcrumb;
+use crate::diff::{CONTEXT_LINES, DiffLine, DiffStat, Hunk, diff_lines, hunks};
+use crate::filetype::{FileType, detect_file_type};
+use crate::git::{CommitDetail, FileChange};
+use crate::highlight::Highlighter;
+use crate::markdown::MarkdownRenderer;
+use crate::util::format_timestamp;
+
+/// Maximum combined line count of a file diff rendered inline.
+///
+/// Larger diffs (lockfiles, generated code) are summarized instead to keep
+/// commit pages responsive.
+const MAX_DIFF_LINES: usize = 10_000;
+
+/// Rendered state of a single file diff.
+enum FileDiffBody {
+    /// Text diff with hunks and highlighted lines for both versions
+    Text {
+        hunks: Vec<Hunk>,
+        old_lines: Vec<String>,
+        new_lines: Vec<String>,
+    },
+    /// Binary content, not diffed
+    Binary,
+    /// Text diff exceeding the inline size limit
+    TooLarge,
+}
+
+/// File change prepared for rendering.
+struct FileDiffView<'a> {
+    change: &'a FileChange,
+    stat: DiffStat,
+    body: FileDiffBody,
+}
+
+/// Generates commit detail page with message, metadata and diff
+///
+/// Shows the full commit message, author and committer, parent links,
+/// a summary of changed files and a syntax highlighted unified diff for
+/// each text file. Page is written to `commit/<oid>.html`.
+///
+/// # Arguments
+///
+/// * `repo_name`: Repository name for navigation
+/// * `detail`: Commit metadata and file changes
+/// * `theme`: Syntax highlighting theme name
+///
+/// # Returns
+///
+/// Rendered HTML markup
+///
+/// # Errors
+///
+/// Returns error if the highlighter cannot be created or highlighting fails
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::pages::commit::generate;
+/// use gitkyl::get_commit_detail;
+/// use std::path::Path;
+///
+/// let detail = get_commit_detail(Path::new("."), "0123456789abcdef0123456789abcdef01234567")?;
+/// let html = generate("my-repo", &detail, "Catppuccin-Latte")?;
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn generate(repo_name: &str, detail: &CommitDetail, theme: &str) -> Result<Markup> {
+    let highlighter = Highlighter::with_theme(theme)
+        .or_else(|_| Highlighter::new())
+        .context("Failed to create highlighter")?;
+
+    let files = detail
+        .changes
+        .iter()
+        .map(|change| prepare_file_diff(change, &highlighter))
+        .collect::<Result<Vec<_>>>()?;
+
+    let total = files.iter().fold(DiffStat::default(), |acc, f| DiffStat {
+        added: acc.added + f.stat.added,
+        removed: acc.removed + f.stat.removed,
+    });
+
+    let commit = &detail.commit;
+    let css_path = "../assets/commit.css";
+    let markdown_css_path = "../assets/markdown.css";
+    let stylesheets: &[&str] = if commit.notes().is_empty() {
+        &[css_path]
+    } else {
+        &[css_path, markdown_css_path]
+    };
+    let index_path = "../index.html";
+    let title = format!("{}: {}", repo_name, commit.message());
+
+    Ok(page_wrapper(
+        &title,
+        stylesheets,
+        html! {
+            (breadcrumb(
+                repo_name,
+                index_path,
+                &[("Commit", None), (commit.short_oid(), None)],
+                commit.short_oid()
+            ))
+            main {
+                div class="commit-card" {
+                    h1 class="commit-title" { (commit.message()) }
+                    @if !commit.body().is_empty() {
+                        pre class="commit-body" { (commit.body()) }
+                    }
+                    (trailer_chips(commit.trailers()))
+                    div class="commit-people" {
+                        (author_avatar(commit.author(), 24, "../"))
+                        (attribution(commit, "../"))
+                        span class="commit-people-label" { "authored " (format_timestamp(commit.date())) }
+                        @if commit.committer() != commit.author() || detail.committer_date != commit.date() {
+                            span class="commit-people-sep" { "·" }
+                            span class="commit-committer" { (commit.committer()) }
+                            span class="commit-people-label" {
+                                "committed " (format_timestamp(detail.committer_date))
+                            }
+                        }
+                    }
+                    div class="commit-refs" {
+                        div class="commit-ref-row" {
+                            span class="commit-ref-label" { "commit" }
+                            code class="commit-ref-value" { (commit.oid()) }
+                        }
+                        @if let Some(signature) = commit.signature() {
+                            div class="commit-ref-row" {
+                                span class="commit-ref-label" { "signature" }
+                                (signature_badge(signature))
+                                @if let Some(fingerprint) = signature.fingerprint() {
+                                    code class="commit-ref-value" { (fingerprint) }
+                                }
+                            }
+                        }
+                        @if !detail.parents.is_empty() {
+                            div class="commit-ref-row" {
+                                span class="commit-ref-label" {
+                                    @if detail.parents.len() > 1 { "parents" } @else { "parent" }
+                                }
+                                @for parent in &detail.parents {
+                                    a class="commit-ref-value" href=(format!("{}.html", parent)) {
+                                        code { (&parent[..parent.len().min(7)]) }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    @if !commit.notes().is_empty() {
+                        div class="commit-notes" {
+                            (commit_notes(commit.notes(), &MarkdownRenderer::new(), true))
+                        }
+                    }
+                }
+
+                section class="diff-summary" {
+                    div class="diff-summary-header" {
+                        i class="ph ph-files" {}
+                        span {
+                            (files.len()) @if files.len() == 1 { " file changed" } @else { " files changed" }
+                        }
+                        span class="stat-added" { "+" (total.added) }
+                        span class="stat-removed" { "−" (total.removed) }
+                        @if detail.parents.len() > 1 {
+                            span class="diff-summary-note" { "Compared with first parent" }
+                        }
+                    }
+                    @if files.is_empty() {
+                        p class="empty-state" { "No file changes" }
+                    } @else {
+                        ul class="diff-file-list" {
+                            @for (idx, file) in files.iter().enumerate() {
+                                li {
+                                    a href=(format!("#diff-{}", idx)) {
+                                        (change_icon(file.change.kind))
+                                        span class="diff-file-path" { (file.change.path) }
+                                    }
+                                    span class="diff-file-stat" {
+                                        span class="stat-added" { "+" (file.stat.added) }
+                                        span class="stat-removed" { "−" (file.stat.removed) }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                @for (idx, file) in files.iter().enumerate() {
+                    (file_diff(idx, file))
+                }
+            }
+        },
+    ))
+}
+
+/// Computes the edit script and highlighted lines for a file change.
+fn prepare_file_diff<'a>(
+    change: &'a FileChange,
+    highlighter: &Highlighter,
+) -> Result<FileDiffView<'a>> {
+    let path = Path::new(&change.path);
+    let old = change.old_content.as_deref().map(|b| text_content(b, path));
+    let new = change.new_content.as_deref().map(|b| text_content(b, path));
+
+    let (old_text, new_text) = match (old, new) {
+        (Some(None), _) | (_, Some(None)) => {
+            return Ok(FileDiffView {
+                change,
+                stat: DiffStat::default(),
+                body: FileDiffBody::Binary,
+            });
+        }
+        (old, new) => (old.flatten().unwrap_or(""), new.flatten().unwrap_or("")),
+    };
+
+    let old_lines: Vec<&str> = old_text.lines().collect();
+    let new_lines: Vec<&str> = new_text.lines().collect();
+    let edits = diff_lines(&old_lines, &new_lines);
+    let stat = DiffStat::from_edits(&edits);
+
+    if old_lines.len() + new_lines.len() > MAX_DIFF_LINES {
+        return Ok(FileDiffView {
+            change,
+            stat,
+            body: FileDiffBody::TooLarge,
+        });
+    }
+
+    let highlight = |text: &str| {
+        highlighter
+            .highlight(text, path)
+            .with_context(|| format!("Failed to highlight: {}", change.path))
+    };
+
+    Ok(FileDiffView {
+        change,
+        stat,
+        body: FileDiffBody::Text {
+            hunks: hunks(&edits, CONTEXT_LINES),
+            old_lines: highlight(old_text)?,
+            new_lines: highlight(new_text)?,
+        },
+    })
+}
+
+/// Returns file content as text, or None when it should be treated as binary.
+///
+/// LFS pointers diff as text so the OID and size change stays visible.
+fn text_content<'b>(bytes: &'b [u8], path: &Path) -> Option<&'b str> {
+    match detect_file_type(bytes, path) {
+        FileType::Text | FileType::LfsPointer => std::str::from_utf8(bytes).ok(),
+        _ => None,
+    }
+}
+
+/// Renders one file section with its unified diff.
+fn file_diff(idx: usize, file: &FileDiffView) -> Markup {
+    html! {
+        div class="diff-file" id=(format!("diff-{}", idx)) {
+            div class="diff-file-header" {
+                (change_icon(file.change.kind))
+                span class="diff-file-path" { (file.change.path) }
+                span class="diff-file-stat" {
+                    span class="stat-added" { "+" (file.stat.added) }
+                    span class="stat-removed" { "−" (file.stat.removed) }
+                }
+            }
+            @match &file.body {
+                FileDiffBody::Binary => {
+                    p class="diff-placeholder" { "Binary file not shown" }
+                }
+                FileDiffBody::TooLarge => {
+                    p class="diff-placeholder" { "Large diff not rendered" }
+                }
+                FileDiffBody::Text { hunks, old_lines, new_lines } => {
+                    @if hunks.is_empty() {
+                        p class="diff-placeholder" { "Empty file" }
+                    } @else {
+                        div class="diff-code-wrapper" {
+                            table class="diff-code" {
+                                tbody {
+                                    @for hunk in hunks {
+                                        tr class="diff-hunk" {
+                                            td class="line-number" colspan="2" {}
+                                            td class="line-content" { (hunk.header()) }
+                                        }
+                                        @for line in &hunk.lines {
+                                            (diff_row(line, old_lines, new_lines))
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Renders a single diff line with both gutters.
+fn diff_row(line: &DiffLine, old_lines: &[String], new_lines: &[String]) -> Markup {
+    let (class, old_num, new_num, content) = match *line {
+        DiffLine::Context { old, new } => {
+            ("diff-context", Some(old), Some(new), &new_lines[new - 1])
+        }
+        DiffLine::Added { new } => ("diff-added", None, Some(new), &new_lines[new - 1]),
+        DiffLine::Removed { old } => ("diff-removed", Some(old), None, &old_lines[old - 1]),
+    };
+
+    html! {
+        tr class=(class) {
+            td class="line-number" { @if let Some(n) = old_num { (n) } }
+            td class="line-number" { @if let Some(n) = new_num { (n) } }
+            td class="line-content" { (PreEscaped(content)) }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::{ChangeKind, CommitInfo};
+
+    fn detail_with(changes: Vec<FileChange>, parents: Vec<String>) -> CommitDetail {
+        CommitDetail {
+            commit: CommitInfo::new(
+                "0123456789abcdef0123456789abcdef01234567".to_string(),
+                "Update parser".to_string(),
+                "Update parser\n\nHandle trailing commas.".to_string(),
+                "Alice".to_string(),
+                1704067200,
+            ),
+            committer_email: "alice@example.com".to_string(),
+            committer_date: 1704067200,
+            parents,
+            changes,
+        }
+    }
+
+    #[test]
+    fn test_generate_renders_message_and_metadata() {
+        // Arrange
+        let detail = detail_with(
+            vec![],
+            vec!["fedcba9876543210fedcba9876543210fedcba98".into()],
+        );
+
+        // Act
+        let html = generate("test-repo", &detail, "base16-ocean.light")
+            .expect("Should render commit page")
+            .into_string();
+
+        // Assert
+        assert!(html.contains("Update parser"), "Should show subject");
+        assert!(html.contains("Handle trailing commas."), "Should show body");
+        assert!(html.contains("Alice"), "Should show author");
+        assert!(
+            html.contains("fedcba9876543210fedcba9876543210fedcba98.html"),
+            "Should link parent commit page"
+        );
+        assert!(html.contains("No file changes"), "Should note empty diff");
+    }
+
+    #[test]
+    fn test_generate_renders_trailer_chips() {
+        // Arrange
+        let mut detail = detail_with(vec![], vec![]);
+        detail.commit = CommitInfo::new(
+            "0123456789abcdef0123456789abcdef01234567".to_string(),
+            "Update parser".to_string(),
+            "Update parser\n\nSigned-off-by: Alice <alice@example.com>\nCo-authored-by: Bob <bob@example.com>"
+                .to_string(),
+            "Alice".to_string(),
+            1704067200,
+        );
+
+        // Act
+        let html = generate("test-repo", &detail, "base16-ocean.light")
+            .expect("Should render commit page")
+            .into_string();
+
+        // Assert
+        assert!(html.contains(r#"<span class="trailer-key">Signed-off-by</span>"#));
+        assert!(
+            !html.contains(r#"<span class="trailer-key">Co-authored-by</span>"#),
+            "Co-authors are shown in the attribution"
+        );
+        assert!(
+            !html.contains("commit-body"),
+            "Trailer only body should be empty"
+        );
+    }
+
+    #[test]
+    fn test_generate_renders_expanded_notes() {
+        // Arrange
+        let mut detail = detail_with(vec![], vec![]);
+        detail.commit.set_notes(vec![
+            crate::notes::Note::new("commits", "Reviewed-by: Bob"),
+            crate::notes::Note::new("ci", "- [x] tests"),
+        ]);
+
+        // Act
+        let html = generate("test-repo", &detail, "base16-ocean.light")
+            .expect("Should render commit page")
+            .into_string();
+
+        // Assert
+        assert_eq!(
+            html.matches(r#"<details class="commit-note" open>"#)
+                .count(),
+            2,
+            "Each note should render expanded"
+        );
+        assert!(html.contains("Reviewed-by: Bob"));
+        assert!(
+            html.contains(r#"type="checkbox""#),
+            "Note text should render as markdown"
+        );
+        assert!(html.contains("../assets/markdown.css"));
+    }
+
+    #[test]
+    fn test_generate_renders_modified_file_diff() {
+        // Arrange
+        let change = FileChange {
+            path: "src/lib.rs".to_string(),
+            kind: ChangeKind::Modified,
+            old_content: Some(b"fn a() {}\nfn b() {}\n".to_vec()),
+            new_content: Some(b"fn a() {}\nfn c() {}\n".to_vec()),
+        };
+        let detail = detail_with(vec![change], vec![]);
+
+        // Act
+        let html = generate("test-repo", &detail, "base16-ocean.light")
+            .expect("Should render commit page")
+            .into_string();
+
+        // Assert
+        assert!(html.contains("src/lib.rs"), "Should list changed file");
+        assert!(
+            html.contains("@@ -1,2 +1,2 @@"),
+            "Should render hunk header"
+        );
+        assert!(html.contains("diff-added"), "Should mark added line");
+        assert!(html.contains("diff-removed"), "Should mark removed line");
+        assert!(html.contains("1 file changed"), "Should summarize changes");
+    }
+
+    #[test]
+    fn test_generate_binary_file_placeholder() {
+        // Arrange
+        let change = FileChange {
+            path: "data.bin".to_string(),
+            kind: ChangeKind::Added,
+            old_content: None,
+            new_content: Some(vec![0, 159, 146, 150, 0, 1]),
+        };
+        let detail = detail_with(vec![change], vec![]);
+
+        // Act
+        let html = generate("test-repo", &detail, "base16-ocean.light")
+            .expect("Should render commit page")
+            .into_string();
+
+        // Assert
+        assert!(html.contains("Binary file not shown"));
+    }
+
+    #[test]
+    fn test_generate_merge_commit_notes_first_parent() {
+        // Arrange
+        let detail = detail_with(
+            vec![],
+            vec![
+                "1111111111111111111111111111111111111111".into(),
+                "2222222222222222222222222222222222222222".into(),
+            ],
+        );
+
+        // Act
+        let html = generate("test-repo", &detail, "base16-ocean.light")
+            .expect("Should render commit page")
+            .into_string();
+
+        // Assert
+        assert!(html.contains("parents"), "Should label multiple parents");
+        assert!(html.contains("Compared with first parent"));
+    }
+}
@@ -2,11 +2,17 @@
 
 use maud::{Markup, html};
 
-use crate::components::commit::{attribution, commit_hash};
+use crate::components::commit::{
+    attribution, author_avatar, commit_hash, commit_notes, signature_badge, trailer_chips,
+};
+use crate::components::graph::{graph_cell, graph_width, ref_badges};
 use crate::components::layout::page_wrapper;
 use crate::components::nav::breadcrumb;
-use crate::git::PaginatedCommits;
-use crate::util::{calculate_depth, format_timestamp};
+use crate::git::{CommitInfo, PaginatedCommits};
+use crate::graph::GraphRow;
+use crate::history_limit::Truncation;
+use crate::markdown::MarkdownRenderer;
+use crate::util::{calculate_depth, format_date, format_timestamp};
 
 /// Generates HTML page displaying commit log for a reference
 ///
@@ -39,8 +45,15 @@ pub fn generate(
     total_commits: usize,
 ) -> Markup {
     let depth = calculate_depth(ref_name, "");
-    let css_path = format!("{}assets/commits.css", "../".repeat(depth));
-    let index_path = format!("{}index.html", "../".repeat(depth));
+    let root = "../".repeat(depth);
+    let css_path = format!("{}assets/commits.css", root);
+    let markdown_css_path = format!("{}assets/markdown.css", root);
+    let stylesheets: &[&str] = if has_notes(&paginated.commits) {
+        &[&css_path, &markdown_css_path]
+    } else {
+        &[&css_path]
+    };
+    let index_path = format!("{}index.html", root);
 
     let title = format!("{}/{}: commits", repo_name, ref_name);
 
@@ -49,7 +62,7 @@ pub fn generate(
 
     page_wrapper(
         &title,
-        &[&css_path],
+        stylesheets,
         html! {
             (breadcrumb(repo_name, &index_path, &[("Commits", None)], ref_name))
             main {
@@ -60,24 +73,9 @@ pub fn generate(
                         @if paginated.commits.is_empty() {
                             p class="empty-state" { "No commits found" }
                         } @else {
-                            ol class="commit-list" {
-                                @for commit in &paginated.commits {
-                                    li class="commit-entry" {
-                                        div class="commit-header" {
-                                            span class="commit-hash" {
-                                                (commit_hash(commit.oid()))
-                                            }
-                                            span class="commit-message" { (commit.message()) }
-                                        }
-                                        div class="commit-meta" {
-                                            (crate::avatar::render(commit.author(), 20))
-                                            (attribution(commit))
-                                            span class="commit-date" {
-                                                (format_timestamp(commit.date()))
-                                            }
-                                        }
-                                    }
-                                }
+                            (commit_list(&paginated.commits, &paginated.graph, &root))
+                            @if let Some(truncation) = &paginated.truncation {
+                                (truncation_marker(truncation, &root))
                             }
                             (pagination_controls(paginated))
                         }
@@ -86,6 +84,100 @@ pub fn generate(
     )
 }
 
+/// Renders the note ending a truncated history
+///
+/// # Arguments
+///
+/// * `truncation`: Why the history ends early
+/// * `root`: Relative prefix from the current page to the site root
+fn truncation_marker(truncation: &Truncation, root: &str) -> Markup {
+    html! {
+        div class="history-truncated" {
+            i class="ph ph-scissors" {}
+            @match truncation {
+                Truncation::MaxCommits(count) => {
+                    "History truncated after the latest " (count) " commits"
+                }
+                Truncation::Since(since) => {
+                    "History truncated at commits made before " (format_date(*since))
+                }
+                Truncation::DefaultBranch(branch) => {
+                    "History truncated where it joins "
+                    a href=(format!("{}commits/{}/page-1.html", root, branch)) { (branch) }
+                }
+            }
+        }
+    }
+}
+
+/// Renders a list of commits linking to their detail pages
+///
+/// When graph rows are given, each entry gets its graph segment and the
+/// branch and tag labels of the commit.
+///
+/// # Arguments
+///
+/// * `commits`: Commits to list, newest first
+/// * `graph`: Graph rows aligned with `commits`, or empty for a plain list
+/// * `root`: Relative prefix from the current page to the site root
+///
+/// # Returns
+///
+/// Rendered HTML markup for the commit list
+pub(crate) fn commit_list(commits: &[CommitInfo], graph: &[GraphRow], root: &str) -> Markup {
+    let renderer = has_notes(commits).then(MarkdownRenderer::new);
+    let lanes = graph.iter().map(|row| row.width).max().unwrap_or(0);
+    let list_style = format!("--graph-width: {}px", graph_width(lanes));
+    html! {
+        ol class=(if graph.is_empty() { "commit-list" } else { "commit-list has-graph" })
+            style=[(!graph.is_empty()).then_some(list_style)] {
+            @for (idx, commit) in commits.iter().enumerate() {
+                @let commit_href = format!("{}commit/{}.html", root, commit.oid());
+                @let row = graph.get(idx);
+                li class="commit-entry" {
+                    @if let Some(row) = row {
+                        (graph_cell(row, lanes))
+                    }
+                    div class="commit-header" {
+                        a class="commit-hash" href=(commit_href) {
+                            (commit_hash(commit.oid()))
+                        }
+                        a class="commit-message" href=(commit_href) { (commit.message()) }
+                        @if let Some(row) = row {
+                            (ref_badges(&row.labels))
+                        }
+                    }
+                    @if !commit.body().is_empty() {
+                        details class="commit-body" {
+                            summary title="Show full message" { "…" }
+                            pre { (commit.body()) }
+                        }
+                    }
+                    div class="commit-meta" {
+                        (author_avatar(commit.author(), 20, root))
+                        (attribution(commit, root))
+                        span class="commit-date" {
+                            (format_timestamp(commit.date()))
+                        }
+                        @if let Some(signature) = commit.signature() {
+                            (signature_badge(signature))
+                        }
+                    }
+                    (trailer_chips(commit.trailers()))
+                    @if let Some(renderer) = &renderer {
+                        (commit_notes(commit.notes(), renderer, false))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns true if any listed commit carries git notes.
+pub(crate) fn has_notes(commits: &[CommitInfo]) -> bool {
+    commits.iter().any(|commit| !commit.notes().is_empty())
+}
+
 /// Generates pagination controls for commit history navigation
 ///
 /// Renders previous/next page links with proper disabled states.
@@ -98,7 +190,7 @@ pub fn generate(
 /// # Returns
 ///
 /// Rendered HTML markup for pagination controls
-fn pagination_controls(paginated: &PaginatedCommits) -> Markup {
+pub(crate) fn pagination_controls(paginated: &PaginatedCommits) -> Markup {
     let has_prev = paginated.page > 1;
     let has_next = paginated.has_more;
 
@@ -208,6 +300,184 @@ mod tests {
         assert_eq!(mock_commits.len(), 3, "Should have exactly 3 test commits");
     }
 
+    #[test]
+    fn test_commits_page_renders_body_and_trailers() {
+        // Arrange
+        let commits = vec![CommitInfo::new(
+            "abc123def456".to_string(),
+            "Fix parser".to_string(),
+            "Fix parser\n\nHandle empty input.\n\nReviewed-by: Bob <bob@example.com>\nFixes: 1a2b3c4"
+                .to_string(),
+            "Alice".to_string(),
+            1234567890,
+        )];
+        let paginated = PaginatedCommits::new(commits, 1, 35, false);
+
+        // Act
+        let html = generate(&paginated, "main", "test-repo", 1).into_string();
+
+        // Assert
+        assert!(html.contains(r#"<details class="commit-body">"#));
+        assert!(
+            html.contains("<pre>Handle empty input.</pre>"),
+            "Body should omit trailers"
+        );
+        assert!(html.contains(r#"<span class="trailer-key">Reviewed-by</span>"#));
+        assert!(html.contains(r#"<span class="trailer-value">Bob</span>"#));
+        assert!(html.contains(r#"<span class="trailer-value">1a2b3c4</span>"#));
+    }
+
+    #[test]
+    fn test_commits_page_renders_graph_and_labels() {
+        // Arrange
+        let commits = vec![
+            CommitInfo::new(
+                "bbb".to_string(),
+                "Second".to_string(),
+                "Second".to_string(),
+                "Alice".to_string(),
+                1234567890,
+            )
+            .with_parents(vec!["aaa".to_string()]),
+            CommitInfo::new(
+                "aaa".to_string(),
+                "First".to_string(),
+                "First".to_string(),
+                "Alice".to_string(),
+                1234567800,
+            ),
+        ];
+        let graph = vec![
+            GraphRow {
+                column: 0,
+                width: 1,
+                edges: vec![crate::graph::Edge::OutOf(0)],
+                is_merge: false,
+                labels: vec![crate::graph::RefLabel::Branch("main".to_string())],
+            },
+            GraphRow {
+                column: 0,
+                width: 2,
+                edges: vec![crate::graph::Edge::Into(0), crate::graph::Edge::Through(1)],
+                is_merge: false,
+                labels: vec![],
+            },
+        ];
+        let paginated = PaginatedCommits::new(commits, 1, 35, false).with_graph(graph);
+
+        // Act
+        let html = generate(&paginated, "main", "test-repo", 2).into_string();
+
+        // Assert
+        assert!(html.contains(r#"<ol class="commit-list has-graph" style="--graph-width: 28px">"#));
+        assert_eq!(
+            html.matches(r#"<svg class="commit-graph" width="28""#)
+                .count(),
+            2
+        );
+        assert!(html.contains(r#"<span class="ref-label branch">"#));
+    }
+
+    #[test]
+    fn test_commits_page_renders_collapsed_notes() {
+        // Arrange
+        let mut commit = CommitInfo::new(
+            "abc123def456".to_string(),
+            "Add feature".to_string(),
+            "Add feature".to_string(),
+            "Alice".to_string(),
+            1234567890,
+        );
+        commit.set_notes(vec![crate::notes::Note::new("ci", "**Build passed**")]);
+        let paginated = PaginatedCommits::new(vec![commit], 1, 35, false);
+
+        // Act
+        let html = generate(&paginated, "main", "test-repo", 1).into_string();
+
+        // Assert
+        assert!(
+            html.contains(r#"<details class="commit-note">"#),
+            "Notes should start collapsed"
+        );
+        assert!(html.contains("<strong>Build passed</strong>"));
+        assert!(html.contains(r#"<span class="commit-note-ref">ci</span>"#));
+        assert!(html.contains("../../assets/markdown.css"));
+    }
+
+    #[test]
+    fn test_commits_page_without_notes_skips_markdown_css() {
+        // Arrange
+        let commits = vec![CommitInfo::new(
+            "abc123def456".to_string(),
+            "Add feature".to_string(),
+            "Add feature".to_string(),
+            "Alice".to_string(),
+            1234567890,
+        )];
+        let paginated = PaginatedCommits::new(commits, 1, 35, false);
+
+        // Act
+        let html = generate(&paginated, "main", "test-repo", 1).into_string();
+
+        // Assert
+        assert!(!html.contains("commit-note"));
+        assert!(!html.contains("markdown.css"));
+    }
+
+    #[test]
+    fn test_commits_page_marks_truncated_history() {
+        // Arrange
+        let commits = vec![CommitInfo::new(
+            "abc123def456".to_string(),
+            "Add feature".to_string(),
+            "Add feature".to_string(),
+            "Alice".to_string(),
+            1234567890,
+        )];
+        let paginated = PaginatedCommits::new(commits, 1, 35, false)
+            .with_truncation(Some(Truncation::DefaultBranch("main".to_string())));
+
+        // Act
+        let html = generate(&paginated, "feature/login", "test-repo", 1).into_string();
+
+        // Assert
+        assert!(html.contains(r#"<div class="history-truncated">"#));
+        assert!(html.contains(
+            r#"History truncated where it joins <a href="../../../commits/main/page-1.html">main</a>"#
+        ));
+    }
+
+    #[test]
+    fn test_truncation_marker_reasons() {
+        let max = truncation_marker(&Truncation::MaxCommits(500), "").into_string();
+        let since = truncation_marker(&Truncation::Since(1_699_920_000), "").into_string();
+
+        assert!(max.contains("History truncated after the latest 500 commits"));
+        assert!(since.contains("History truncated at commits made before 2023-11-14"));
+    }
+
+    #[test]
+    fn test_commits_page_links_commit_detail() {
+        // Arrange
+        let commits = vec![CommitInfo::new(
+            "abc123def456".to_string(),
+            "Add feature".to_string(),
+            "Add feature".to_string(),
+            "Alice".to_string(),
+            1234567890,
+        )];
+        let paginated = PaginatedCommits::new(commits, 1, 35, false);
+
+        // Act
+        let html = generate(&paginated, "feature/x", "test-repo", 1).into_string();
+
+        // Assert: commits/feature/x/page-1.html is three levels below root
+        assert!(
+            html.contains("href=\"../../../commit/abc123def456.html\""),
+            "Should link to commit detail page"
+        );
+    }
+
     #[test]
     fn test_pagination_controls_first_page_with_more() {
         // Arrange: First page with more commits available
@@ -0,0 +1,235 @@
+//! Contributor ranking and per-author page generation
+
+use maud::{Markup, html};
+
+use crate::components::commit::{author_avatar, author_link};
+use crate::components::layout::page_wrapper;
+use crate::components::nav::breadcrumb;
+use crate::contributors::Contributor;
+use crate::git::PaginatedCommits;
+use crate::pages::commits::{commit_list, has_notes, pagination_controls};
+use crate::util::format_date;
+
+/// Generates the contributors ranking page
+///
+/// Lists authors and co-authors ranked by commit count, with the dates of
+/// their first and last commits and the lines they added and removed.
+///
+/// # Arguments
+///
+/// * `repo_name`: Repository name for page title and breadcrumb
+/// * `contributors`: Contributors in ranking order
+///
+/// # Returns
+///
+/// Complete HTML page as Markup
+pub fn generate_list(repo_name: &str, contributors: &[Contributor]) -> Markup {
+    let css_path = "../assets/contributors.css";
+    let index_path = "../index.html";
+
+    page_wrapper(
+        &format!("{}: contributors", repo_name),
+        &[css_path],
+        html! {
+            (breadcrumb(repo_name, index_path, &[("Contributors", None)], "contributors"))
+
+            main.repo-card {
+                div.repo-controls {
+                    div.control-left {
+                        h1.page-title {
+                            i.ph.ph-users {}
+                            "Contributors"
+                        }
+                    }
+                    div.control-right {
+                        span.badge { (contributors.len()) " contributors" }
+                    }
+                }
+
+                @if contributors.is_empty() {
+                    div.empty-state {
+                        p { "No commits found in this repository." }
+                    }
+                } @else {
+                    ol.contributor-list {
+                        @for (rank, contributor) in contributors.iter().enumerate() {
+                            li.contributor-row {
+                                span.contributor-rank { "#" (rank + 1) }
+                                (author_avatar(contributor.name(), 32, "../"))
+                                div.contributor-name {
+                                    (author_link(contributor.name(), "../"))
+                                    span.contributor-span {
+                                        (format_date(contributor.first_date()))
+                                        " – "
+                                        (format_date(contributor.last_date()))
+                                    }
+                                }
+                                div.contributor-stats {
+                                    span.contributor-commits {
+                                        (contributor.commit_count())
+                                        @if contributor.commit_count() == 1 { " commit" } @else { " commits" }
+                                    }
+                                    span.stat-added { "+" (contributor.lines_added()) }
+                                    span.stat-removed { "−" (contributor.lines_removed()) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
+
+/// Generates one page of an author's commits
+///
+/// Pages live under `contributors/<slug>/` like the per-branch commit
+/// pages, with the author's statistics above the commit list.
+///
+/// # Arguments
+///
+/// * `repo_name`: Repository name for page title and breadcrumb
+/// * `contributor`: Author whose commits are listed
+/// * `paginated`: Page of the author's commits, newest first
+///
+/// # Returns
+///
+/// Complete HTML page as Markup
+pub fn generate_author(
+    repo_name: &str,
+    contributor: &Contributor,
+    paginated: &PaginatedCommits,
+) -> Markup {
+    let root = "../../";
+    let css_path = format!("{}assets/author.css", root);
+    let markdown_css_path = format!("{}assets/markdown.css", root);
+    let stylesheets: &[&str] = if has_notes(&paginated.commits) {
+        &[&css_path, &markdown_css_path]
+    } else {
+        &[&css_path]
+    };
+    let index_path = format!("{}index.html", root);
+
+    let total = contributor.commit_count();
+    let start = (paginated.page - 1) * paginated.per_page + 1;
+    let end = start + paginated.commits.len().max(1) - 1;
+
+    page_wrapper(
+        &format!("{}: {}", repo_name, contributor.name()),
+        stylesheets,
+        html! {
+            (breadcrumb(
+                repo_name,
+                &index_path,
+                &[("Contributors", Some("../index.html".to_string())), (contributor.name(), None)],
+                "contributors"
+            ))
+            main {
+                div.author-summary {
+                    (crate::avatar::render(contributor.name(), 48))
+                    div {
+                        h1 { (contributor.name()) }
+                        div.author-stats {
+                            span { (total) @if total == 1 { " commit" } @else { " commits" } }
+                            span.stat-added { "+" (contributor.lines_added()) }
+                            span.stat-removed { "−" (contributor.lines_removed()) }
+                            span {
+                                (format_date(contributor.first_date()))
+                                " – "
+                                (format_date(contributor.last_date()))
+                            }
+                        }
+                    }
+                }
+                div class="commit-count" {
+                    (start) "–" (end) " of " (total)
+                }
+                @if paginated.commits.is_empty() {
+                    p class="empty-state" { "No commits found" }
+                } @else {
+                    (commit_list(&paginated.commits, &[], root))
+                    (pagination_controls(paginated))
+                }
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contributors::collect_contributors;
+    use crate::git::CommitInfo;
+    use std::collections::HashMap;
+
+    fn sample_contributors() -> Vec<Contributor> {
+        let commits = vec![
+            CommitInfo::new(
+                "abc1234".to_string(),
+                "Add parser".to_string(),
+                "Add parser\n\nCo-authored-by: Bob Stone <bob@example.com>".to_string(),
+                "Alice".to_string(),
+                1_700_000_000,
+            ),
+            CommitInfo::new(
+                "def5678".to_string(),
+                "Initial commit".to_string(),
+                "Initial commit".to_string(),
+                "Alice".to_string(),
+                1_600_000_000,
+            ),
+        ];
+        let stats = HashMap::from([("abc1234".to_string(), (12, 3))]);
+        collect_contributors(&commits, &stats)
+    }
+
+    #[test]
+    fn test_generate_list_ranks_contributors() {
+        // Arrange
+        let contributors = sample_contributors();
+
+        // Act
+        let html = generate_list("test-repo", &contributors).into_string();
+
+        // Assert
+        assert!(html.contains("2 contributors"));
+        let alice = html.find("Alice").expect("Should list Alice");
+        let bob = html.find("Bob Stone").expect("Should list co-author");
+        assert!(alice < bob, "Should rank by commit count");
+        assert!(html.contains(r#"href="../contributors/bob-stone/page-1.html""#));
+        assert!(html.contains("2020-09-13 – 2023-11-14"));
+        assert!(html.contains("+12"));
+    }
+
+    #[test]
+    fn test_generate_list_empty() {
+        // Act
+        let html = generate_list("test-repo", &[]).into_string();
+
+        // Assert
+        assert!(html.contains("No commits found"));
+    }
+
+    #[test]
+    fn test_generate_author_lists_commits() {
+        // Arrange
+        let contributors = sample_contributors();
+        let commits = vec![CommitInfo::new(
+            "abc1234".to_string(),
+            "Add parser".to_string(),
+            "Add parser".to_string(),
+            "Alice".to_string(),
+            1_700_000_000,
+        )];
+        let paginated = PaginatedCommits::new(commits, 1, 35, false);
+
+        // Act
+        let html = generate_author("test-repo", &contributors[0], &paginated).into_string();
+
+        // Assert
+        assert!(html.contains("../../assets/author.css"));
+        assert!(html.contains("<h1>Alice</h1>"));
+        assert!(html.contains(r#"href="../../commit/abc1234.html""#));
+        assert!(html.contains("1–1 of 2"));
+    }
+}
@@ -0,0 +1,160 @@
+//! History page generation for files and directories
+
+use maud::{Markup, html};
+
+use crate::components::layout::page_wrapper;
+use crate::components::nav::{breadcrumb, extract_breadcrumb_components};
+use crate::git::PaginatedCommits;
+use crate::pages::commits::{commit_list, has_notes, pagination_controls};
+use crate::util::calculate_depth;
+
+/// Generates HTML page listing commits that changed a path
+///
+/// Pages are written to `history/<ref>/<path>/page-N.html`, so links back
+/// into the site are one level deeper than the blob or tree page for the
+/// same path.
+///
+/// # Arguments
+///
+/// * `paginated`: Paginated commit data with page metadata
+/// * `ref_name`: Reference name (branch/tag) the history starts from
+/// * `path`: File or directory path within repository tree
+/// * `is_dir`: Whether the path is a directory
+/// * `repo_name`: Repository name for navigation
+///
+/// # Returns
+///
+/// Rendered HTML markup
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::pages::history::generate;
+/// use gitkyl::{CommitOrder, Mailmap, list_path_commits_paginated};
+/// use std::path::Path;
+///
+/// let mailmap = Mailmap::default();
+/// let order = CommitOrder::load(Path::new("."), Some("main"))?;
+/// let paginated =
+///     list_path_commits_paginated(Path::new("."), &order, "src/git.rs", true, 1, 35, &mailmap)?;
+/// let html = generate(&paginated, "main", "src/git.rs", false, "my-repo");
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn generate(
+    paginated: &PaginatedCommits,
+    ref_name: &str,
+    path: &str,
+    is_dir: bool,
+    repo_name: &str,
+) -> Markup {
+    let depth = calculate_depth(ref_name, path) + 1;
+    let root = "../".repeat(depth);
+    let css_path = format!("{}assets/commits.css", root);
+    let markdown_css_path = format!("{}assets/markdown.css", root);
+    let stylesheets: &[&str] = if has_notes(&paginated.commits) {
+        &[&css_path, &markdown_css_path]
+    } else {
+        &[&css_path]
+    };
+    let index_path = format!("{}index.html", root);
+
+    let components = extract_breadcrumb_components(path);
+    let breadcrumb_data: Vec<(&str, Option<String>)> = components
+        .iter()
+        .enumerate()
+        .map(|(idx, &component)| {
+            let partial_path = components[..=idx].join("/");
+            let kind = if idx == components.len() - 1 && !is_dir {
+                "blob"
+            } else {
+                "tree"
+            };
+            let link = format!("{}{}/{}/{}.html", root, kind, ref_name, partial_path);
+            (component, Some(link))
+        })
+        .chain(std::iter::once(("History", None)))
+        .collect();
+
+    let title = format!("{}/{}: {} (history)", repo_name, ref_name, path);
+
+    page_wrapper(
+        &title,
+        stylesheets,
+        html! {
+            (breadcrumb(repo_name, &index_path, &breadcrumb_data, ref_name))
+            main {
+                h1 {
+                    "History for "
+                    code class="history-path" { (path) }
+                }
+                @if paginated.commits.is_empty() {
+                    p class="empty-state" { "No commits found" }
+                } @else {
+                    (commit_list(&paginated.commits, &[], &root))
+                    (pagination_controls(paginated))
+                }
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::CommitInfo;
+
+    fn commits() -> Vec<CommitInfo> {
+        vec![CommitInfo::new(
+            "abc123def456".to_string(),
+            "Move parser".to_string(),
+            "Move parser".to_string(),
+            "Alice".to_string(),
+            1234567890,
+        )]
+    }
+
+    #[test]
+    fn test_history_page_for_file() {
+        // Arrange
+        let paginated = PaginatedCommits::new(commits(), 1, 35, false);
+
+        // Act
+        let html = generate(&paginated, "main", "src/git.rs", false, "test-repo").into_string();
+
+        // Assert: history/main/src/git.rs/page-1.html is four levels below root
+        assert!(html.contains("History for"));
+        assert!(html.contains("../../../../commit/abc123def456.html"));
+        assert!(html.contains("../../../../blob/main/src/git.rs.html"));
+        assert!(html.contains("../../../../tree/main/src.html"));
+        assert!(html.contains("../../../../assets/commits.css"));
+    }
+
+    #[test]
+    fn test_history_page_for_directory() {
+        // Arrange
+        let paginated = PaginatedCommits::new(commits(), 2, 1, true);
+
+        // Act
+        let html = generate(&paginated, "feature/x", "src", true, "test-repo").into_string();
+
+        // Assert
+        assert!(html.contains("../../../../tree/feature/x/src.html"));
+        assert!(
+            html.contains("page-1.html"),
+            "Previous should link to page 1"
+        );
+        assert!(html.contains("page-3.html"), "Next should link to page 3");
+    }
+
+    #[test]
+    fn test_history_page_empty() {
+        // Arrange
+        let paginated = PaginatedCommits::new(vec![], 1, 35, false);
+
+        // Act
+        let html = generate(&paginated, "main", "README.md", false, "test-repo").into_string();
+
+        // Assert
+        assert!(html.contains("No commits found"));
+    }
+}
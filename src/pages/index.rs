@@ -4,12 +4,18 @@ use anyhow::{Context, Result};
 use maud::{Markup, PreEscaped, html};
 use std::path::Path;
 
-use crate::components::commit::{attribution, commit_hash};
-use crate::components::file_list::{file_row, file_table};
+use crate::activity::Activity;
+use crate::components::activity::calendar;
+use crate::components::commit::{attribution, author_avatar, commit_hash};
+use crate::components::file_list::{
+    file_row, file_table, submodule_href, submodule_row, symlink_href, symlink_row,
+};
 use crate::components::icons::file_icon;
+use crate::components::languages::language_bar;
 use crate::components::layout::page_wrapper;
-use crate::components::metadata::{RepoHeaderData, branch_selector, repo_header};
-use crate::git::{CommitInfo, TreeItem};
+use crate::components::metadata::{RepoHeaderData, branch_selector, clone_url, repo_header};
+use crate::git::{CommitInfo, TagInfo, TreeItem};
+use crate::languages::LanguageBreakdown;
 use crate::util::format_timestamp;
 
 /// Minimum branches required to show selector dropdown
@@ -18,6 +24,9 @@ use crate::util::format_timestamp;
 /// branch badge instead of interactive selector to reduce visual noise.
 const MIN_BRANCHES_FOR_SELECTOR: usize = 2;
 
+/// Weeks of commit activity shown next to the repository header
+const COMPACT_CALENDAR_WEEKS: usize = 26;
+
 /// Data container for index page generation
 pub struct IndexPageData<'a> {
     pub name: &'a str,
@@ -26,10 +35,21 @@ pub struct IndexPageData<'a> {
     pub branches: &'a [String],
     pub commit_count: usize,
     pub tag_count: usize,
+    pub contributor_count: usize,
     pub latest_commit: Option<&'a CommitInfo>,
     pub items: &'a [TreeItem],
     pub readme_html: Option<&'a str>,
     pub depth: usize,
+    /// Source archive name (without extension) offered for download
+    pub archive: Option<&'a str>,
+    /// Commit activity of the default branch, shown as a compact calendar
+    pub activity: Option<&'a Activity>,
+    /// Language breakdown of the default branch, shown as a colored bar
+    pub languages: Option<&'a LanguageBreakdown>,
+    /// Clone URL of the dumb HTTP repository, shown next to the branches
+    pub clone_url: Option<&'a str>,
+    /// Tag shown as the latest release in the header
+    pub latest_release: Option<&'a TagInfo>,
 }
 
 /// Generates repository index page HTML with optional README rendering
@@ -74,30 +94,59 @@ pub fn generate(data: IndexPageData<'_>) -> Markup {
             } else {
                 None
             };
-            (repo_header(RepoHeaderData {
-                name: data.name,
-                owner: data.owner,
-                tag_count: data.tag_count,
-                tags_href: tags_href.as_deref(),
-            }))
+            @let contributors_href = (data.contributor_count > 0)
+                .then(|| format!("{}contributors/index.html", "../".repeat(data.depth)));
+            @let archive_href = data
+                .archive
+                .map(|archive| format!("{}archive/{}", "../".repeat(data.depth), archive));
+            @let latest_release_href = data
+                .latest_release
+                .map(|tag| format!("{}tags/{}.html", "../".repeat(data.depth), tag.name));
+            div class="repo-header-row" {
+                (repo_header(RepoHeaderData {
+                    name: data.name,
+                    owner: data.owner,
+                    tag_count: data.tag_count,
+                    tags_href: tags_href.as_deref(),
+                    contributor_count: data.contributor_count,
+                    contributors_href: contributors_href.as_deref(),
+                    archive_href: archive_href.as_deref(),
+                    latest_release: data.latest_release,
+                    latest_release_href: latest_release_href.as_deref(),
+                }))
+                @if let Some(activity) = data.activity {
+                    a class="repo-activity" href=(format!("{}activity/index.html", "../".repeat(data.depth)))
+                        title="Commit activity" {
+                        (calendar(activity, COMPACT_CALENDAR_WEEKS, false))
+                    }
+                }
+            }
+            @if let Some(languages) = data.languages.filter(|languages| !languages.is_empty()) {
+                (language_bar(languages, &format!("{}languages/index.html", "../".repeat(data.depth))))
+            }
 
             main class="repo-card" {
                 div class="repo-controls" {
                     div class="control-left" {
                         @let branch_strs: Vec<&str> = data.branches.iter().map(|s| s.as_str()).collect();
                         (branch_selector(&branch_strs, data.default_branch, MIN_BRANCHES_FOR_SELECTOR, data.depth))
+                        @if let Some(url) = data.clone_url {
+                            (clone_url(url))
+                        }
                     }
 
                     @if let Some(commit) = data.latest_commit {
                         div class="commit-info-wrapper" {
                             div class="commit-line" {
-                                (crate::avatar::render(commit.author(), 24))
+                                (author_avatar(commit.author(), 24, &"../".repeat(data.depth)))
                                 span class="repo-commit-message" { (commit.message()) }
                             }
                             div class="commit-meta" {
-                                (attribution(commit))
+                                (attribution(commit, &"../".repeat(data.depth)))
                                 span { "·" }
-                                (commit_hash(commit.oid()))
+                                a class="commit-hash-link" href=(format!("{}commit/{}.html", "../".repeat(data.depth), commit.oid())) {
+                                    (commit_hash(commit.oid()))
+                                }
                                 span { "·" }
                                 span { (format_timestamp(commit.date())) }
                             }
@@ -133,6 +182,7 @@ pub fn generate(data: IndexPageData<'_>) -> Markup {
                                                     &href,
                                                     file_icon(path_str),
                                                     path_str,
+                                                    entry.is_executable(),
                                                     Some(commit),
                                                     &format_timestamp(commit.date())
                                                 ))
@@ -149,6 +199,30 @@ pub fn generate(data: IndexPageData<'_>) -> Markup {
                                                 &href,
                                                 file_icon(&format!("{}/", display_path)),
                                                 name,
+                                                false,
+                                                Some(commit),
+                                                &format_timestamp(commit.date())
+                                            ))
+                                        }
+                                        TreeItem::Symlink { entry, target, resolved, commit } => {
+                                            @if let Some(path) = entry.path()
+                                                && let Some(path_str) = path.to_str() {
+                                                @let href = symlink_href(&"../".repeat(data.depth), data.default_branch, path_str, resolved.as_ref());
+                                                (symlink_row(
+                                                    &href,
+                                                    path_str,
+                                                    target,
+                                                    Some(commit),
+                                                    &format_timestamp(commit.date())
+                                                ))
+                                            }
+                                        }
+                                        TreeItem::Submodule { name, full_path, oid, url, has_site, commit } => {
+                                            @let href = submodule_href(&"../".repeat(data.depth), full_path, oid, url.as_deref(), *has_site);
+                                            (submodule_row(
+                                                href.as_deref(),
+                                                name,
+                                                oid,
                                                 Some(commit),
                                                 &format_timestamp(commit.date())
                                             ))
@@ -264,10 +338,16 @@ mod tests {
             branches: &branches,
             commit_count,
             tag_count: 0,
+            contributor_count: 0,
             latest_commit: None,
             items: &items,
             readme_html: None,
             depth: 0,
+            archive: None,
+            activity: None,
+            languages: None,
+            clone_url: None,
+            latest_release: None,
         });
         let html_string = html.into_string();
 
@@ -280,6 +360,107 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_index_page_links_activity_and_contributors() {
+        // Arrange
+        let branches = vec!["main".to_string()];
+        let activity = Activity::from_commits(&[], 1_700_000_000);
+
+        // Act
+        let html = generate(IndexPageData {
+            name: "TestRepo",
+            owner: None,
+            default_branch: "main",
+            branches: &branches,
+            commit_count: 0,
+            tag_count: 0,
+            contributor_count: 3,
+            latest_commit: None,
+            items: &[],
+            readme_html: None,
+            depth: 0,
+            archive: None,
+            activity: Some(&activity),
+            languages: None,
+            clone_url: None,
+            latest_release: None,
+        })
+        .into_string();
+
+        // Assert
+        assert!(html.contains(r#"<a class="repo-activity" href="activity/index.html""#));
+        assert!(html.contains(r#"class="activity-calendar""#));
+        assert!(html.contains(r#"href="contributors/index.html""#));
+        assert!(html.contains(r#"<i class="ph ph-users"></i>3"#));
+    }
+
+    #[test]
+    fn test_index_page_shows_language_bar() {
+        // Arrange
+        let branches = vec!["main".to_string()];
+        let languages = LanguageBreakdown::from_bytes(std::collections::HashMap::from([(
+            "Rust".to_string(),
+            100,
+        )]));
+
+        // Act
+        let html = generate(IndexPageData {
+            name: "TestRepo",
+            owner: None,
+            default_branch: "main",
+            branches: &branches,
+            commit_count: 0,
+            tag_count: 0,
+            contributor_count: 0,
+            latest_commit: None,
+            items: &[],
+            readme_html: None,
+            depth: 1,
+            archive: None,
+            activity: None,
+            languages: Some(&languages),
+            clone_url: None,
+            latest_release: None,
+        })
+        .into_string();
+
+        // Assert
+        assert!(html.contains(r#"<a class="language-bar" href="../languages/index.html""#));
+        assert!(html.contains(">100.0%</span>"));
+    }
+
+    #[test]
+    fn test_index_page_shows_clone_url() {
+        // Arrange
+        let branches = vec!["main".to_string()];
+
+        // Act
+        let html = generate(IndexPageData {
+            name: "TestRepo",
+            owner: None,
+            default_branch: "main",
+            branches: &branches,
+            commit_count: 0,
+            tag_count: 0,
+            contributor_count: 0,
+            latest_commit: None,
+            items: &[],
+            readme_html: None,
+            depth: 0,
+            archive: None,
+            activity: None,
+            languages: None,
+            clone_url: Some("https://example.com/TestRepo.git"),
+            latest_release: None,
+        })
+        .into_string();
+
+        // Assert
+        assert!(html.contains(
+            r#"<code class="clone-command">git clone https://example.com/TestRepo.git</code>"#
+        ));
+    }
+
     #[test]
     fn test_index_page_with_latest_commit() {
         // Arrange: Test with mock commit data
@@ -307,10 +488,16 @@ mod tests {
             branches: &branches,
             commit_count,
             tag_count: 0,
+            contributor_count: 0,
             latest_commit: Some(&mock_commit),
             items: &items,
             readme_html: None,
             depth: 0,
+            archive: None,
+            activity: None,
+            languages: None,
+            clone_url: None,
+            latest_release: None,
         });
         let html_string = html.into_string();
 
@@ -372,10 +559,16 @@ mod tests {
             branches: &branches,
             commit_count,
             tag_count: 0,
+            contributor_count: 0,
             latest_commit: None,
             items: &items,
             readme_html: None,
             depth: 0,
+            archive: None,
+            activity: None,
+            languages: None,
+            clone_url: None,
+            latest_release: None,
         });
         let html_string = html.into_string();
 
@@ -423,10 +616,16 @@ mod tests {
             branches: &branches,
             commit_count: 1,
             tag_count: 0,
+            contributor_count: 0,
             latest_commit: None,
             items: &items,
             readme_html: None,
             depth: 0,
+            archive: None,
+            activity: None,
+            languages: None,
+            clone_url: None,
+            latest_release: None,
         });
         let html_string = html.into_string();
 
@@ -465,10 +664,16 @@ mod tests {
             branches: &branches,
             commit_count,
             tag_count: 0,
+            contributor_count: 0,
             latest_commit: None,
             items: &items,
             readme_html,
             depth: 0,
+            archive: None,
+            activity: None,
+            languages: None,
+            clone_url: None,
+            latest_release: None,
         });
         let html_string = html.into_string();
 
@@ -510,10 +715,16 @@ mod tests {
             branches: &branches,
             commit_count,
             tag_count: 0,
+            contributor_count: 0,
             latest_commit: None,
             items: &items,
             readme_html: None,
             depth: 0,
+            archive: None,
+            activity: None,
+            languages: None,
+            clone_url: None,
+            latest_release: None,
         });
         let html_string = html.into_string();
 
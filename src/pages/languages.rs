@@ -0,0 +1,116 @@
+//! Language breakdown page generation
+
+use maud::{Markup, html};
+
+use crate::components::languages::{format_percent, segment};
+use crate::components::layout::page_wrapper;
+use crate::components::nav::breadcrumb;
+use crate::languages::LanguageBreakdown;
+use crate::util::format_file_size;
+
+/// Generates the language breakdown page
+///
+/// Lists every language of the default branch tree with its share and
+/// size, below a full-width language bar.
+///
+/// # Arguments
+///
+/// * `repo_name`: Repository name for page title and breadcrumb
+/// * `branch`: Branch whose tree was measured
+/// * `breakdown`: Languages of the branch tree, largest first
+///
+/// # Returns
+///
+/// Complete HTML page as Markup
+pub fn generate(repo_name: &str, branch: &str, breakdown: &LanguageBreakdown) -> Markup {
+    let css_path = "../assets/languages.css";
+    let index_path = "../index.html";
+    let count = breakdown.languages().len();
+
+    page_wrapper(
+        &format!("{}: languages", repo_name),
+        &[css_path],
+        html! {
+            (breadcrumb(repo_name, index_path, &[("Languages", None)], branch))
+
+            main.repo-card {
+                div.repo-controls {
+                    div.control-left {
+                        h1.page-title {
+                            i.ph.ph-code {}
+                            "Languages"
+                        }
+                    }
+                    div.control-right {
+                        span.badge {
+                            (count)
+                            @if count == 1 { " language" } @else { " languages" }
+                        }
+                    }
+                }
+
+                @if breakdown.is_empty() {
+                    div.empty-state {
+                        p { "No source files detected on " (branch) "." }
+                    }
+                } @else {
+                    div.language-content {
+                        div.language-bar.language-bar-large {
+                            @for language in breakdown.languages() {
+                                (segment(breakdown, language))
+                            }
+                        }
+                        p.language-total {
+                            (format_file_size(breakdown.total() as usize)) " of source on " (branch)
+                        }
+                    }
+                    ol.language-list {
+                        @for language in breakdown.languages() {
+                            li.language-row {
+                                span.language-dot style=(format!("background-color: {}", language.color())) {}
+                                span.language-name { (language.name()) }
+                                span.language-size { (format_file_size(language.bytes() as usize)) }
+                                span.language-percent { (format_percent(breakdown.percent(language))) }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_generate_lists_languages() {
+        // Arrange
+        let breakdown = LanguageBreakdown::from_bytes(HashMap::from([
+            ("Rust".to_string(), 3072),
+            ("CSS".to_string(), 1024),
+        ]));
+
+        // Act
+        let html = generate("test-repo", "main", &breakdown).into_string();
+
+        // Assert
+        assert!(html.contains("../assets/languages.css"));
+        assert!(html.contains("2 languages"));
+        assert!(html.contains("4.00 KB of source on main"));
+        assert!(html.contains(">3.00 KB</span>"));
+        assert!(html.contains(">75.0%</span>"));
+        assert!(html.find(">Rust<") < html.find(">CSS<"));
+    }
+
+    #[test]
+    fn test_generate_empty_tree() {
+        // Act
+        let html = generate("test-repo", "main", &LanguageBreakdown::default()).into_string();
+
+        // Assert
+        assert!(html.contains("No source files detected on main."));
+    }
+}
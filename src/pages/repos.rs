@@ -0,0 +1,181 @@
+//! Multi-repository landing page generation
+
+use maud::{Markup, html};
+
+use crate::components::layout::page_wrapper;
+use crate::repos::RepoSummary;
+use crate::util::format_timestamp;
+
+/// Generates the landing page listing every repository of a site
+///
+/// Repositories are grouped by section, with unsectioned repositories
+/// listed first. Each row links to the repository's own site.
+///
+/// # Arguments
+///
+/// * `title`: Page title and heading
+/// * `repos`: Repository summaries, in display order within sections
+///
+/// # Returns
+///
+/// Complete HTML page as Markup
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::pages::repos::generate;
+/// use gitkyl::{RefFilter, discover_repositories, summarize_repository};
+/// use std::path::Path;
+///
+/// let root = Path::new("/srv/git");
+/// let repos = discover_repositories(root)?
+///     .iter()
+///     .map(|path| summarize_repository(root, path, None, &RefFilter::default()))
+///     .collect::<Result<Vec<_>, _>>()?;
+/// let html = generate("Repositories", &repos);
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn generate(title: &str, repos: &[RepoSummary]) -> Markup {
+    page_wrapper(
+        title,
+        &["assets/repos.css"],
+        html! {
+            main.repo-card {
+                div.repo-controls {
+                    div.control-left {
+                        h1.page-title {
+                            i.ph.ph-books {}
+                            (title)
+                        }
+                    }
+                    div.control-right {
+                        span.badge {
+                            (repos.len())
+                            @if repos.len() == 1 { " repository" } @else { " repositories" }
+                        }
+                    }
+                }
+
+                @if repos.is_empty() {
+                    div.empty-state {
+                        p { "No repositories found." }
+                    }
+                } @else {
+                    @for (section, members) in sections(repos) {
+                        @if let Some(section) = section {
+                            h2.section-title.repo-section { (section) }
+                        }
+                        div.file-table {
+                            @for repo in members {
+                                (repo_row(repo))
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
+
+/// Renders a single repository row.
+fn repo_row(repo: &RepoSummary) -> Markup {
+    html! {
+        a.file-row href=(format!("{}/index.html", repo.site_path)) {
+            div.cell-name {
+                i.ph.ph-book {}
+                span.name-text { (repo.name) }
+            }
+            div.cell-message {
+                @if let Some(ref description) = repo.description {
+                    (description)
+                } @else {
+                    span.faint { "No description" }
+                }
+            }
+            div.cell-meta {
+                @if let Some(ref owner) = repo.owner {
+                    span.owner { (owner) }
+                }
+                span.branch {
+                    i.ph.ph-git-branch {}
+                    (repo.default_branch)
+                }
+                span.tags {
+                    i.ph.ph-tag {}
+                    (repo.tag_count)
+                }
+                span.date {
+                    @if let Some(date) = repo.last_commit {
+                        (format_timestamp(date))
+                    } @else {
+                        "-"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Groups repositories by section, unsectioned first, sections by name.
+fn sections(repos: &[RepoSummary]) -> Vec<(Option<&str>, Vec<&RepoSummary>)> {
+    let mut groups: std::collections::BTreeMap<Option<&str>, Vec<&RepoSummary>> =
+        std::collections::BTreeMap::new();
+    for repo in repos {
+        groups
+            .entry(repo.section.as_deref())
+            .or_default()
+            .push(repo);
+    }
+    groups.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(name: &str, section: Option<&str>) -> RepoSummary {
+        RepoSummary {
+            name: name.to_string(),
+            site_path: match section {
+                Some(section) => format!("{}/{}", section, name),
+                None => name.to_string(),
+            },
+            section: section.map(str::to_string),
+            description: Some(format!("{} description", name)),
+            owner: Some("Owner".to_string()),
+            default_branch: "main".to_string(),
+            last_commit: Some(1234567890),
+            tag_count: 3,
+        }
+    }
+
+    #[test]
+    fn test_generate_groups_sections() {
+        // Arrange
+        let repos = vec![
+            summary("zeta", Some("tools")),
+            summary("alpha", None),
+            summary("beta", Some("libs")),
+        ];
+
+        // Act
+        let html = generate("Repositories", &repos).into_string();
+
+        // Assert
+        assert!(html.contains("3 repositories"));
+        assert!(html.contains(r#"href="tools/zeta/index.html""#));
+        assert!(html.contains("alpha description"));
+        let alpha = html.find("alpha description").unwrap();
+        let libs = html.find(">libs<").expect("Should render libs section");
+        let tools = html.find(">tools<").expect("Should render tools section");
+        assert!(alpha < libs, "Unsectioned repositories come first");
+        assert!(libs < tools, "Sections are sorted by name");
+    }
+
+    #[test]
+    fn test_generate_empty() {
+        let html = generate("Repositories", &[]).into_string();
+
+        assert!(html.contains("No repositories found"));
+    }
+}
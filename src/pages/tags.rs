@@ -1,22 +1,28 @@
 //! Tag listing and detail page generation
 
-use maud::{Markup, html};
+use maud::{Markup, PreEscaped, html};
 
+use crate::archive::ArchiveFormat;
 use crate::avatar;
+use crate::components::commit::{attribution, change_icon, commit_hash, signature_badge};
 use crate::components::layout::page_wrapper;
 use crate::components::nav::breadcrumb;
-use crate::git::TagInfo;
+use crate::git::{CommitInfo, TagInfo};
+use crate::markdown::MarkdownRenderer;
+use crate::release::{ReleaseChanges, latest_release};
 use crate::util::format_timestamp;
 
 /// Generates the tags listing page
 ///
-/// Displays all repository tags sorted by date (newest first).
-/// Shows tag name, short OID, message (if annotated), and date.
+/// Displays all repository tags newest first, grouped by major and minor
+/// version when tags are named as versions. Shows tag name, short OID,
+/// message (if annotated), and date, and marks pre-releases and the
+/// latest release.
 ///
 /// # Arguments
 ///
 /// * `repo_name`: Repository name for page title and breadcrumb
-/// * `tags`: Slice of TagInfo sorted by date
+/// * `tags`: Slice of TagInfo in `list_tags` order
 ///
 /// # Returns
 ///
@@ -24,6 +30,9 @@ use crate::util::format_timestamp;
 pub fn generate_list(repo_name: &str, tags: &[TagInfo]) -> Markup {
     let css_path = "../assets/tags.css";
     let index_path = "../index.html";
+    let groups = version_groups(tags);
+    let grouped = tags.iter().any(|tag| tag.version.is_some());
+    let latest = latest_release(tags).map(|tag| tag.name.as_str());
 
     page_wrapper(
         &format!("{}: tags", repo_name),
@@ -50,11 +59,21 @@ pub fn generate_list(repo_name: &str, tags: &[TagInfo]) -> Markup {
                     }
                 } @else {
                     div.file-table {
-                        @for tag in tags {
+                        @for (label, group) in &groups {
+                            @if grouped {
+                                div.tag-group-header {
+                                    span.tag-group-label {
+                                        @if let Some(label) = label { (label) } @else { "Other tags" }
+                                    }
+                                    span.tag-group-count { (group.len()) }
+                                }
+                            }
+                            @for tag in *group {
                             a.file-row href=(format!("{}.html", tag.name)) {
                                 div.cell-name {
                                     i.ph.ph-tag {}
                                     span.name-text { (tag.name) }
+                                    (release_badge(tag, latest))
                                 }
                                 div.cell-message {
                                     @if let Some(ref message) = tag.message {
@@ -64,6 +83,9 @@ pub fn generate_list(repo_name: &str, tags: &[TagInfo]) -> Markup {
                                     }
                                 }
                                 div.cell-meta {
+                                    @if let Some(ref signature) = tag.signature {
+                                        (signature_badge(signature))
+                                    }
                                     span.oid { (tag.short_oid) }
                                     span.date {
                                         @if let Some(date) = tag.date {
@@ -74,6 +96,7 @@ pub fn generate_list(repo_name: &str, tags: &[TagInfo]) -> Markup {
                                     }
                                 }
                             }
+                            }
                         }
                     }
                 }
@@ -82,34 +105,95 @@ pub fn generate_list(repo_name: &str, tags: &[TagInfo]) -> Markup {
     )
 }
 
-/// Generates a tag detail page
+/// Groups tags by major and minor version, keeping their order.
+///
+/// Versioned tags come first in `list_tags` order, so each group is a run
+/// of tags. Tags that are not versions form a last group without a label.
+fn version_groups(tags: &[TagInfo]) -> Vec<(Option<String>, &[TagInfo])> {
+    let series = |tag: &TagInfo| {
+        tag.version
+            .as_ref()
+            .map(|version| (version.major(), version.minor()))
+    };
+
+    tags.chunk_by(|a, b| series(a) == series(b))
+        .map(|group| {
+            let label = series(&group[0]).map(|(major, minor)| format!("{}.{}", major, minor));
+            (label, group)
+        })
+        .collect()
+}
+
+/// Renders the pre-release or latest release marker of a tag, if any.
+fn release_badge(tag: &TagInfo, latest: Option<&str>) -> Markup {
+    html! {
+        @if tag.version.as_ref().is_some_and(|version| version.is_prerelease()) {
+            span.release-badge.prerelease { "Pre-release" }
+        } @else if latest == Some(tag.name.as_str()) {
+            span.release-badge.latest { "Latest" }
+        }
+    }
+}
+
+/// Data container for release page generation
+pub struct ReleasePageData<'a> {
+    pub repo_name: &'a str,
+    pub tag: &'a TagInfo,
+    /// Commit the tag points to
+    pub commit: &'a CommitInfo,
+    /// Source archive name (without extension) under `archive/`
+    pub archive: Option<&'a str>,
+    /// Commits and file changes since the previous release
+    pub changes: &'a ReleaseChanges,
+    /// Release published before this one
+    pub previous: Option<&'a TagInfo>,
+    /// Release published after this one
+    pub next: Option<&'a TagInfo>,
+}
+
+/// Generates a tag detail page as a release page
 ///
-/// Shows tag information with commit details and browse link.
+/// Shows the tagged commit and tag metadata, the tag message rendered as
+/// markdown, every commit since the previous release and a diffstat of
+/// the files changed. Links to the previous and next releases let the
+/// pages be read in order as a changelog.
 ///
 /// # Arguments
 ///
-/// * `repo_name`: Repository name
-/// * `tag`: Tag information
-/// * `commit_message`: Full commit message at tag
-/// * `commit_author`: Commit author name
-/// * `commit_date`: Commit timestamp
+/// * `data`: Release page data container with all required fields
 ///
 /// # Returns
 ///
 /// Complete HTML page as Markup
-pub fn generate_detail(
-    repo_name: &str,
-    tag: &TagInfo,
-    commit_message: &str,
-    commit_author: &str,
-    commit_date: i64,
-) -> Markup {
+pub fn generate_detail(data: ReleasePageData<'_>) -> Markup {
+    let ReleasePageData {
+        repo_name,
+        tag,
+        commit,
+        archive,
+        changes,
+        previous,
+        next,
+    } = data;
     let css_path = "../assets/tags.css";
+    let markdown_css_path = "../assets/markdown.css";
     let index_path = "../index.html";
+    let message = tag
+        .message
+        .as_deref()
+        .map(str::trim)
+        .filter(|message| !message.is_empty());
+    let stylesheets: &[&str] = if message.is_some() {
+        &[css_path, markdown_css_path]
+    } else {
+        &[css_path]
+    };
+    let commit_count = changes.commits().len();
+    let total = changes.total();
 
     page_wrapper(
         &format!("{}: {}", repo_name, tag.name),
-        &[css_path],
+        stylesheets,
         html! {
             (breadcrumb(
                 repo_name,
@@ -127,29 +211,34 @@ pub fn generate_detail(
                         }
                     }
                     div.control-right {
+                        @if tag.version.as_ref().is_some_and(|version| version.is_prerelease()) {
+                            span.release-badge.prerelease { "Pre-release" }
+                        }
                         span.badge {
                             i.ph.ph-git-commit {}
-                            "Tag"
+                            "Release"
                         }
                     }
                 }
 
                 div.detail-content {
                     div.commit-info {
-                        (avatar::render(commit_author, 40))
+                        (avatar::render(commit.author(), 40))
                         div.commit-details {
                             div.commit-author-line {
-                                span.commit-author { (commit_author) }
-                                span.commit-date { (format_timestamp(commit_date)) }
+                                span.commit-author { (commit.author()) }
+                                span.commit-date { (format_timestamp(commit.date())) }
                             }
-                            p.commit-message-text { (commit_message) }
+                            p.commit-message-text { (commit.message()) }
                         }
                     }
 
                     div.detail-section {
                         div.detail-grid {
                             span.detail-label { "Commit" }
-                            span.detail-value.mono { (tag.target_oid) }
+                            a.detail-value.mono href=(format!("../commit/{}.html", tag.target_oid)) {
+                                (tag.target_oid)
+                            }
 
                             @if let Some(ref tagger) = tag.tagger {
                                 span.detail-label { "Tagger" }
@@ -160,21 +249,117 @@ pub fn generate_detail(
                                 span.detail-label { "Tagged" }
                                 span.detail-value { (format_timestamp(date)) }
                             }
+
+                            @if let Some(ref signature) = tag.signature {
+                                span.detail-label { "Signature" }
+                                span.detail-value.signature-value {
+                                    (signature_badge(signature))
+                                    @if let Some(fingerprint) = signature.fingerprint() {
+                                        span.mono { (fingerprint) }
+                                    }
+                                }
+                            }
+
+                            @if let Some(archive) = archive {
+                                span.detail-label { "Downloads" }
+                                span.detail-value.archive-links {
+                                    @for format in ArchiveFormat::ALL {
+                                        a.archive-link href=(format!("../archive/{}.{}", archive, format.extension())) download {
+                                            i.ph.ph-file-archive {}
+                                            (format.extension())
+                                        }
+                                    }
+                                }
+                            }
                         }
                     }
 
-                    @if let Some(ref message) = tag.message {
-                        @if !message.trim().is_empty() {
-                            div.tag-message-section {
-                                div.tag-message-label { "Tag Message" }
-                                p.tag-message-text { (message.trim()) }
+                    @if let Some(message) = message {
+                        div.tag-message-section {
+                            div.tag-message-label { "Release Notes" }
+                            @match MarkdownRenderer::new().render(message) {
+                                Ok(rendered) => {
+                                    div.tag-message-text.markdown-content.latte { (PreEscaped(rendered)) }
+                                }
+                                Err(_) => {
+                                    pre.tag-message-text { (message) }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                section.release-section {
+                    div.release-section-header {
+                        i.ph.ph-git-commit {}
+                        span {
+                            (commit_count) @if commit_count == 1 { " commit" } @else { " commits" }
+                            @if let Some(previous) = previous {
+                                " since "
+                                a href=(format!("{}.html", previous.name)) { (previous.name) }
+                            }
+                        }
+                    }
+                    ul.release-commit-list {
+                        @for commit in changes.commits() {
+                            li.release-commit {
+                                (commit_hash(commit.oid()))
+                                a.release-commit-message href=(format!("../commit/{}.html", commit.oid())) {
+                                    (commit.message())
+                                }
+                                (attribution(commit, "../"))
+                                span.commit-date { (format_timestamp(commit.date())) }
+                            }
+                        }
+                    }
+                }
+
+                section.release-section {
+                    div.release-section-header {
+                        i.ph.ph-files {}
+                        span {
+                            (changes.files().len())
+                            @if changes.files().len() == 1 { " file changed" } @else { " files changed" }
+                        }
+                        span.stat-added { "+" (total.added) }
+                        span.stat-removed { "−" (total.removed) }
+                    }
+                    @if !changes.files().is_empty() {
+                        ul.diff-file-list {
+                            @for file in changes.files() {
+                                li {
+                                    (change_icon(file.kind))
+                                    span.diff-file-path { (file.path) }
+                                    span.diff-file-stat {
+                                        @if let Some(stat) = file.stat {
+                                            span.stat-added { "+" (stat.added) }
+                                            span.stat-removed { "−" (stat.removed) }
+                                        } @else {
+                                            span.faint { "binary" }
+                                        }
+                                    }
+                                }
                             }
                         }
                     }
                 }
             }
 
-            div.action-bar {
+            nav.action-bar.release-nav {
+                @if let Some(previous) = previous {
+                    a.release-link href=(format!("{}.html", previous.name)) {
+                        i.ph.ph-arrow-left {}
+                        span.release-link-label { "Previous" }
+                        span.release-link-name { (previous.name) }
+                    }
+                }
+                @if let Some(next) = next {
+                    a.release-link href=(format!("{}.html", next.name)) {
+                        span.release-link-label { "Next" }
+                        span.release-link-name { (next.name) }
+                        i.ph.ph-arrow-right {}
+                    }
+                }
                 a.browse-link href=(format!("../tree/{}/index.html", tag.name)) {
                     "Browse files"
                     i.ph.ph-arrow-right {}
@@ -187,6 +372,20 @@ pub fn generate_detail(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::diff::DiffStat;
+    use crate::git::ChangeKind;
+    use crate::release::ReleaseFile;
+    use crate::signature::{Signature, Verification};
+
+    fn commit(oid: &str, message: &str, author: &str) -> CommitInfo {
+        CommitInfo::new(
+            oid.to_string(),
+            message.to_string(),
+            message.to_string(),
+            author.to_string(),
+            1234567890,
+        )
+    }
 
     #[test]
     fn test_generate_list_empty() {
@@ -245,14 +444,18 @@ mod tests {
             Some(1234567890),
         );
 
+        let commit = commit("def456abc123", "Commit message here", "Commit Author");
+
         // Act
-        let html = generate_detail(
-            "test-repo",
-            &tag,
-            "Commit message here",
-            "Commit Author",
-            1234567890,
-        );
+        let html = generate_detail(ReleasePageData {
+            repo_name: "test-repo",
+            tag: &tag,
+            commit: &commit,
+            archive: Some("test-repo-v2.0.0"),
+            changes: &ReleaseChanges::default(),
+            previous: None,
+            next: None,
+        });
 
         // Assert
         let html_str = html.into_string();
@@ -270,5 +473,192 @@ mod tests {
         assert!(html_str.contains("action-bar"), "Should have action bar");
         assert!(html_str.contains("browse-link"), "Should have browse link");
         assert!(html_str.contains("avatar"), "Should have avatar");
+        assert!(
+            html_str.contains("../commit/def456abc123.html"),
+            "Should link to commit detail page"
+        );
+        assert!(
+            html_str.contains("../archive/test-repo-v2.0.0.zip"),
+            "Should link to zip archive"
+        );
+        assert!(
+            html_str.contains("../archive/test-repo-v2.0.0.tar.gz"),
+            "Should link to tarball"
+        );
+    }
+
+    #[test]
+    fn test_signature_badges() {
+        // Arrange
+        let mut signature = Signature::parse(
+            "-----BEGIN SIGNED MESSAGE-----\nMIAG\n-----END SIGNED MESSAGE-----\n",
+        )
+        .expect("Should parse signature");
+        let mut tag = TagInfo::new(
+            "v3.0.0".to_string(),
+            "fed321cba654".to_string(),
+            Some("Signed release".to_string()),
+            Some("Tagger <tagger@example.com>".to_string()),
+            Some(1234567890),
+        );
+        tag.signature = Some(signature.clone());
+        let unchecked = tag.clone();
+        signature.set_verification(Verification::Verified("tagger@example.com".to_string()));
+        tag.signature = Some(signature);
+
+        // Act
+        let list_html = generate_list("test-repo", &[unchecked]).into_string();
+        let detail_html = generate_detail(ReleasePageData {
+            repo_name: "test-repo",
+            tag: &tag,
+            commit: &commit("fed321cba654", "Commit message", "Author"),
+            archive: None,
+            changes: &ReleaseChanges::default(),
+            previous: None,
+            next: None,
+        })
+        .into_string();
+
+        // Assert
+        assert!(list_html.contains("signature-badge signed"));
+        assert!(list_html.contains("X.509 key unknown"));
+        assert!(detail_html.contains("signature-badge verified"));
+        assert!(
+            detail_html.contains("tagger@example.com"),
+            "Tooltip should name the verified signer"
+        );
+    }
+
+    #[test]
+    fn test_release_page() {
+        // Arrange
+        let tag_at = |name: &str, oid: &str| {
+            TagInfo::new(
+                name.to_string(),
+                oid.to_string(),
+                Some("## Highlights\n\n- Faster *builds*".to_string()),
+                None,
+                Some(1234567890),
+            )
+        };
+        let (previous, tag, next) = (
+            tag_at("v1.0", &"a".repeat(40)),
+            tag_at("v1.1", &"b".repeat(40)),
+            tag_at("v1.2", &"c".repeat(40)),
+        );
+        let commits = vec![
+            commit(&"b".repeat(40), "Add c", "Alice"),
+            commit(&"d".repeat(40), "Edit a", "Bob"),
+        ];
+        let changes = ReleaseChanges::new(
+            commits.clone(),
+            vec![
+                ReleaseFile {
+                    path: "a.txt".to_string(),
+                    kind: ChangeKind::Modified,
+                    stat: Some(DiffStat {
+                        added: 2,
+                        removed: 1,
+                    }),
+                },
+                ReleaseFile {
+                    path: "logo.png".to_string(),
+                    kind: ChangeKind::Added,
+                    stat: None,
+                },
+            ],
+        );
+
+        // Act
+        let html = generate_detail(ReleasePageData {
+            repo_name: "test-repo",
+            tag: &tag,
+            commit: &commits[0],
+            archive: None,
+            changes: &changes,
+            previous: Some(&previous),
+            next: Some(&next),
+        })
+        .into_string();
+
+        // Assert
+        assert!(html.contains("../assets/markdown.css"));
+        assert!(html.contains("Highlights</h2>"));
+        assert!(html.contains("<em>builds</em>"), "Tag message is markdown");
+        assert!(html.contains(r#"2 commits since <a href="v1.0.html">v1.0</a>"#));
+        assert!(html.contains(&format!("../commit/{}.html", "d".repeat(40))));
+        assert!(html.contains("Edit a"));
+        assert!(html.contains("2 files changed"));
+        assert!(html.contains(">binary<"));
+        assert!(html.find("Previous") < html.find("Next"));
+        assert!(html.contains(r#"href="v1.2.html""#));
+    }
+
+    #[test]
+    fn test_first_release_without_neighbours() {
+        // Arrange
+        let tag = TagInfo::new("v0.1".to_string(), "a".repeat(40), None, None, None);
+        let changes =
+            ReleaseChanges::new(vec![commit(&"a".repeat(40), "Initial", "Alice")], vec![]);
+
+        // Act
+        let html = generate_detail(ReleasePageData {
+            repo_name: "test-repo",
+            tag: &tag,
+            commit: &changes.commits()[0],
+            archive: None,
+            changes: &changes,
+            previous: None,
+            next: None,
+        })
+        .into_string();
+
+        // Assert
+        assert!(html.contains("1 commit<"));
+        assert!(!html.contains("release-link"));
+        assert!(!html.contains("markdown.css"));
+    }
+
+    #[test]
+    fn test_generate_list_groups_versions() {
+        // Arrange
+        let tag = |name: &str| TagInfo::new(name.to_string(), "a".repeat(40), None, None, None);
+        let tags = vec![
+            tag("v1.3.0-rc.1"),
+            tag("v1.2.1"),
+            tag("v1.2.0"),
+            tag("v0.9.0"),
+            tag("nightly"),
+        ];
+
+        // Act
+        let html = generate_list("test-repo", &tags).into_string();
+
+        // Assert
+        let labels: Vec<&str> = html
+            .split(r#"<span class="tag-group-label">"#)
+            .skip(1)
+            .filter_map(|rest| rest.split('<').next())
+            .collect();
+        assert_eq!(labels, vec!["1.3", "1.2", "0.9", "Other tags"]);
+        assert_eq!(html.matches("release-badge prerelease").count(), 1);
+        assert_eq!(html.matches("release-badge latest").count(), 1);
+        assert!(html.find("release-badge latest") > html.find(">v1.2.1<"));
+        assert!(html.find("release-badge latest") < html.find(">v1.2.0<"));
+    }
+
+    #[test]
+    fn test_generate_list_without_versions_is_flat() {
+        let tags = vec![TagInfo::new(
+            "nightly".to_string(),
+            "a".repeat(40),
+            None,
+            None,
+            None,
+        )];
+
+        let html = generate_list("test-repo", &tags).into_string();
+
+        assert!(!html.contains("tag-group-header"));
     }
 }
@@ -4,7 +4,9 @@ use anyhow::Result;
 use maud::{Markup, html};
 use std::path::Path;
 
-use crate::components::file_list::{file_row, file_table};
+use crate::components::file_list::{
+    file_row, file_table, submodule_href, submodule_row, symlink_href, symlink_row,
+};
 use crate::components::icons::file_icon;
 use crate::components::layout::page_wrapper;
 use crate::components::nav::breadcrumb;
@@ -88,6 +90,12 @@ pub fn generate(
     };
 
     let css_path = format!("{}assets/tree.css", "../".repeat(depth));
+    let history_link = format!(
+        "{}history/{}/{}/page-1.html",
+        "../".repeat(depth),
+        ref_name,
+        tree_path
+    );
 
     Ok(page_wrapper(
         &title,
@@ -95,6 +103,18 @@ pub fn generate(
         html! {
             (breadcrumb(repo_name, &index_path, &breadcrumb_data, ref_name))
             main class="tree-container" {
+                @if !tree_path.is_empty() {
+                    div class="tree-header" {
+                        div class="tree-header-left" {
+                            i class="ph ph-folder-open" {}
+                            span class="tree-path" { (tree_path) }
+                        }
+                        a class="history-link" href=(history_link) {
+                            i class="ph ph-clock-counter-clockwise" {}
+                            " History"
+                        }
+                    }
+                }
                 @if items.is_empty() && tree_path.is_empty() {
                     p class="empty-state" { "Empty directory" }
                 } @else {
@@ -115,6 +135,7 @@ pub fn generate(
                                 &parent_href,
                                 html! { div class="icon-box" { i class="ph ph-arrow-up icon-folder" {} } },
                                 "..",
+                                false,
                                 None,
                                 ""
                             ))
@@ -138,6 +159,7 @@ pub fn generate(
                                             &href,
                                             file_icon(&display_name),
                                             &display_name,
+                                            entry.is_executable(),
                                             Some(commit),
                                             &format_timestamp(commit.date())
                                         ))
@@ -149,6 +171,31 @@ pub fn generate(
                                         &href,
                                         file_icon(&format!("{}/", full_path)),
                                         name,
+                                        false,
+                                        Some(commit),
+                                        &format_timestamp(commit.date())
+                                    ))
+                                }
+                                TreeItem::Symlink { entry, target, resolved, commit } => {
+                                    @if let Some(path) = entry.path()
+                                        && let Some(path_str) = path.to_str() {
+                                        @let display_name = path_str.rsplit('/').next().unwrap_or(path_str);
+                                        @let href = symlink_href(&"../".repeat(depth), ref_name, path_str, resolved.as_ref());
+                                        (symlink_row(
+                                            &href,
+                                            display_name,
+                                            target,
+                                            Some(commit),
+                                            &format_timestamp(commit.date())
+                                        ))
+                                    }
+                                }
+                                TreeItem::Submodule { name, full_path, oid, url, has_site, commit } => {
+                                    @let href = submodule_href(&"../".repeat(depth), full_path, oid, url.as_deref(), *has_site);
+                                    (submodule_row(
+                                        href.as_deref(),
+                                        name,
+                                        oid,
                                         Some(commit),
                                         &format_timestamp(commit.date())
                                     ))
@@ -165,7 +212,7 @@ pub fn generate(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{CommitInfo, list_files};
+    use crate::{CommitInfo, FileEntry, SymlinkTarget, list_files};
 
     #[test]
     fn test_generate_empty() {
@@ -235,4 +282,108 @@ mod tests {
         assert!(html_str.contains("test-repo"));
         assert!(html_str.contains("file.txt"));
     }
+
+    #[test]
+    fn test_generate_subdirectory_links_history() {
+        let items: Vec<TreeItem> = vec![];
+        let html = generate(Path::new("."), "main", "src/pages", "test-repo", &items).unwrap();
+
+        let html_str = html.into_string();
+        assert!(html_str.contains("tree-header"));
+        assert!(html_str.contains("../../../history/main/src/pages/page-1.html"));
+    }
+
+    #[test]
+    fn test_generate_submodule_rows() {
+        let commit = CommitInfo::new(
+            "abc123".into(),
+            "Bump lib".into(),
+            "Bump lib".into(),
+            "Test".into(),
+            1234567890,
+        );
+        let items = vec![
+            TreeItem::Submodule {
+                name: "lib".into(),
+                full_path: "vendor/lib".into(),
+                oid: "0123456789abcdef0123456789abcdef01234567".into(),
+                url: Some("git@github.com:owner/lib.git".into()),
+                has_site: false,
+                commit: commit.clone(),
+            },
+            TreeItem::Submodule {
+                name: "local".into(),
+                full_path: "vendor/local".into(),
+                oid: "89abcdef0123456789abcdef0123456789abcdef".into(),
+                url: Some("../local.git".into()),
+                has_site: false,
+                commit,
+            },
+        ];
+
+        let html = generate(Path::new("."), "main", "vendor", "test-repo", &items).unwrap();
+
+        let html_str = html.into_string();
+        assert!(html_str.contains("icon-submodule"));
+        assert!(html_str.contains("@ 0123456"));
+        assert!(html_str.contains(r#"href="https://github.com/owner/lib""#));
+        assert!(
+            html_str.contains(r#"<div class="file-row submodule-row">"#),
+            "Relative remotes are not linked"
+        );
+    }
+
+    #[test]
+    fn test_generate_symlink_and_executable_rows() {
+        let commit = CommitInfo::new(
+            "abc123".into(),
+            "Add tools".into(),
+            "Add tools".into(),
+            "Test".into(),
+            1234567890,
+        );
+        let null = gix::ObjectId::null(gix::hash::Kind::Sha1);
+        let items = vec![
+            TreeItem::Symlink {
+                entry: FileEntry::new(
+                    "tools/docs",
+                    null,
+                    gix::object::tree::EntryKind::Link.into(),
+                ),
+                target: "../docs".into(),
+                resolved: Some(SymlinkTarget::Directory("docs".into())),
+                commit: commit.clone(),
+            },
+            TreeItem::Symlink {
+                entry: FileEntry::new(
+                    "tools/hosts",
+                    null,
+                    gix::object::tree::EntryKind::Link.into(),
+                ),
+                target: "/etc/hosts".into(),
+                resolved: None,
+                commit: commit.clone(),
+            },
+            TreeItem::File {
+                entry: FileEntry::new(
+                    "tools/run.sh",
+                    null,
+                    gix::object::tree::EntryKind::BlobExecutable.into(),
+                ),
+                commit,
+            },
+        ];
+
+        let html = generate(Path::new("."), "main", "tools", "test-repo", &items).unwrap();
+
+        let html_str = html.into_string();
+        assert!(html_str.contains("icon-symlink"));
+        assert!(html_str.contains("→ ../docs"));
+        assert!(html_str.contains(r#"href="../../tree/main/docs.html""#));
+        assert!(
+            html_str.contains(r#"href="../../blob/main/tools/hosts.html""#),
+            "Unresolved symlinks link to their own page"
+        );
+        assert_eq!(html_str.matches("Executable file").count(), 1);
+    }
 }
@@ -0,0 +1,161 @@
+//! Worker pool for parallel page generation.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// Applies a function to every item using a pool of worker threads.
+///
+/// Workers pull the next unprocessed item from a shared counter, so long
+/// running jobs do not hold up the rest of the queue. Results are returned
+/// in input order regardless of completion order, which keeps anything
+/// derived from them identical to a serial run. With a single job, or at
+/// most one item, everything runs on the calling thread.
+///
+/// # Arguments
+///
+/// * `items`: Inputs to process
+/// * `jobs`: Maximum number of worker threads
+/// * `f`: Function applied to each item
+///
+/// # Returns
+///
+/// One result per item, in the same order as `items`
+///
+/// # Panics
+///
+/// Propagates panics raised by `f` after all workers have stopped
+///
+/// # Examples
+///
+/// ```
+/// use gitkyl::parallel_map;
+///
+/// let squares = parallel_map(&[1, 2, 3, 4], 2, |n| n * n);
+/// assert_eq!(squares, vec![1, 4, 9, 16]);
+/// ```
+pub fn parallel_map<T, R, F>(items: &[T], jobs: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let workers = jobs.min(items.len());
+    if workers <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+
+    let mut indexed: Vec<(usize, R)> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let idx = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(idx) else {
+                            break;
+                        };
+                        done.push((idx, f(item)));
+                    }
+                    done
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|e| std::panic::resume_unwind(e))
+            })
+            .collect()
+    });
+
+    indexed.sort_by_key(|(idx, _)| *idx);
+    indexed.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Returns the number of threads to use when none is configured.
+///
+/// Falls back to a single thread if the available parallelism cannot be
+/// determined.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::thread::ThreadId;
+
+    #[test]
+    fn test_preserves_input_order() {
+        // Arrange
+        let items: Vec<usize> = (0..200).collect();
+
+        // Act
+        let results = parallel_map(&items, 8, |n| n * 2);
+
+        // Assert
+        let expected: Vec<usize> = items.iter().map(|n| n * 2).collect();
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    fn test_single_job_runs_on_caller() {
+        // Arrange
+        let caller = thread::current().id();
+        let items = [1, 2, 3];
+
+        // Act
+        let threads = parallel_map(&items, 1, |_| thread::current().id());
+
+        // Assert
+        assert!(threads.iter().all(|id| *id == caller));
+    }
+
+    #[test]
+    fn test_uses_multiple_workers() {
+        // Arrange
+        let items: Vec<usize> = (0..64).collect();
+        let seen: Mutex<Vec<ThreadId>> = Mutex::new(Vec::new());
+
+        // Act
+        parallel_map(&items, 4, |_| {
+            thread::sleep(std::time::Duration::from_millis(1));
+            seen.lock().unwrap().push(thread::current().id());
+        });
+
+        // Assert
+        let mut ids = seen.into_inner().unwrap();
+        ids.sort_by_key(|id| format!("{:?}", id));
+        ids.dedup();
+        assert!(ids.len() > 1, "Work should spread across threads");
+    }
+
+    #[test]
+    fn test_empty_input() {
+        // Arrange
+        let items: Vec<u8> = vec![];
+
+        // Act
+        let results = parallel_map(&items, 4, |n| *n);
+
+        // Assert
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_default_jobs_is_positive() {
+        // Act
+        let jobs = default_jobs();
+
+        // Assert
+        assert!(jobs >= 1);
+    }
+}
@@ -0,0 +1,209 @@
+//! Selection of the branches and tags a site publishes.
+//!
+//! Branches and tags are kept when they match an include pattern (or no
+//! include patterns are given) and match no exclude pattern. Patterns use
+//! the glob syntax of `.gitignore`, so `feature/*` matches one level of
+//! branch names and `release/**` any depth. Tags can further be limited to
+//! the most recent ones.
+
+use std::collections::HashSet;
+
+use crate::git::TagInfo;
+use crate::glob::glob_match;
+
+/// Include and exclude rules for branches and tags.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RefFilter {
+    include_branches: Vec<String>,
+    exclude_branches: Vec<String>,
+    include_tags: Vec<String>,
+    exclude_tags: Vec<String>,
+    max_tags: Option<usize>,
+}
+
+impl RefFilter {
+    /// Sets the glob patterns selecting branches.
+    ///
+    /// # Arguments
+    ///
+    /// * `include`: Branches to keep, all when empty
+    /// * `exclude`: Branches to drop, even when included
+    pub fn with_branches(mut self, include: Vec<String>, exclude: Vec<String>) -> Self {
+        self.include_branches = include;
+        self.exclude_branches = exclude;
+        self
+    }
+
+    /// Sets the glob patterns selecting tags.
+    ///
+    /// # Arguments
+    ///
+    /// * `include`: Tags to keep, all when empty
+    /// * `exclude`: Tags to drop, even when included
+    pub fn with_tags(mut self, include: Vec<String>, exclude: Vec<String>) -> Self {
+        self.include_tags = include;
+        self.exclude_tags = exclude;
+        self
+    }
+
+    /// Keeps only the most recent tags among those matching the patterns.
+    pub fn with_max_tags(mut self, max_tags: Option<usize>) -> Self {
+        self.max_tags = max_tags;
+        self
+    }
+
+    /// Checks whether a branch matches the branch patterns.
+    pub fn keeps_branch(&self, name: &str) -> bool {
+        is_selected(name, &self.include_branches, &self.exclude_branches)
+    }
+
+    /// Checks whether a tag matches the tag patterns.
+    ///
+    /// The tag count limit is not applied, see `filter_tags`.
+    pub fn keeps_tag(&self, name: &str) -> bool {
+        is_selected(name, &self.include_tags, &self.exclude_tags)
+    }
+
+    /// Selects the published branches.
+    ///
+    /// The default branch is always kept, as the site's home page shows it.
+    ///
+    /// # Arguments
+    ///
+    /// * `branches`: All branch names
+    /// * `default_branch`: Name of the default branch
+    pub fn filter_branches(&self, branches: &[String], default_branch: &str) -> Vec<String> {
+        branches
+            .iter()
+            .filter(|branch| *branch == default_branch || self.keeps_branch(branch))
+            .cloned()
+            .collect()
+    }
+
+    /// Selects the published tags.
+    ///
+    /// The tag count limit keeps the most recent tags by date, whatever
+    /// order the tags are listed in. Undated tags count as the oldest.
+    ///
+    /// # Arguments
+    ///
+    /// * `tags`: All tags, in the order returned by `list_tags`
+    ///
+    /// # Returns
+    ///
+    /// Matching tags in the same order, at most `max_tags` of them
+    pub fn filter_tags(&self, tags: Vec<TagInfo>) -> Vec<TagInfo> {
+        let mut tags: Vec<TagInfo> = tags
+            .into_iter()
+            .filter(|tag| self.keeps_tag(&tag.name))
+            .collect();
+
+        if let Some(max_tags) = self.max_tags
+            && tags.len() > max_tags
+        {
+            let mut by_date: Vec<&TagInfo> = tags.iter().collect();
+            by_date.sort_by(|a, b| {
+                b.date
+                    .is_some()
+                    .cmp(&a.date.is_some())
+                    .then_with(|| b.date.cmp(&a.date))
+                    .then_with(|| a.name.cmp(&b.name))
+            });
+            let recent: HashSet<String> = by_date
+                .into_iter()
+                .take(max_tags)
+                .map(|tag| tag.name.clone())
+                .collect();
+            tags.retain(|tag| recent.contains(&tag.name));
+        }
+
+        tags
+    }
+}
+
+/// Applies include and exclude patterns to a ref name.
+fn is_selected(name: &str, include: &[String], exclude: &[String]) -> bool {
+    (include.is_empty() || include.iter().any(|pattern| glob_match(pattern, name)))
+        && !exclude.iter().any(|pattern| glob_match(pattern, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    fn tag(name: &str, date: i64) -> TagInfo {
+        TagInfo::new(name.to_string(), "a".repeat(40), None, None, Some(date))
+    }
+
+    #[test]
+    fn test_default_filter_keeps_everything() {
+        let filter = RefFilter::default();
+
+        assert!(filter.keeps_branch("feature/x"));
+        assert!(filter.keeps_tag("nightly-2024-01-01"));
+    }
+
+    #[test]
+    fn test_filter_branches_applies_patterns() {
+        // Arrange
+        let filter = RefFilter::default()
+            .with_branches(names(&["main", "release/**"]), names(&["release/old/*"]));
+        let branches = names(&[
+            "develop",
+            "feature/login",
+            "main",
+            "release/1.0",
+            "release/old/0.9",
+        ]);
+
+        // Act
+        let kept = filter.filter_branches(&branches, "develop");
+
+        // Assert
+        assert_eq!(kept, names(&["develop", "main", "release/1.0"]));
+    }
+
+    #[test]
+    fn test_filter_tags_keeps_most_recent() {
+        // Arrange
+        let filter = RefFilter::default()
+            .with_tags(vec![], names(&["nightly-*"]))
+            .with_max_tags(Some(2));
+        let tags = vec![
+            tag("nightly-3", 40),
+            tag("v1.2", 30),
+            tag("v1.1", 20),
+            tag("v1.0", 10),
+        ];
+
+        // Act
+        let kept = filter.filter_tags(tags);
+
+        // Assert
+        let kept: Vec<&str> = kept.iter().map(|tag| tag.name.as_str()).collect();
+        assert_eq!(kept, vec!["v1.2", "v1.1"]);
+    }
+
+    #[test]
+    fn test_filter_tags_limit_uses_dates_not_versions() {
+        // Arrange: a maintenance release tagged after the next major one
+        let filter = RefFilter::default().with_max_tags(Some(2));
+        let tags = vec![
+            tag("v2.0.0", 20),
+            tag("v1.9.1", 40),
+            tag("v1.9.0", 30),
+            tag("v1.0.0", 10),
+        ];
+
+        // Act
+        let kept = filter.filter_tags(tags);
+
+        // Assert: listing order is kept, the oldest tags are dropped
+        let kept: Vec<&str> = kept.iter().map(|tag| tag.name.as_str()).collect();
+        assert_eq!(kept, vec!["v1.9.1", "v1.9.0"]);
+    }
+}
@@ -0,0 +1,343 @@
+//! Changes shipped by a release.
+//!
+//! A release is a tag compared with the tag published before it: the
+//! commits reachable from the tag but not from the previous one, as listed
+//! by `git log previous..tag`, and the files that differ between the two
+//! trees. The first release is compared with the empty tree.
+
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::contributors::change_stat;
+use crate::diff::DiffStat;
+use crate::git::{
+    ChangeKind, CommitInfo, TagInfo, diff_trees, extract_commit_info, read_file_change,
+    resolve_commit,
+};
+use crate::mailmap::Mailmap;
+
+/// File changed by a release.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseFile {
+    /// File path relative to repository root
+    pub path: String,
+    /// Kind of change since the previous release
+    pub kind: ChangeKind,
+    /// Lines added and removed, None for binary files
+    pub stat: Option<DiffStat>,
+}
+
+/// Commits and file changes of a release since the previous one.
+#[derive(Debug, Clone, Default)]
+pub struct ReleaseChanges {
+    commits: Vec<CommitInfo>,
+    files: Vec<ReleaseFile>,
+}
+
+impl ReleaseChanges {
+    /// Creates release changes from commits and changed files.
+    pub(crate) fn new(commits: Vec<CommitInfo>, files: Vec<ReleaseFile>) -> Self {
+        Self { commits, files }
+    }
+
+    /// Commits new in the release, newest first.
+    pub fn commits(&self) -> &[CommitInfo] {
+        &self.commits
+    }
+
+    /// Changed files, sorted by path.
+    pub fn files(&self) -> &[ReleaseFile] {
+        &self.files
+    }
+
+    /// Lines added and removed across all text files.
+    pub fn total(&self) -> DiffStat {
+        self.files
+            .iter()
+            .filter_map(|file| file.stat)
+            .fold(DiffStat::default(), |acc, stat| DiffStat {
+                added: acc.added + stat.added,
+                removed: acc.removed + stat.removed,
+            })
+    }
+}
+
+/// Picks the tag shown as the latest release.
+///
+/// The highest stable version wins. Pre-releases and tags that are not
+/// versions are only picked when no stable version is published.
+///
+/// # Arguments
+///
+/// * `tags`: Published tags, newest first as returned by `list_tags`
+///
+/// # Returns
+///
+/// Latest release, or None when there are no tags
+pub fn latest_release(tags: &[TagInfo]) -> Option<&TagInfo> {
+    tags.iter()
+        .find(|tag| tag.version.as_ref().is_some_and(|v| !v.is_prerelease()))
+        .or_else(|| tags.first())
+}
+
+/// Compares a tag with the previous release.
+///
+/// # Arguments
+///
+/// * `repo_path`: Path to git repository
+/// * `tag`: Tag of the release
+/// * `previous`: Tag of the previous release, None for the first one
+/// * `mailmap`: Mailmap applied to commit identities
+///
+/// # Returns
+///
+/// Commits and changed files of the release
+///
+/// # Errors
+///
+/// Returns error if either tag cannot be resolved to a commit, or if the
+/// history or trees cannot be read
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::{Mailmap, release_changes};
+/// use std::path::Path;
+///
+/// let changes = release_changes(Path::new("."), "v1.1.0", Some("v1.0.0"), &Mailmap::default())?;
+/// let total = changes.total();
+/// println!(
+///     "{} commits, {} files, +{} -{}",
+///     changes.commits().len(),
+///     changes.files().len(),
+///     total.added,
+///     total.removed
+/// );
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn release_changes(
+    repo_path: impl AsRef<Path>,
+    tag: &str,
+    previous: Option<&str>,
+    mailmap: &Mailmap,
+) -> Result<ReleaseChanges> {
+    let repo_path = repo_path.as_ref();
+    let repo = gix::open(repo_path)
+        .with_context(|| format!("Failed to open repository at {}", repo_path.display()))?;
+
+    let tip = resolve_commit(&repo, Some(tag))?;
+    let base = previous
+        .map(|previous| resolve_commit(&repo, Some(previous)))
+        .transpose()?;
+    let commits = new_commits(&repo, tip.id, base.as_ref().map(|base| base.id), mailmap)?;
+
+    let new_tree = tip.tree().context("Failed to read release tree")?;
+    let old_tree = match &base {
+        Some(base) => Some(
+            base.tree()
+                .context("Failed to read previous release tree")?,
+        ),
+        None => None,
+    };
+
+    // Read one file at a time: the first release adds every file in the tree
+    let mut files = diff_trees(&repo, old_tree.as_ref(), &new_tree)?
+        .into_iter()
+        .map(|change| {
+            let change = read_file_change(&repo, change)?;
+            Ok(ReleaseFile {
+                stat: change_stat(&change),
+                path: change.path,
+                kind: change.kind,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(ReleaseChanges::new(commits, files))
+}
+
+/// Lists the commits reachable from a release but not from the previous one.
+///
+/// Like `git log end..tip`, the walk stops where the histories meet instead
+/// of listing both of them in full.
+///
+/// # Arguments
+///
+/// * `repo`: Open repository handle
+/// * `tip`: Commit of the release
+/// * `end`: Commit of the previous release, None to list the whole history
+/// * `mailmap`: Mailmap applied to commit identities
+///
+/// # Returns
+///
+/// New commits, newest first
+///
+/// # Errors
+///
+/// Returns error if the history cannot be traversed or a commit cannot be read
+fn new_commits(
+    repo: &gix::Repository,
+    tip: gix::ObjectId,
+    end: Option<gix::ObjectId>,
+    mailmap: &Mailmap,
+) -> Result<Vec<CommitInfo>> {
+    use gix::traverse::commit::topo;
+
+    let walker = topo::Builder::from_iters(&repo.objects, [tip], end.map(|end| [end]))
+        .sorting(topo::Sorting::DateOrder)
+        .build()
+        .context("Failed to create release commit walk")?;
+
+    let mut commits = Vec::new();
+    for result in walker {
+        let info = result.context("Failed to traverse release history")?;
+        let commit = repo
+            .find_object(info.id)
+            .context("Failed to read commit object")?
+            .try_into_commit()
+            .context("Object is not a commit")?;
+        commits.push(extract_commit_info(&commit, mailmap)?);
+    }
+
+    Ok(commits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latest_release_skips_prereleases() {
+        let tag = |name: &str| TagInfo::new(name.to_string(), "a".repeat(40), None, None, None);
+        let tags = vec![tag("v2.0.0-rc.1"), tag("v1.4.2"), tag("nightly")];
+        let unversioned = vec![tag("nightly"), tag("snapshot")];
+
+        assert_eq!(
+            latest_release(&tags).map(|t| t.name.as_str()),
+            Some("v1.4.2")
+        );
+        assert_eq!(
+            latest_release(&tags[..1]).map(|t| t.name.as_str()),
+            Some("v2.0.0-rc.1")
+        );
+        assert_eq!(
+            latest_release(&unversioned).map(|t| t.name.as_str()),
+            Some("nightly")
+        );
+        assert!(latest_release(&[]).is_none());
+    }
+
+    #[test]
+    fn test_release_changes_since_previous_tag() {
+        // Arrange
+        let td = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let repo = td.path();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(repo)
+                .output()
+                .expect("Failed to run git");
+            assert!(output.status.success(), "git {:?} failed", args);
+        };
+        git(&["init"]);
+        git(&["config", "user.name", "Test"]);
+        git(&["config", "user.email", "test@test.com"]);
+        std::fs::write(repo.join("a.txt"), "one\ntwo\n").unwrap();
+        std::fs::write(repo.join("b.bin"), [0u8, 1, 2]).unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-m", "Initial"]);
+        git(&["tag", "-a", "v1.0", "-m", "First release"]);
+        std::fs::write(repo.join("a.txt"), "one\n2\nthree\n").unwrap();
+        git(&["commit", "-am", "Edit a"]);
+        std::fs::write(repo.join("c.txt"), "new\n").unwrap();
+        std::fs::remove_file(repo.join("b.bin")).unwrap();
+        git(&["add", "-A"]);
+        git(&["commit", "-m", "Add c, drop b"]);
+        git(&["tag", "v1.1"]);
+
+        // Act
+        let first = release_changes(repo, "v1.0", None, &Mailmap::default())
+            .expect("Should compare first release");
+        let second = release_changes(repo, "v1.1", Some("v1.0"), &Mailmap::default())
+            .expect("Should compare releases");
+
+        // Assert
+        assert_eq!(first.commits().len(), 1);
+        assert_eq!(first.files().len(), 2);
+
+        let messages: Vec<&str> = second.commits().iter().map(|c| c.message()).collect();
+        assert_eq!(messages, vec!["Add c, drop b", "Edit a"]);
+        let files: Vec<(&str, ChangeKind, Option<DiffStat>)> = second
+            .files()
+            .iter()
+            .map(|file| (file.path.as_str(), file.kind, file.stat))
+            .collect();
+        assert_eq!(
+            files,
+            vec![
+                (
+                    "a.txt",
+                    ChangeKind::Modified,
+                    Some(DiffStat {
+                        added: 2,
+                        removed: 1
+                    })
+                ),
+                ("b.bin", ChangeKind::Deleted, None),
+                (
+                    "c.txt",
+                    ChangeKind::Added,
+                    Some(DiffStat {
+                        added: 1,
+                        removed: 0
+                    })
+                ),
+            ]
+        );
+        assert_eq!(
+            second.total(),
+            DiffStat {
+                added: 3,
+                removed: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_release_changes_include_merged_branches() {
+        // Arrange
+        let td = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let repo = td.path();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(repo)
+                .output()
+                .expect("Failed to run git");
+            assert!(output.status.success(), "git {:?} failed", args);
+        };
+        git(&["init", "-b", "main"]);
+        git(&["config", "user.name", "Test"]);
+        git(&["config", "user.email", "test@test.com"]);
+        git(&["commit", "--allow-empty", "-m", "Initial"]);
+        git(&["checkout", "-b", "feature"]);
+        git(&["commit", "--allow-empty", "-m", "Feature"]);
+        git(&["checkout", "main"]);
+        git(&["commit", "--allow-empty", "-m", "Fix"]);
+        git(&["tag", "v1.0"]);
+        git(&["merge", "--no-ff", "feature", "-m", "Merge feature"]);
+        git(&["tag", "v1.1"]);
+
+        // Act
+        let changes = release_changes(repo, "v1.1", Some("v1.0"), &Mailmap::default())
+            .expect("Should compare releases");
+
+        // Assert
+        let mut messages: Vec<&str> = changes.commits().iter().map(|c| c.message()).collect();
+        messages.sort_unstable();
+        assert_eq!(messages, vec!["Feature", "Merge feature"]);
+        assert!(changes.files().is_empty());
+    }
+}
@@ -0,0 +1,294 @@
+//! Repository discovery for multi-repository sites.
+//!
+//! Scans a directory for git repositories, bare or with a working tree, and
+//! collects the metadata shown on the landing page. Per-repository details
+//! follow the gitweb conventions also understood by cgit: the `description`
+//! file or `gitweb.description`, `gitweb.owner` and `gitweb.category`.
+
+use anyhow::{Context, Result};
+use gix::bstr::ByteSlice;
+use std::path::{Path, PathBuf};
+
+use crate::git::{analyze_repository, list_commits, list_tags};
+use crate::ref_filter::RefFilter;
+
+/// Placeholder description written by `git init`.
+const DEFAULT_DESCRIPTION: &str = "Unnamed repository;";
+
+/// Repository listed on the multi-repository index.
+#[derive(Debug, Clone)]
+pub struct RepoSummary {
+    /// Display name (directory name without a `.git` suffix)
+    pub name: String,
+    /// Path relative to the scanned directory, used as the site subdirectory
+    pub site_path: String,
+    /// Section grouping the repository on the index
+    pub section: Option<String>,
+    /// Short description
+    pub description: Option<String>,
+    /// Repository owner
+    pub owner: Option<String>,
+    /// Default branch name
+    pub default_branch: String,
+    /// Date of the latest commit on the default branch (Unix timestamp)
+    pub last_commit: Option<i64>,
+    /// Number of tags
+    pub tag_count: usize,
+}
+
+/// Finds git repositories below a directory.
+///
+/// Repositories are not searched for nested repositories, and hidden
+/// directories are skipped. Symbolic links are not followed.
+///
+/// # Arguments
+///
+/// * `root`: Directory to scan
+///
+/// # Returns
+///
+/// Repository paths sorted by path
+///
+/// # Errors
+///
+/// Returns error if `root` cannot be read
+///
+/// # Examples
+///
+/// ```no_run
+/// use gitkyl::discover_repositories;
+/// use std::path::Path;
+///
+/// for repo in discover_repositories(Path::new("/srv/git"))? {
+///     println!("{}", repo.display());
+/// }
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn discover_repositories(root: impl AsRef<Path>) -> Result<Vec<PathBuf>> {
+    let mut repositories = Vec::new();
+    let mut pending = vec![root.as_ref().to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        let entries = std::fs::read_dir(&dir)
+            .with_context(|| format!("Failed to read directory {}", dir.display()))?;
+
+        for entry in entries.flatten() {
+            let is_dir = entry.file_type().map(|t| t.is_dir()).unwrap_or(false);
+            let hidden = entry.file_name().to_string_lossy().starts_with('.');
+            if !is_dir || hidden {
+                continue;
+            }
+
+            let path = entry.path();
+            if is_repository(&path) {
+                repositories.push(path);
+            } else {
+                pending.push(path);
+            }
+        }
+    }
+
+    repositories.sort();
+    Ok(repositories)
+}
+
+/// Collects landing page metadata for a discovered repository.
+///
+/// The section comes from `gitweb.category`, falling back to the directory
+/// containing the repository relative to `root`.
+///
+/// # Arguments
+///
+/// * `root`: Scanned directory the repository was found in
+/// * `repo_path`: Repository path
+/// * `default_owner`: Owner used when the repository sets no `gitweb.owner`
+/// * `ref_filter`: Rules selecting the published tags, so the tag count
+///   matches the repository's site
+///
+/// # Errors
+///
+/// Returns error if the repository cannot be opened or has no commits
+pub fn summarize_repository(
+    root: impl AsRef<Path>,
+    repo_path: impl AsRef<Path>,
+    default_owner: Option<&str>,
+    ref_filter: &RefFilter,
+) -> Result<RepoSummary> {
+    let repo_path = repo_path.as_ref();
+    let repo = gix::open(repo_path)
+        .with_context(|| format!("Failed to open repository at {}", repo_path.display()))?;
+
+    let config = repo.config_snapshot();
+    let config_value = |key: &str| {
+        config
+            .string(key)
+            .map(|v| v.to_str_lossy().trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+
+    let description = config_value("gitweb.description").or_else(|| {
+        std::fs::read_to_string(repo.git_dir().join("description"))
+            .ok()
+            .map(|d| d.trim().to_string())
+            .filter(|d| !d.is_empty() && !d.starts_with(DEFAULT_DESCRIPTION))
+    });
+    let owner = config_value("gitweb.owner").or_else(|| default_owner.map(str::to_string));
+
+    let relative = repo_path
+        .strip_prefix(root.as_ref())
+        .unwrap_or(repo_path)
+        .to_string_lossy()
+        .replace('\\', "/");
+    let site_path = relative
+        .strip_suffix(".git")
+        .unwrap_or(&relative)
+        .to_string();
+    let (parent, name) = match site_path.rsplit_once('/') {
+        Some((parent, name)) => (Some(parent.to_string()), name.to_string()),
+        None => (None, site_path.clone()),
+    };
+    let section = config_value("gitweb.category").or(parent);
+
+    let info = analyze_repository(repo_path, owner.clone())?;
+    let last_commit = list_commits(repo_path, Some(info.default_branch()), Some(1))
+        .ok()
+        .and_then(|commits| commits.first().map(|c| c.date()));
+    let tag_count = list_tags(repo_path)
+        .map(|tags| ref_filter.filter_tags(tags).len())
+        .unwrap_or(0);
+
+    Ok(RepoSummary {
+        name,
+        site_path,
+        section,
+        description,
+        owner,
+        default_branch: info.default_branch().to_string(),
+        last_commit,
+        tag_count,
+    })
+}
+
+/// Returns true if a directory is a working tree or a bare repository.
+fn is_repository(path: &Path) -> bool {
+    path.join(".git").exists()
+        || (path.join("HEAD").is_file()
+            && path.join("objects").is_dir()
+            && path.join("refs").is_dir())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("Failed to run git");
+        assert!(output.status.success(), "git {:?} failed", args);
+    }
+
+    fn init_repo(path: &Path) {
+        std::fs::create_dir_all(path).unwrap();
+        git(path, &["init"]);
+        git(path, &["config", "user.name", "Test"]);
+        git(path, &["config", "user.email", "test@test.com"]);
+        std::fs::write(path.join("README.md"), "# Test\n").unwrap();
+        git(path, &["add", "."]);
+        git(path, &["commit", "-m", "Initial commit"]);
+    }
+
+    #[test]
+    fn test_discover_repositories() {
+        // Arrange
+        let td = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let root = td.path();
+        init_repo(&root.join("alpha"));
+        init_repo(&root.join("tools/beta"));
+        init_repo(&root.join(".hidden/gamma"));
+        std::fs::create_dir_all(root.join("empty-dir")).unwrap();
+        git(
+            root,
+            &["clone", "--bare", "alpha", "mirrors/alpha-mirror.git"],
+        );
+
+        // Act
+        let found = discover_repositories(root).expect("Should scan directory");
+
+        // Assert
+        let relative: Vec<String> = found
+            .iter()
+            .map(|p| p.strip_prefix(root).unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(
+            relative,
+            vec!["alpha", "mirrors/alpha-mirror.git", "tools/beta"]
+        );
+    }
+
+    #[test]
+    fn test_summarize_repository_metadata() {
+        // Arrange
+        let td = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let root = td.path();
+        init_repo(&root.join("alpha"));
+        git(&root.join("alpha"), &["tag", "v1.0.0"]);
+        git(
+            root,
+            &["clone", "--bare", "alpha", "mirrors/alpha-mirror.git"],
+        );
+        let bare = root.join("mirrors/alpha-mirror.git");
+        std::fs::write(bare.join("description"), "Mirror of alpha\n").unwrap();
+        git(&bare, &["config", "gitweb.owner", "Mirror Team"]);
+
+        // Act
+        let filter = RefFilter::default();
+        let plain = summarize_repository(root, root.join("alpha"), Some("Default Owner"), &filter)
+            .expect("Should summarize repository");
+        let mirror = summarize_repository(root, &bare, Some("Default Owner"), &filter)
+            .expect("Should summarize bare repository");
+        let unpublished = RefFilter::default().with_tags(vec![], vec!["v*".to_string()]);
+        let filtered = summarize_repository(root, root.join("alpha"), None, &unpublished)
+            .expect("Should summarize filtered repository");
+
+        // Assert
+        assert_eq!(plain.name, "alpha");
+        assert_eq!(plain.site_path, "alpha");
+        assert_eq!(plain.section, None);
+        assert_eq!(plain.description, None, "git init placeholder is ignored");
+        assert_eq!(plain.owner.as_deref(), Some("Default Owner"));
+        assert_eq!(plain.tag_count, 1);
+        assert_eq!(filtered.tag_count, 0, "Excluded tags are not counted");
+        assert!(plain.last_commit.is_some());
+
+        assert_eq!(mirror.name, "alpha-mirror");
+        assert_eq!(mirror.site_path, "mirrors/alpha-mirror");
+        assert_eq!(mirror.section.as_deref(), Some("mirrors"));
+        assert_eq!(mirror.description.as_deref(), Some("Mirror of alpha"));
+        assert_eq!(mirror.owner.as_deref(), Some("Mirror Team"));
+    }
+
+    #[test]
+    fn test_summarize_repository_category_overrides_directory() {
+        // Arrange
+        let td = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let root = td.path();
+        init_repo(&root.join("tools/beta"));
+        git(
+            &root.join("tools/beta"),
+            &["config", "gitweb.category", "Libraries"],
+        );
+
+        // Act
+        let summary =
+            summarize_repository(root, root.join("tools/beta"), None, &RefFilter::default())
+                .expect("Should summarize");
+
+        // Assert
+        assert_eq!(summary.section.as_deref(), Some("Libraries"));
+        assert_eq!(summary.site_path, "tools/beta");
+    }
+}
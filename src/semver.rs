@@ -0,0 +1,264 @@
+//! Semantic version parsing of tag names.
+//!
+//! Tags such as `v1.4.0` or `2.0.0-rc.1` are read as semantic versions so
+//! releases sort by version rather than by date, which lightweight tags
+//! pointing at old commits would otherwise scramble. Partial versions like
+//! `v1.4` are accepted with missing components read as zero, and build
+//! metadata after `+` is ignored, as it is for precedence in the spec. A
+//! bare number needs the `v` prefix, so date tags like `20240101` are not
+//! taken for versions.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::git::TagInfo;
+
+/// Dot separated part of a pre-release label.
+///
+/// Variant order gives the spec's precedence: numeric identifiers compare
+/// numerically and rank below alphanumeric ones, which compare as text.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Identifier {
+    Numeric(u64),
+    Alphanumeric(String),
+}
+
+/// Semantic version parsed from a tag name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    major: u64,
+    minor: u64,
+    patch: u64,
+    pre: Vec<Identifier>,
+}
+
+impl Version {
+    /// Parses a tag name as a semantic version.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: Tag name, prefixed with `v` or holding at least `major.minor`
+    ///
+    /// # Returns
+    ///
+    /// Parsed version, or None when the name is not a version
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gitkyl::Version;
+    ///
+    /// let version = Version::parse("v2.1.0-rc.1").unwrap();
+    /// assert_eq!((version.major(), version.minor()), (2, 1));
+    /// assert!(version.is_prerelease());
+    /// assert!(Version::parse("nightly").is_none());
+    /// assert!(Version::parse("20240101").is_none());
+    /// ```
+    pub fn parse(name: &str) -> Option<Self> {
+        let (prefixed, version) = match name.strip_prefix(['v', 'V']) {
+            Some(version) => (true, version),
+            None => (false, name),
+        };
+        let version = version
+            .split_once('+')
+            .map_or(version, |(version, _)| version);
+        let (core, pre) = match version.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (version, None),
+        };
+
+        let parts: Vec<&str> = core.split('.').collect();
+        if parts.len() > 3 || (parts.len() < 2 && !prefixed) {
+            return None;
+        }
+        let mut numbers = [0u64; 3];
+        for (idx, part) in parts.into_iter().enumerate() {
+            if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            numbers[idx] = part.parse().ok()?;
+        }
+
+        let pre = match pre {
+            Some(pre) => pre
+                .split('.')
+                .map(|part| match part {
+                    "" => None,
+                    _ if part.bytes().all(|b| b.is_ascii_digit()) => {
+                        part.parse().ok().map(Identifier::Numeric)
+                    }
+                    _ => Some(Identifier::Alphanumeric(part.to_string())),
+                })
+                .collect::<Option<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+
+        Some(Self {
+            major: numbers[0],
+            minor: numbers[1],
+            patch: numbers[2],
+            pre,
+        })
+    }
+
+    /// Major version number.
+    pub fn major(&self) -> u64 {
+        self.major
+    }
+
+    /// Minor version number.
+    pub fn minor(&self) -> u64 {
+        self.minor
+    }
+
+    /// Patch version number.
+    pub fn patch(&self) -> u64 {
+        self.patch
+    }
+
+    /// Checks whether the version carries a pre-release label.
+    pub fn is_prerelease(&self) -> bool {
+        !self.pre.is_empty()
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (self.pre.is_empty(), other.pre.is_empty()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => self.pre.cmp(&other.pre),
+            })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        for (idx, identifier) in self.pre.iter().enumerate() {
+            f.write_str(if idx == 0 { "-" } else { "." })?;
+            match identifier {
+                Identifier::Numeric(number) => write!(f, "{}", number)?,
+                Identifier::Alphanumeric(text) => f.write_str(text)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Orders tags newest first.
+///
+/// Versioned tags come first, highest version first. Other tags follow,
+/// most recent first, with undated tags last in name order.
+pub(crate) fn compare_tags(a: &TagInfo, b: &TagInfo) -> Ordering {
+    let by_date = || match (a.date, b.date) {
+        (Some(date_a), Some(date_b)) => date_b.cmp(&date_a),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    };
+
+    match (&a.version, &b.version) {
+        (Some(version_a), Some(version_b)) => version_b.cmp(version_a),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+    .then_with(by_date)
+    .then_with(|| a.name.cmp(&b.name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(name: &str, date: Option<i64>) -> TagInfo {
+        TagInfo::new(name.to_string(), "a".repeat(40), None, None, date)
+    }
+
+    #[test]
+    fn test_parse_versions() {
+        let parsed = |name: &str| Version::parse(name).map(|version| version.to_string());
+
+        assert_eq!(parsed("v1.2.3"), Some("1.2.3".to_string()));
+        assert_eq!(parsed("1.2"), Some("1.2.0".to_string()));
+        assert_eq!(parsed("V3"), Some("3.0.0".to_string()));
+        assert_eq!(parsed("3"), None);
+        assert_eq!(parsed("20240101"), None);
+        assert_eq!(parsed("20240101-1"), None);
+        assert_eq!(
+            parsed("v2.0.0-rc.1+build.5"),
+            Some("2.0.0-rc.1".to_string())
+        );
+        assert_eq!(parsed("nightly-2024"), None);
+        assert_eq!(parsed("v1.2.3.4"), None);
+        assert_eq!(parsed("v1..2"), None);
+        assert_eq!(parsed("v1.0.0-"), None);
+        assert_eq!(parsed("release/1.0"), None);
+    }
+
+    #[test]
+    fn test_version_precedence() {
+        // Arrange: the example ordering from the semver specification
+        let names = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.10.0",
+            "2.0.0",
+        ];
+
+        // Act
+        let versions: Vec<Version> = names.iter().filter_map(|n| Version::parse(n)).collect();
+
+        // Assert
+        assert_eq!(versions.len(), names.len());
+        for pair in versions.windows(2) {
+            assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_compare_tags() {
+        // Arrange
+        let mut tags = [
+            tag("nightly", Some(50)),
+            tag("v1.2.0", Some(10)),
+            tag("v1.10.0", Some(5)),
+            tag("v2.0.0-rc.1", Some(40)),
+            tag("old", None),
+            tag("snapshot", Some(60)),
+        ];
+
+        // Act
+        tags.sort_by(compare_tags);
+
+        // Assert
+        let names: Vec<&str> = tags.iter().map(|tag| tag.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "v2.0.0-rc.1",
+                "v1.10.0",
+                "v1.2.0",
+                "snapshot",
+                "nightly",
+                "old"
+            ]
+        );
+    }
+}
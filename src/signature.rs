@@ -0,0 +1,576 @@
+//! Commit and tag signature inspection and verification.
+//!
+//! Signatures are recognized by their ASCII armor. Key fingerprints are read
+//! from the signature itself: the issuer subpacket of OpenPGP signatures and
+//! the embedded public key of SSH signatures (SSHSIG format). SSH signatures
+//! can additionally be verified offline against an `allowed_signers` file,
+//! using `ssh-keygen -Y verify` the same way `git verify-commit` does.
+
+use anyhow::{Context, Result};
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+
+/// Armor lines opening each supported signature format.
+const SIGNATURE_MARKERS: &[(&str, SignatureKind)] = &[
+    ("-----BEGIN PGP SIGNATURE-----", SignatureKind::Gpg),
+    ("-----BEGIN SSH SIGNATURE-----", SignatureKind::Ssh),
+    ("-----BEGIN SIGNED MESSAGE-----", SignatureKind::X509),
+];
+
+/// Namespace git uses when creating SSH signatures for commits and tags.
+const SSH_NAMESPACE: &str = "git";
+
+/// Signature format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureKind {
+    /// OpenPGP signature created by gpg
+    Gpg,
+    /// SSH signature created by ssh-keygen
+    Ssh,
+    /// X.509 (S/MIME) signature created by gpgsm
+    X509,
+}
+
+impl SignatureKind {
+    /// Short display label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            SignatureKind::Gpg => "GPG",
+            SignatureKind::Ssh => "SSH",
+            SignatureKind::X509 => "X.509",
+        }
+    }
+}
+
+/// Result of checking a signature against the trusted keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verification {
+    /// Signature was not checked
+    Unchecked,
+    /// Signature is valid and made by the named allowed signer
+    Verified(String),
+    /// Signature is invalid or its key is not an allowed signer
+    Unverified,
+}
+
+/// Signature attached to a commit or annotated tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    kind: SignatureKind,
+    fingerprint: Option<String>,
+    verification: Verification,
+}
+
+impl Signature {
+    /// Parses an armored signature.
+    ///
+    /// The fingerprint is left empty when the signature format is known but
+    /// its key cannot be determined, such as X.509 signatures or OpenPGP
+    /// signatures without issuer information.
+    ///
+    /// # Returns
+    ///
+    /// Parsed signature, or None if the text is not a recognized signature
+    pub fn parse(armor: &str) -> Option<Self> {
+        let armor = armor.trim_start();
+        let kind = SIGNATURE_MARKERS
+            .iter()
+            .find(|(marker, _)| armor.starts_with(marker))
+            .map(|(_, kind)| *kind)?;
+
+        let fingerprint = armor_body(armor).and_then(|data| match kind {
+            SignatureKind::Gpg => pgp_issuer(&data),
+            SignatureKind::Ssh => ssh_fingerprint(&data),
+            SignatureKind::X509 => None,
+        });
+
+        Some(Self {
+            kind,
+            fingerprint,
+            verification: Verification::Unchecked,
+        })
+    }
+
+    /// Signature format.
+    pub fn kind(&self) -> SignatureKind {
+        self.kind
+    }
+
+    /// Signing key fingerprint.
+    ///
+    /// OpenPGP keys are shown as uppercase hex (full fingerprint or 16 digit
+    /// key ID), SSH keys as `SHA256:<base64>` like `ssh-keygen -l`.
+    pub fn fingerprint(&self) -> Option<&str> {
+        self.fingerprint.as_deref()
+    }
+
+    /// Verification state.
+    pub fn verification(&self) -> &Verification {
+        &self.verification
+    }
+
+    /// Records the outcome of verifying this signature.
+    pub(crate) fn set_verification(&mut self, verification: Verification) {
+        self.verification = verification;
+    }
+}
+
+/// Verifies SSH signatures against an `allowed_signers` file.
+///
+/// Results are cached per signature and payload digest, so a commit shown on
+/// several pages is only verified once, while a signature copied onto other
+/// content is checked again. Clones share the cache.
+#[derive(Debug, Clone)]
+pub struct SignatureVerifier {
+    allowed_signers: PathBuf,
+    fingerprint: String,
+    cache: Arc<Mutex<HashMap<CacheKey, Verification>>>,
+}
+
+/// Signature armor and SHA-256 digest of the payload it was checked against.
+type CacheKey = (String, [u8; 32]);
+
+impl SignatureVerifier {
+    /// Creates a verifier trusting the keys in an `allowed_signers` file.
+    ///
+    /// The file uses the format described in ssh-keygen(1), as configured
+    /// by git's `gpg.ssh.allowedSignersFile` setting.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be read
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gitkyl::SignatureVerifier;
+    /// use std::path::Path;
+    ///
+    /// let verifier = SignatureVerifier::new(Path::new("allowed_signers"))?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn new(allowed_signers: impl AsRef<Path>) -> Result<Self> {
+        let path = allowed_signers.as_ref();
+        let content = std::fs::read(path)
+            .with_context(|| format!("Failed to read allowed signers file {}", path.display()))?;
+        let fingerprint =
+            gix::objs::compute_hash(gix::hash::Kind::Sha1, gix::objs::Kind::Blob, &content)
+                .to_hex_with_len(12)
+                .to_string();
+
+        Ok(Self {
+            allowed_signers: path.to_path_buf(),
+            fingerprint,
+            cache: Arc::default(),
+        })
+    }
+
+    /// Short content hash of the allowed signers file.
+    ///
+    /// Used in the build manifest fingerprint so trusting or revoking a key
+    /// regenerates every page showing a verification state.
+    pub fn fingerprint(&self) -> &str {
+        &self.fingerprint
+    }
+
+    /// Verifies an SSH signature over a payload.
+    ///
+    /// Finds the principals allowed to use the signing key, then checks the
+    /// signature for each until one succeeds. Signatures in other formats are
+    /// left unchecked.
+    ///
+    /// # Arguments
+    ///
+    /// * `armor`: Armored signature as stored in the commit or tag
+    /// * `payload`: Signed data (the object without its signature)
+    ///
+    /// # Errors
+    ///
+    /// Returns error if ssh-keygen cannot be run or the temporary signature
+    /// file cannot be written
+    pub fn verify(&self, armor: &str, payload: &[u8]) -> Result<Verification> {
+        if Signature::parse(armor).map(|s| s.kind()) != Some(SignatureKind::Ssh) {
+            return Ok(Verification::Unchecked);
+        }
+
+        let key = (armor.to_string(), Sha256::digest(payload).into());
+        if let Some(cached) = self.cached(&key) {
+            return Ok(cached);
+        }
+
+        let mut sig_file = tempfile::Builder::new()
+            .prefix("gitkyl-")
+            .suffix(".sig")
+            .tempfile()
+            .context("Failed to create temporary signature file")?;
+        writeln!(sig_file, "{}", armor.trim())
+            .and_then(|()| sig_file.flush())
+            .with_context(|| {
+                format!("Failed to write signature to {}", sig_file.path().display())
+            })?;
+
+        let verification = self.verify_file(sig_file.path(), payload)?;
+
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.insert(key, verification.clone());
+        }
+
+        Ok(verification)
+    }
+
+    /// Looks up a previously computed verification result.
+    fn cached(&self, key: &CacheKey) -> Option<Verification> {
+        self.cache.lock().ok()?.get(key).cloned()
+    }
+
+    /// Runs ssh-keygen against a signature file.
+    fn verify_file(&self, sig_path: &Path, payload: &[u8]) -> Result<Verification> {
+        let output = Command::new("ssh-keygen")
+            .args(["-Y", "find-principals", "-f"])
+            .arg(&self.allowed_signers)
+            .arg("-s")
+            .arg(sig_path)
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output()
+            .context("Failed to run ssh-keygen")?;
+
+        // Failure here means no allowed signer uses the signing key
+        if !output.status.success() {
+            return Ok(Verification::Unverified);
+        }
+
+        let principals = String::from_utf8_lossy(&output.stdout);
+        for principal in principals.lines().map(str::trim).filter(|p| !p.is_empty()) {
+            let mut child = Command::new("ssh-keygen")
+                .args(["-Y", "verify", "-f"])
+                .arg(&self.allowed_signers)
+                .args(["-I", principal, "-n", SSH_NAMESPACE, "-s"])
+                .arg(sig_path)
+                .stdin(Stdio::piped())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()
+                .context("Failed to run ssh-keygen")?;
+
+            if let Some(mut stdin) = child.stdin.take() {
+                stdin
+                    .write_all(payload)
+                    .context("Failed to pass signed data to ssh-keygen")?;
+            }
+
+            let status = child.wait().context("Failed to wait for ssh-keygen")?;
+            if status.success() {
+                return Ok(Verification::Verified(principal.to_string()));
+            }
+        }
+
+        Ok(Verification::Unverified)
+    }
+}
+
+/// Splits raw annotated tag data into signed payload and signature.
+///
+/// Git appends tag signatures to the tag message, so the signature starts
+/// at the first line opening a known armor.
+///
+/// # Returns
+///
+/// Payload and armored signature, or None when the tag is unsigned
+pub(crate) fn split_tag_signature(data: &[u8]) -> Option<(&[u8], &str)> {
+    let mut offset = 0;
+    for line in data.split_inclusive(|&b| b == b'\n') {
+        if SIGNATURE_MARKERS
+            .iter()
+            .any(|(marker, _)| line.starts_with(marker.as_bytes()))
+        {
+            let armor = std::str::from_utf8(&data[offset..]).ok()?;
+            return Some((&data[..offset], armor));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Decodes the base64 body between armor header and footer lines.
+///
+/// OpenPGP armor headers (`Key: value`) and the CRC checksum line are
+/// skipped.
+fn armor_body(armor: &str) -> Option<Vec<u8>> {
+    let encoded: String = armor
+        .lines()
+        .map(str::trim)
+        .skip(1)
+        .take_while(|line| !line.starts_with("-----END"))
+        .filter(|line| !line.contains(':') && !line.starts_with('='))
+        .collect();
+
+    STANDARD.decode(encoded).ok()
+}
+
+/// Reads the issuer of an OpenPGP signature packet.
+///
+/// Prefers the issuer fingerprint subpacket (type 33) and falls back to the
+/// issuer key ID subpacket (type 16) or the key ID of version 3 signatures.
+fn pgp_issuer(data: &[u8]) -> Option<String> {
+    let (&header, rest) = data.split_first()?;
+    if header & 0x80 == 0 {
+        return None;
+    }
+
+    let (tag, body) = if header & 0x40 != 0 {
+        let (len, len_size) = pgp_length(rest)?;
+        (header & 0x3f, rest.get(len_size..len_size + len)?)
+    } else {
+        let len_size = match header & 0x03 {
+            0 => 1,
+            1 => 2,
+            2 => 4,
+            _ => 0,
+        };
+        let len = if len_size == 0 {
+            rest.len()
+        } else {
+            rest.get(..len_size)?
+                .iter()
+                .fold(0usize, |acc, &b| (acc << 8) | b as usize)
+        };
+        ((header >> 2) & 0x0f, rest.get(len_size..len_size + len)?)
+    };
+
+    // Tag 2 is the signature packet
+    if tag != 2 {
+        return None;
+    }
+
+    match body.first()? {
+        3 => Some(hex_upper(body.get(7..15)?)),
+        4 => {
+            let hashed_len = u16::from_be_bytes([*body.get(4)?, *body.get(5)?]) as usize;
+            let hashed = body.get(6..6 + hashed_len)?;
+            let unhashed_start = 6 + hashed_len;
+            let unhashed_len =
+                u16::from_be_bytes([*body.get(unhashed_start)?, *body.get(unhashed_start + 1)?])
+                    as usize;
+            let unhashed = body.get(unhashed_start + 2..unhashed_start + 2 + unhashed_len)?;
+
+            let subpackets: Vec<(u8, &[u8])> = pgp_subpackets(hashed)
+                .into_iter()
+                .chain(pgp_subpackets(unhashed))
+                .collect();
+
+            subpackets
+                .iter()
+                .find(|(kind, data)| *kind == 33 && data.len() > 1)
+                .map(|(_, data)| hex_upper(&data[1..]))
+                .or_else(|| {
+                    subpackets
+                        .iter()
+                        .find(|(kind, data)| *kind == 16 && data.len() == 8)
+                        .map(|(_, data)| hex_upper(data))
+                })
+        }
+        _ => None,
+    }
+}
+
+/// Decodes a new format OpenPGP length, returning length and its size.
+///
+/// Partial body lengths are not used by signature packets and yield None.
+fn pgp_length(data: &[u8]) -> Option<(usize, usize)> {
+    match *data.first()? {
+        first @ 0..192 => Some((first as usize, 1)),
+        first @ 192..224 => {
+            let second = *data.get(1)? as usize;
+            Some((((first as usize - 192) << 8) + second + 192, 2))
+        }
+        255 => {
+            let bytes = data.get(1..5)?;
+            Some((u32::from_be_bytes(bytes.try_into().ok()?) as usize, 5))
+        }
+        _ => None,
+    }
+}
+
+/// Splits an OpenPGP subpacket area into (type, data) pairs.
+fn pgp_subpackets(mut area: &[u8]) -> Vec<(u8, &[u8])> {
+    let mut subpackets = Vec::new();
+    while let Some((len, len_size)) = pgp_length(area) {
+        let Some(packet) = area.get(len_size..len_size + len) else {
+            break;
+        };
+        if let Some((&kind, data)) = packet.split_first() {
+            // High bit marks the subpacket as critical
+            subpackets.push((kind & 0x7f, data));
+        }
+        area = &area[len_size + len..];
+    }
+    subpackets
+}
+
+/// Computes the `SHA256:` fingerprint of the key in an SSHSIG blob.
+///
+/// The blob starts with the `SSHSIG` magic and a version, followed by the
+/// public key as a length prefixed string.
+fn ssh_fingerprint(data: &[u8]) -> Option<String> {
+    let rest = data.strip_prefix(b"SSHSIG")?;
+    let key_len = u32::from_be_bytes(rest.get(4..8)?.try_into().ok()?) as usize;
+    let key = rest.get(8..8 + key_len)?;
+
+    Some(format!(
+        "SHA256:{}",
+        STANDARD_NO_PAD.encode(Sha256::digest(key))
+    ))
+}
+
+/// Formats bytes as uppercase hex.
+fn hex_upper(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SSH_SIGNATURE: &str = "-----BEGIN SSH SIGNATURE-----
+U1NIU0lHAAAAAQAAADMAAAALc3NoLWVkMjU1MTkAAAAgM5o6OzZKTmTKkExxpsSHsGZ60q
+pp4zdeX35B6OInGzgAAAADZ2l0AAAAAAAAAAZzaGE1MTIAAABTAAAAC3NzaC1lZDI1NTE5
+AAAAQFLsgA3H0KsyluL+G7+eufocDOZQULxLMEHOHY5Fi3gery8cvHjsSxltOtsuTDUOz8
+n39PU70mmcJEb9WZJRnwA=
+-----END SSH SIGNATURE-----
+";
+
+    const SSH_ALLOWED_SIGNERS: &str = "jane@example.com ssh-ed25519 \
+AAAAC3NzaC1lZDI1NTE5AAAAIDOaOjs2Sk5kypBMcabEh7BmetKqaeM3Xl9+QejiJxs4\n";
+
+    const PGP_SIGNATURE: &str = "-----BEGIN PGP SIGNATURE-----
+
+iHUEABYIAB0WIQTVNCmiYJuquHFJO8PDl18luTI6IgUCatKmpAAKCRDDl18luTI6
+IjotAQDwcuqBKZmFrpn81AWs3LjbZi+ebSYXbp0+dXkIsQABswD9Hj3PYEEW8Ve+
+E6qmT0P+g4V/IZ6kXNBkHiPvTZSw2Qg=
+=xPl1
+-----END PGP SIGNATURE-----
+";
+
+    #[test]
+    fn test_parse_ssh_signature() {
+        let signature = Signature::parse(SSH_SIGNATURE).expect("Should parse SSH signature");
+
+        assert_eq!(signature.kind(), SignatureKind::Ssh);
+        assert_eq!(
+            signature.fingerprint(),
+            Some("SHA256:9Z4knn1U6n4C1TFlDMOacQKzXCVDcdE1x8otlL35vUM"),
+            "Fingerprint should match ssh-keygen -l output"
+        );
+        assert_eq!(signature.verification(), &Verification::Unchecked);
+    }
+
+    #[test]
+    fn test_parse_pgp_signature() {
+        let signature = Signature::parse(PGP_SIGNATURE).expect("Should parse PGP signature");
+
+        assert_eq!(signature.kind(), SignatureKind::Gpg);
+        assert_eq!(
+            signature.fingerprint(),
+            Some("D53429A2609BAAB871493BC3C3975F25B9323A22")
+        );
+    }
+
+    #[test]
+    fn test_parse_x509_and_malformed_signatures() {
+        let x509 = Signature::parse(
+            "-----BEGIN SIGNED MESSAGE-----\nMIAG\n-----END SIGNED MESSAGE-----\n",
+        )
+        .expect("Should recognize X.509 signature");
+        assert_eq!(x509.kind(), SignatureKind::X509);
+        assert_eq!(x509.fingerprint(), None);
+
+        let garbled = Signature::parse(
+            "-----BEGIN PGP SIGNATURE-----\n\n!!!!\n-----END PGP SIGNATURE-----\n",
+        )
+        .expect("Should recognize PGP armor");
+        assert_eq!(garbled.fingerprint(), None);
+
+        assert!(Signature::parse("not a signature").is_none());
+    }
+
+    #[test]
+    fn test_split_tag_signature() {
+        let data = format!(
+            "object abc\ntype commit\ntag v1\n\nRelease v1\n{}",
+            SSH_SIGNATURE
+        );
+
+        let (payload, armor) =
+            split_tag_signature(data.as_bytes()).expect("Should find tag signature");
+        assert_eq!(
+            payload,
+            b"object abc\ntype commit\ntag v1\n\nRelease v1\n".as_slice()
+        );
+        assert_eq!(armor, SSH_SIGNATURE);
+
+        assert!(split_tag_signature(b"object abc\n\nUnsigned\n").is_none());
+    }
+
+    #[test]
+    fn test_verify_ssh_signature() {
+        let td = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let allowed = td.path().join("allowed_signers");
+        std::fs::write(&allowed, SSH_ALLOWED_SIGNERS).expect("Failed to write allowed signers");
+
+        let verifier = SignatureVerifier::new(&allowed).expect("Should create verifier");
+
+        assert_eq!(
+            verifier
+                .verify(SSH_SIGNATURE, b"signed payload\n")
+                .expect("Should run ssh-keygen"),
+            Verification::Verified("jane@example.com".to_string())
+        );
+        assert_eq!(
+            verifier
+                .verify(SSH_SIGNATURE, b"tampered payload\n")
+                .expect("Should run ssh-keygen"),
+            Verification::Unverified,
+            "Cached results do not carry over to other payloads"
+        );
+        assert_eq!(
+            verifier
+                .verify(SSH_SIGNATURE, b"signed payload\n")
+                .expect("Should use cached result"),
+            Verification::Verified("jane@example.com".to_string())
+        );
+        assert_eq!(
+            verifier
+                .verify(PGP_SIGNATURE, b"signed payload\n")
+                .expect("PGP signatures are not checked"),
+            Verification::Unchecked
+        );
+    }
+
+    #[test]
+    fn test_verify_unknown_signer() {
+        let td = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let allowed = td.path().join("allowed_signers");
+        std::fs::write(&allowed, "# nobody is trusted\n").expect("Failed to write allowed signers");
+
+        let verifier = SignatureVerifier::new(&allowed).expect("Should create verifier");
+
+        assert_eq!(
+            verifier
+                .verify(SSH_SIGNATURE, b"signed payload\n")
+                .expect("Should run ssh-keygen"),
+            Verification::Unverified
+        );
+    }
+
+    #[test]
+    fn test_verifier_missing_file() {
+        assert!(SignatureVerifier::new("/nonexistent/allowed_signers").is_err());
+    }
+}
@@ -0,0 +1,277 @@
+//! Commit message trailers.
+//!
+//! Trailers are the `Key: value` lines forming the last paragraph of a commit
+//! message, as written by `git commit --trailer` or `git interpret-trailers`:
+//!
+//! ```text
+//! Fix parser crash on empty input
+//!
+//! The tokenizer assumed at least one byte of input.
+//!
+//! Fixes: 1a2b3c4 ("Add tokenizer")
+//! Reviewed-by: Alice <alice@example.com>
+//! Signed-off-by: Bob <bob@example.com>
+//! ```
+//!
+//! Lines starting with whitespace continue the previous value. Keys are
+//! matched case insensitively.
+
+use crate::mailmap::Mailmap;
+
+/// Trailer key crediting additional authors.
+pub const CO_AUTHORED_BY: &str = "Co-authored-by";
+
+/// Line appended by `git cherry-pick -x`, allowed inside a trailer block.
+const CHERRY_PICK_PREFIX: &str = "(cherry picked from commit ";
+
+/// Single trailer of a commit message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trailer {
+    key: String,
+    value: String,
+    identity: Option<(String, String)>,
+}
+
+impl Trailer {
+    /// Creates a trailer, reading `Name <email>` values as identities.
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: Trailer key as written (e.g. "Signed-off-by")
+    /// * `value`: Trailer value
+    pub fn new(key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::resolve(key.into(), value.into(), &Mailmap::default())
+    }
+
+    /// Creates a trailer whose identity is normalized through the mailmap.
+    fn resolve(key: String, value: String, mailmap: &Mailmap) -> Self {
+        let identity = match (value.find('<'), value.rfind('>')) {
+            (Some(open), Some(close)) if open < close && !value[..open].trim().is_empty() => {
+                Some(mailmap.resolve(value[..open].trim(), &value[open + 1..close]))
+            }
+            _ => None,
+        };
+        Self {
+            key,
+            value,
+            identity,
+        }
+    }
+
+    /// Trailer key as written in the message.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Trailer value as written in the message.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Person named by the value, for `Name <email>` values.
+    pub fn name(&self) -> Option<&str> {
+        self.identity.as_ref().map(|(name, _)| name.as_str())
+    }
+
+    /// Email of the person named by the value, for `Name <email>` values.
+    pub fn email(&self) -> Option<&str> {
+        self.identity.as_ref().map(|(_, email)| email.as_str())
+    }
+
+    /// Returns true if the trailer has the given key (case insensitive).
+    pub fn is(&self, key: &str) -> bool {
+        self.key.eq_ignore_ascii_case(key)
+    }
+}
+
+/// Parses the trailers of a commit message.
+///
+/// Identities in trailer values are normalized through the mailmap, so
+/// people show under the same name as their own commits.
+///
+/// # Arguments
+///
+/// * `message_full`: Full commit message
+/// * `mailmap`: Identity mapping for trailer values
+///
+/// # Returns
+///
+/// Trailers in message order, empty when the last paragraph is not a
+/// trailer block
+pub(crate) fn parse_trailers(message_full: &str, mailmap: &Mailmap) -> Vec<Trailer> {
+    let (_, block) = split_message(message_full);
+
+    let mut trailers: Vec<(String, String)> = Vec::new();
+    for line in block.lines() {
+        if line.starts_with(char::is_whitespace) {
+            if let Some((_, value)) = trailers.last_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+        } else if let Some((key, value)) = parse_line(line) {
+            trailers.push((key.to_string(), value.to_string()));
+        }
+    }
+
+    trailers
+        .into_iter()
+        .map(|(key, value)| Trailer::resolve(key, value, mailmap))
+        .collect()
+}
+
+/// Splits a commit message into its body and trailer block.
+///
+/// The subject line is dropped. The body is empty for subject only
+/// messages, and the trailer block is empty when the last paragraph does
+/// not consist of trailers.
+///
+/// # Arguments
+///
+/// * `message_full`: Full commit message
+///
+/// # Returns
+///
+/// Trimmed body and trailer block
+pub(crate) fn split_message(message_full: &str) -> (&str, &str) {
+    let rest = message_full
+        .split_once('\n')
+        .map(|(_, rest)| rest.trim())
+        .unwrap_or("");
+
+    let (body, last) = match rest.rfind("\n\n") {
+        Some(idx) => (rest[..idx].trim_end(), rest[idx..].trim()),
+        None => ("", rest),
+    };
+
+    if is_trailer_block(last) {
+        (body, last)
+    } else {
+        (rest, "")
+    }
+}
+
+/// Returns true if every line of the paragraph belongs to a trailer.
+fn is_trailer_block(paragraph: &str) -> bool {
+    let mut has_trailer = false;
+    for (idx, line) in paragraph.lines().enumerate() {
+        if (idx > 0 && line.starts_with(char::is_whitespace))
+            || line.starts_with(CHERRY_PICK_PREFIX)
+        {
+            continue;
+        }
+        if parse_line(line).is_none() {
+            return false;
+        }
+        has_trailer = true;
+    }
+    has_trailer
+}
+
+/// Splits a `Key: value` line, requiring a token key and a nonempty value.
+fn parse_line(line: &str) -> Option<(&str, &str)> {
+    let (key, value) = line.split_once(':')?;
+    let valid_key = !key.is_empty()
+        && !key.starts_with('-')
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+    // Requiring whitespace after the colon keeps URLs from reading as trailers
+    if !valid_key || !value.starts_with(char::is_whitespace) || value.trim().is_empty() {
+        return None;
+    }
+    Some((key, value.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_trailers_reads_last_paragraph() {
+        // Arrange
+        let msg =
+            "Fix crash\n\nBody text.\n\nSigned-off-by: Alice <alice@example.com>\nFixes: 1a2b3c4";
+
+        // Act
+        let trailers = parse_trailers(msg, &Mailmap::default());
+
+        // Assert
+        assert_eq!(trailers.len(), 2);
+        assert_eq!(trailers[0].key(), "Signed-off-by");
+        assert_eq!(trailers[0].name(), Some("Alice"));
+        assert_eq!(trailers[0].email(), Some("alice@example.com"));
+        assert_eq!(trailers[1].value(), "1a2b3c4");
+        assert_eq!(trailers[1].name(), None);
+    }
+
+    #[test]
+    fn test_parse_trailers_ignores_prose_paragraph() {
+        // Arrange: last paragraph mixes prose with a key-like line
+        let msg = "Update docs\n\nSee the guide.\nNote: wording only";
+
+        // Act
+        let trailers = parse_trailers(msg, &Mailmap::default());
+
+        // Assert
+        assert!(trailers.is_empty());
+    }
+
+    #[test]
+    fn test_parse_trailers_joins_continuation_lines() {
+        // Arrange
+        let msg = "Fix\n\nFixes: 1a2b3c4 (\"Add a very\n  long subject\")\n(cherry picked from commit abc)";
+
+        // Act
+        let trailers = parse_trailers(msg, &Mailmap::default());
+
+        // Assert
+        assert_eq!(trailers.len(), 1);
+        assert_eq!(trailers[0].value(), "1a2b3c4 (\"Add a very long subject\")");
+    }
+
+    #[test]
+    fn test_parse_trailers_rejects_urls() {
+        // Arrange
+        let msg = "Fix\n\nhttps://example.com/issue/1";
+
+        // Act
+        let trailers = parse_trailers(msg, &Mailmap::default());
+
+        // Assert
+        assert!(trailers.is_empty());
+    }
+
+    #[test]
+    fn test_parse_trailers_resolves_identities_through_mailmap() {
+        // Arrange
+        let mailmap = Mailmap::parse("Alice Smith <alice@example.com> <old@example.com>");
+        let msg = "Fix\n\nReviewed-by: alice <old@example.com>";
+
+        // Act
+        let trailers = parse_trailers(msg, &mailmap);
+
+        // Assert
+        assert_eq!(trailers[0].name(), Some("Alice Smith"));
+        assert_eq!(trailers[0].value(), "alice <old@example.com>");
+    }
+
+    #[test]
+    fn test_split_message_separates_body_and_trailers() {
+        assert_eq!(
+            split_message("Subject\n\nBody text\n\nAcked-by: Bob <b@x.com>\n"),
+            ("Body text", "Acked-by: Bob <b@x.com>")
+        );
+        assert_eq!(
+            split_message("Subject\n\nSigned-off-by: Bob <b@x.com>"),
+            ("", "Signed-off-by: Bob <b@x.com>")
+        );
+        assert_eq!(split_message("Subject\n\nBody text\n"), ("Body text", ""));
+        assert_eq!(split_message("Subject only"), ("", ""));
+    }
+
+    #[test]
+    fn test_trailer_key_matches_case_insensitively() {
+        let trailer = Trailer::new("co-authored-BY", "Bob");
+
+        assert!(trailer.is(CO_AUTHORED_BY));
+        assert_eq!(trailer.name(), None);
+    }
+}
@@ -3,7 +3,7 @@
 //! Provides O(depth) lookups for files and subdirectories at any level,
 //! replacing O(n) linear scans through flat file lists.
 
-use crate::FileEntry;
+use crate::{FileEntry, SubmoduleEntry};
 use std::collections::HashMap;
 
 /// In-memory representation of repository directory tree.
@@ -37,9 +37,19 @@ pub struct FileTree {
     root: DirNode,
 }
 
-#[derive(Debug, Clone)]
+/// Location a symlink points to inside the repository tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SymlinkTarget {
+    /// File path relative to repository root
+    File(String),
+    /// Directory path relative to repository root (empty for root)
+    Directory(String),
+}
+
+#[derive(Debug, Clone, Default)]
 struct DirNode {
     files: Vec<FileEntry>,
+    submodules: Vec<SubmoduleEntry>,
     subdirs: HashMap<String, DirNode>,
 }
 
@@ -65,41 +75,55 @@ impl FileTree {
     /// # Ok::<(), anyhow::Error>(())
     /// ```
     pub fn from_files(files: Vec<FileEntry>) -> Self {
-        let mut root = DirNode {
-            files: Vec::new(),
-            subdirs: HashMap::new(),
-        };
+        let mut root = DirNode::default();
 
         for file in files {
             if let Some(path) = file.path()
                 && let Some(path_str) = path.to_str()
             {
-                let components: Vec<&str> = path_str.split('/').collect();
-                let mut current = &mut root;
-
-                // Navigate or create directory structure
-                for (i, &component) in components.iter().enumerate() {
-                    if i == components.len() - 1 {
-                        // Leaf node: file itself
-                        current.files.push(file.clone());
-                    } else {
-                        // Intermediate directory
-                        current =
-                            current
-                                .subdirs
-                                .entry(component.to_string())
-                                .or_insert_with(|| DirNode {
-                                    files: Vec::new(),
-                                    subdirs: HashMap::new(),
-                                });
-                    }
-                }
+                Self::parent_node_mut(&mut root, path_str)
+                    .files
+                    .push(file.clone());
             }
         }
 
         Self { root }
     }
 
+    /// Adds submodules to the tree.
+    ///
+    /// Submodules are placed next to files in their parent directory,
+    /// creating intermediate directories as needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `submodules`: Submodule entries from the same ref as the files
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gitkyl::{list_files, list_submodules, FileTree};
+    /// use std::path::Path;
+    ///
+    /// let files = list_files(Path::new("."), None)?;
+    /// let submodules = list_submodules(Path::new("."), None)?;
+    /// let tree = FileTree::from_files(files).with_submodules(submodules);
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn with_submodules(mut self, submodules: Vec<SubmoduleEntry>) -> Self {
+        for submodule in submodules {
+            if let Some(path) = submodule.path()
+                && let Some(path_str) = path.to_str()
+            {
+                Self::parent_node_mut(&mut self.root, path_str)
+                    .submodules
+                    .push(submodule.clone());
+            }
+        }
+
+        self
+    }
+
     /// Returns files directly at the given directory level.
     ///
     /// Returns only files that are immediate children of the directory,
@@ -131,6 +155,21 @@ impl FileTree {
             .unwrap_or(&[])
     }
 
+    /// Returns submodules directly at the given directory level.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir_path`: Directory path (empty string for root)
+    ///
+    /// # Returns
+    ///
+    /// Slice of submodule entries at the specified level
+    pub fn submodules_at(&self, dir_path: &str) -> &[SubmoduleEntry] {
+        self.get_node(dir_path)
+            .map(|node| node.submodules.as_slice())
+            .unwrap_or(&[])
+    }
+
     /// Returns immediate subdirectories at the given level.
     ///
     /// Returns directory names only (not full paths), sorted alphabetically.
@@ -229,7 +268,6 @@ impl FileTree {
     /// let all_src_files = tree.all_files_under("src");
     /// # Ok::<(), anyhow::Error>(())
     /// ```
-    #[allow(dead_code)] // Reserved for future recursive operations (search, stats)
     pub fn all_files_under(&self, dir_path: &str) -> Vec<&FileEntry> {
         let mut files = Vec::new();
         if let Some(node) = self.get_node(dir_path) {
@@ -238,6 +276,76 @@ impl FileTree {
         files
     }
 
+    /// Resolves a symlink target against the tree.
+    ///
+    /// Relative targets are interpreted from the directory containing the
+    /// link, following `.` and `..` components. Absolute targets, targets
+    /// escaping the repository root, and targets that do not name a file or
+    /// directory in this tree are not resolved.
+    ///
+    /// # Arguments
+    ///
+    /// * `link_path`: Path of the symlink relative to repository root
+    /// * `target`: Link target as stored in the symlink blob
+    ///
+    /// # Returns
+    ///
+    /// Resolved file or directory, or None if the target lies outside the tree
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gitkyl::{list_files, FileTree, SymlinkTarget};
+    /// # use std::path::Path;
+    /// # let files = list_files(Path::new("."), None)?;
+    /// let tree = FileTree::from_files(files);
+    ///
+    /// let resolved = tree.resolve_symlink("docs/README.md", "../README.md");
+    /// assert_eq!(resolved, Some(SymlinkTarget::File("README.md".to_string())));
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn resolve_symlink(&self, link_path: &str, target: &str) -> Option<SymlinkTarget> {
+        if target.starts_with('/') {
+            return None;
+        }
+
+        let mut components: Vec<&str> = match link_path.rsplit_once('/') {
+            Some((parent, _)) => parent.split('/').collect(),
+            None => Vec::new(),
+        };
+
+        for component in target.split('/') {
+            match component {
+                "" | "." => {}
+                ".." => {
+                    components.pop()?;
+                }
+                name => components.push(name),
+            }
+        }
+
+        let path = components.join("/");
+        if self.get_node(&path).is_some() {
+            return Some(SymlinkTarget::Directory(path));
+        }
+
+        self.files_at(path.rsplit_once('/').map_or("", |(parent, _)| parent))
+            .iter()
+            .any(|file| file.path().and_then(|p| p.to_str()) == Some(path.as_str()))
+            .then_some(SymlinkTarget::File(path))
+    }
+
+    /// Navigates to the directory containing `path`, creating it if needed.
+    fn parent_node_mut<'a>(root: &'a mut DirNode, path: &str) -> &'a mut DirNode {
+        let mut current = root;
+        if let Some((parent, _)) = path.rsplit_once('/') {
+            for component in parent.split('/') {
+                current = current.subdirs.entry(component.to_string()).or_default();
+            }
+        }
+        current
+    }
+
     fn get_node(&self, dir_path: &str) -> Option<&DirNode> {
         if dir_path.is_empty() {
             return Some(&self.root);
@@ -509,4 +617,54 @@ mod tests {
             }
         }
     }
+
+    fn entry(path: &str) -> FileEntry {
+        FileEntry::new(
+            path,
+            gix::ObjectId::null(gix::hash::Kind::Sha1),
+            gix::object::tree::EntryKind::Blob.into(),
+        )
+    }
+
+    #[test]
+    fn test_resolve_symlink_relative_file() {
+        // Arrange
+        let tree = FileTree::from_files(vec![entry("README.md"), entry("docs/guide.md")]);
+
+        // Act
+        let parent = tree.resolve_symlink("docs/README.md", "../README.md");
+        let sibling = tree.resolve_symlink("docs/link.md", "./guide.md");
+
+        // Assert
+        assert_eq!(parent, Some(SymlinkTarget::File("README.md".to_string())));
+        assert_eq!(
+            sibling,
+            Some(SymlinkTarget::File("docs/guide.md".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_resolve_symlink_directory() {
+        // Arrange
+        let tree = FileTree::from_files(vec![entry("src/lib.rs"), entry("current")]);
+
+        // Act
+        let subdir = tree.resolve_symlink("current", "src/");
+        let root = tree.resolve_symlink("src/up", "..");
+
+        // Assert
+        assert_eq!(subdir, Some(SymlinkTarget::Directory("src".to_string())));
+        assert_eq!(root, Some(SymlinkTarget::Directory(String::new())));
+    }
+
+    #[test]
+    fn test_resolve_symlink_outside_tree() {
+        // Arrange
+        let tree = FileTree::from_files(vec![entry("README.md")]);
+
+        // Act & Assert
+        assert_eq!(tree.resolve_symlink("link", "/etc/passwd"), None);
+        assert_eq!(tree.resolve_symlink("link", "../README.md"), None);
+        assert_eq!(tree.resolve_symlink("link", "missing.md"), None);
+    }
 }
@@ -92,10 +92,142 @@ pub fn format_file_size(bytes: usize) -> String {
     }
 }
 
+/// Converts a git remote URL into a browsable web URL
+///
+/// Handles `https://`, `http://`, `ssh://` and scp-like `user@host:path`
+/// remotes, dropping credentials and a trailing `.git`. Relative URLs and
+/// local paths, as used by some `.gitmodules` files, have no web location.
+///
+/// # Arguments
+///
+/// * `remote`: Remote URL as written in git configuration
+///
+/// # Returns
+///
+/// HTTPS (or HTTP) URL of the repository, or None if unknown
+pub fn web_url(remote: &str) -> Option<String> {
+    let remote = remote.trim();
+
+    let (scheme, rest) = if let Some(rest) = remote.strip_prefix("https://") {
+        ("https", rest)
+    } else if let Some(rest) = remote.strip_prefix("http://") {
+        ("http", rest)
+    } else if let Some(rest) = remote
+        .strip_prefix("ssh://")
+        .or_else(|| remote.strip_prefix("git://"))
+    {
+        ("https", rest)
+    } else if !remote.contains("://")
+        && let Some((host, path)) = remote.split_once(':')
+        && host.contains('.')
+        && !host.contains('/')
+    {
+        return web_url(&format!("https://{}/{}", host, path));
+    } else {
+        return None;
+    };
+
+    let rest = rest.split_once('@').map_or(rest, |(_, after)| after);
+    let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+    // Drop an explicit port, which belongs to the git transport
+    let host = host.split_once(':').map_or(host, |(name, _)| name);
+    let path = path.trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+
+    if host.is_empty() {
+        return None;
+    }
+
+    Some(if path.is_empty() {
+        format!("{}://{}", scheme, host)
+    } else {
+        format!("{}://{}/{}", scheme, host, path)
+    })
+}
+
+/// Formats a timestamp as a calendar date (YYYY-MM-DD, UTC)
+///
+/// # Arguments
+///
+/// * `seconds`: Unix timestamp in seconds since epoch
+///
+/// # Returns
+///
+/// ISO 8601 date string
+pub(crate) fn format_date(seconds: i64) -> String {
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Converts days since the Unix epoch to a proleptic Gregorian date.
+///
+/// # Arguments
+///
+/// * `days`: Days since 1970-01-01 (negative for earlier dates)
+///
+/// # Returns
+///
+/// Tuple of (year, month 1-12, day 1-31)
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    // Shift the epoch to 0000-03-01 so leap days end each 400 year era
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Converts a proleptic Gregorian date to days since the Unix epoch.
+///
+/// Inverse of `civil_from_days`.
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let mp = i64::from(if month > 2 { month - 3 } else { month + 9 });
+    let day_of_year = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Parses an ISO 8601 date (`YYYY-MM-DD`) as midnight UTC.
+///
+/// # Returns
+///
+/// Unix timestamp in seconds, or None for malformed or impossible dates
+pub(crate) fn parse_date(value: &str) -> Option<i64> {
+    let mut parts = value.splitn(3, '-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: u32 = parts.next()?.parse().ok()?;
+    let day: u32 = parts.next()?.parse().ok()?;
+
+    let days = days_from_civil(year, month, day);
+    (civil_from_days(days) == (year, month, day)).then_some(days * 86_400)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(parse_date("1970-01-01"), Some(0));
+        assert_eq!(parse_date("2023-11-14"), Some(1_699_920_000));
+        assert_eq!(
+            parse_date("2024-02-29").map(format_date).as_deref(),
+            Some("2024-02-29")
+        );
+        assert_eq!(parse_date("2023-02-29"), None);
+        assert_eq!(parse_date("2023-13-01"), None);
+        assert_eq!(parse_date("yesterday"), None);
+    }
+
     #[test]
     fn test_single_level_branch_root() {
         assert_eq!(calculate_depth("dev", ""), 2);
@@ -126,6 +258,13 @@ mod tests {
         assert_eq!(calculate_depth("feature/ui", "assets/styles.css"), 4);
     }
 
+    #[test]
+    fn test_format_date() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(1_700_000_000), "2023-11-14");
+        assert_eq!(format_date(-1), "1969-12-31");
+    }
+
     #[test]
     fn test_format_timestamp_just_now() {
         let now = SystemTime::now()
@@ -259,4 +398,43 @@ mod tests {
         assert_eq!(format_file_size(1572864), "1.50 MB");
         assert_eq!(format_file_size(10485760), "10.00 MB");
     }
+
+    #[test]
+    fn test_web_url_https() {
+        assert_eq!(
+            web_url("https://github.com/owner/repo.git"),
+            Some("https://github.com/owner/repo".to_string())
+        );
+        assert_eq!(
+            web_url("https://user@example.com/group/repo/"),
+            Some("https://example.com/group/repo".to_string())
+        );
+    }
+
+    #[test]
+    fn test_web_url_ssh() {
+        assert_eq!(
+            web_url("git@github.com:owner/repo.git"),
+            Some("https://github.com/owner/repo".to_string())
+        );
+        assert_eq!(
+            web_url("ssh://git@example.com:2222/owner/repo.git"),
+            Some("https://example.com/owner/repo".to_string())
+        );
+    }
+
+    #[test]
+    fn test_web_url_relative() {
+        assert_eq!(web_url("../sibling.git"), None);
+        assert_eq!(web_url("./libs/vendored"), None);
+        assert_eq!(web_url("/srv/git/repo.git"), None);
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+    }
 }
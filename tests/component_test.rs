@@ -19,7 +19,7 @@ fn test_attribution_simple_case() {
         1234567890,
     );
 
-    let html = attribution(&commit).into_string();
+    let html = attribution(&commit, "").into_string();
 
     assert!(html.contains("lemorage"));
     assert!(!html.contains("attribution-indicator"));
@@ -55,7 +55,7 @@ fn test_attribution_handles_special_characters() {
         1234567890,
     );
 
-    let html = attribution(&commit).into_string();
+    let html = attribution(&commit, "").into_string();
 
     assert!(html.contains("Müller"));
 }
@@ -289,6 +289,7 @@ fn test_file_row_contains_href_link() {
         "/repo/blob/main/src/main.rs",
         test_icon,
         "main.rs",
+        false,
         Some(&commit),
         "2025-12-17",
     );
@@ -312,6 +313,7 @@ fn test_file_row_contains_file_row_class() {
         "/repo/main/README.md",
         test_icon,
         "README.md",
+        false,
         Some(&commit),
         "2025-12-16",
     );
@@ -335,6 +337,7 @@ fn test_file_row_contains_file_name() {
         "/repo/tree/main/src/",
         test_icon,
         "src/",
+        false,
         Some(&commit),
         "2025-12-15",
     );
@@ -359,6 +362,7 @@ fn test_file_row_contains_commit_message() {
         "/repo/blob/dev/config.toml",
         test_icon,
         "config.toml",
+        false,
         Some(&commit),
         "2025-12-14",
     );
@@ -383,6 +387,7 @@ fn test_file_row_contains_commit_date() {
         "/repo/blob/main/LICENSE",
         test_icon,
         "LICENSE",
+        false,
         Some(&commit),
         "2025-12-13",
     );
@@ -411,6 +416,7 @@ fn test_file_row_contains_icon_markup() {
         "/repo/blob/main/lib.rs",
         test_icon,
         "lib.rs",
+        false,
         Some(&commit),
         "2025-12-12",
     );
@@ -435,6 +441,7 @@ fn test_file_row_has_title_with_author_info() {
         "/repo/blob/feature/test.rs",
         test_icon,
         "test.rs",
+        false,
         Some(&commit),
         "2025-12-11",
     );
@@ -459,6 +466,7 @@ fn test_file_row_proper_html_structure() {
         "/repo/tree/main/tests/",
         test_icon,
         "tests/",
+        false,
         Some(&commit),
         "2025-12-10",
     );
@@ -486,6 +494,7 @@ fn test_file_row_handles_special_characters_in_filename() {
         "/repo/blob/main/my-file_v2.0.rs",
         test_icon,
         "my-file_v2.0.rs",
+        false,
         Some(&commit),
         "2025-12-09",
     );
@@ -510,6 +519,7 @@ fn test_file_row_handles_long_commit_messages() {
         "/repo/blob/main/complex.rs",
         test_icon,
         "complex.rs",
+        false,
         Some(&commit),
         "2025-12-08",
     );
@@ -533,6 +543,7 @@ fn test_file_row_handles_unicode_in_filenames() {
         "/repo/blob/main/README_中文.md",
         test_icon,
         "README_中文.md",
+        false,
         Some(&commit),
         "2025-12-07",
     );
@@ -563,6 +574,7 @@ fn test_file_table_and_file_row_integration() {
         "/repo/blob/main/file1.rs",
         test_icon.clone(),
         "file1.rs",
+        false,
         Some(&commit1),
         "2025-12-06",
     );
@@ -571,6 +583,7 @@ fn test_file_table_and_file_row_integration() {
         "/repo/blob/main/file2.rs",
         test_icon,
         "file2.rs",
+        false,
         Some(&commit2),
         "2025-12-05",
     );
@@ -594,7 +607,7 @@ fn test_file_table_and_file_row_integration() {
 fn test_file_row_none_commit_shows_empty_message() {
     let test_icon = html! { i class="ph-fill ph-folder" {} };
 
-    let result = file_row("/repo/tree/main/parent/", test_icon, "..", None, "");
+    let result = file_row("/repo/tree/main/parent/", test_icon, "..", false, None, "");
     let html_output = result.into_string();
 
     assert!(html_output.contains("class=\"file-row\""));
@@ -611,6 +624,11 @@ fn test_repo_header_with_owner_contains_separator() {
         owner: Some("lemorage"),
         tag_count: 0,
         tags_href: None,
+        contributor_count: 0,
+        contributors_href: None,
+        archive_href: None,
+        latest_release: None,
+        latest_release_href: None,
     });
     let html_str = html.into_string();
 
@@ -629,6 +647,11 @@ fn test_repo_header_with_owner_contains_both_names() {
         owner: Some("torvalds"),
         tag_count: 0,
         tags_href: None,
+        contributor_count: 0,
+        contributors_href: None,
+        archive_href: None,
+        latest_release: None,
+        latest_release_href: None,
     });
     let html_str = html.into_string();
 
@@ -651,6 +674,11 @@ fn test_repo_header_without_owner_displays_only_repo() {
         owner: None,
         tag_count: 0,
         tags_href: None,
+        contributor_count: 0,
+        contributors_href: None,
+        archive_href: None,
+        latest_release: None,
+        latest_release_href: None,
     });
     let html_str = html.into_string();
 
@@ -669,6 +697,11 @@ fn test_repo_header_without_owner_no_separator() {
         owner: None,
         tag_count: 0,
         tags_href: None,
+        contributor_count: 0,
+        contributors_href: None,
+        archive_href: None,
+        latest_release: None,
+        latest_release_href: None,
     });
     let html_str = html.into_string();
 
@@ -678,6 +711,67 @@ fn test_repo_header_without_owner_no_separator() {
     );
 }
 
+#[test]
+fn test_repo_header_links_archives() {
+    use gitkyl::components::metadata::{RepoHeaderData, repo_header};
+
+    let html = repo_header(RepoHeaderData {
+        name: "gitkyl",
+        owner: None,
+        tag_count: 1,
+        tags_href: Some("tags/index.html"),
+        contributor_count: 0,
+        contributors_href: None,
+        archive_href: Some("archive/gitkyl-v1.0.0"),
+        latest_release: None,
+        latest_release_href: None,
+    });
+    let html_str = html.into_string();
+
+    assert!(
+        html_str.contains(r#"href="archive/gitkyl-v1.0.0.zip""#),
+        "Header should link zip archive"
+    );
+    assert!(
+        html_str.contains(r#"href="archive/gitkyl-v1.0.0.tar.gz""#),
+        "Header should link tarball"
+    );
+}
+
+#[test]
+fn test_repo_header_shows_latest_release() {
+    use gitkyl::TagInfo;
+    use gitkyl::components::metadata::{RepoHeaderData, repo_header};
+
+    let tag = TagInfo::new(
+        "v1.4.0".to_string(),
+        "a".repeat(40),
+        None,
+        None,
+        Some(1234567890),
+    );
+
+    let html = repo_header(RepoHeaderData {
+        name: "gitkyl",
+        owner: None,
+        tag_count: 3,
+        tags_href: Some("tags/index.html"),
+        contributor_count: 0,
+        contributors_href: None,
+        archive_href: None,
+        latest_release: Some(&tag),
+        latest_release_href: Some("tags/v1.4.0.html"),
+    });
+    let html_str = html.into_string();
+
+    assert!(
+        html_str.contains(r#"<a href="tags/v1.4.0.html" class="repo-release-card">"#),
+        "Header should link the latest release"
+    );
+    assert!(html_str.contains("Latest release"));
+    assert!(html_str.contains(r#"<span class="repo-release-name">v1.4.0</span>"#));
+}
+
 #[test]
 fn test_repo_header_contains_proper_html_structure() {
     use gitkyl::components::metadata::{RepoHeaderData, repo_header};
@@ -687,6 +781,11 @@ fn test_repo_header_contains_proper_html_structure() {
         owner: Some("lemorage"),
         tag_count: 0,
         tags_href: None,
+        contributor_count: 0,
+        contributors_href: None,
+        archive_href: None,
+        latest_release: None,
+        latest_release_href: None,
     });
     let html_str = html.into_string();
 
@@ -1,5 +1,7 @@
 //! End-to-end tests for Gitkyl binary workflow.
 
+mod common;
+
 use anyhow::Result;
 use std::fs;
 use std::path::PathBuf;
@@ -95,3 +97,192 @@ fn test_minimal_args_e2e() -> Result<()> {
 
     Ok(())
 }
+
+/// Tests second run reuses pages recorded in the build manifest.
+#[test]
+fn test_incremental_rebuild_e2e() -> Result<()> {
+    // Arrange
+    let repo = common::create_test_repo()?;
+    common::write_file(repo.path(), "README.md", "# Readme\n")?;
+    common::write_file(repo.path(), "src/lib.rs", "pub fn a() {}\n")?;
+    common::git_add(repo.path(), &["."])?;
+    common::git_commit(repo.path(), "Initial commit")?;
+    let output = tempfile::TempDir::new()?;
+
+    let run = |extra: &[&str]| -> Result<String> {
+        let result = Command::new(env!("CARGO_BIN_EXE_gitkyl"))
+            .arg(repo.path())
+            .arg("-o")
+            .arg(output.path())
+            .arg("--no-open")
+            .args(extra)
+            .output()?;
+        assert!(result.status.success(), "gitkyl should succeed");
+        Ok(String::from_utf8_lossy(&result.stdout).into_owned())
+    };
+
+    // Act
+    let first = run(&[])?;
+    let second = run(&[])?;
+    let forced = run(&["--force"])?;
+
+    // Assert
+    assert!(output.path().join(".gitkyl-manifest").exists());
+    assert!(!first.contains("up to date"), "First run builds everything");
+    assert!(
+        second.contains("up to date"),
+        "Second run should skip the ref"
+    );
+    assert!(
+        !forced.contains("up to date"),
+        "--force bypasses the manifest"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_submodule_nested_site_e2e() -> Result<()> {
+    // Arrange
+    let library = common::create_test_repo()?;
+    common::write_file(library.path(), "lib.rs", "pub fn lib() {}\n")?;
+    common::git_add(library.path(), &["."])?;
+    common::git_commit(library.path(), "Library commit")?;
+
+    let repo = common::create_test_repo()?;
+    common::write_file(repo.path(), "README.md", "# App\n")?;
+    common::git_add(repo.path(), &["."])?;
+    common::git_commit(repo.path(), "Initial commit")?;
+    let added = Command::new("git")
+        .args(["-c", "protocol.file.allow=always", "submodule", "add", "-q"])
+        .arg(library.path())
+        .arg("vendor/lib")
+        .current_dir(repo.path())
+        .output()?;
+    assert!(added.status.success(), "submodule add should succeed");
+    common::git_commit(repo.path(), "Add library submodule")?;
+    let pinned = String::from_utf8(
+        Command::new("git")
+            .args(["rev-parse", "HEAD:vendor/lib"])
+            .current_dir(repo.path())
+            .output()?
+            .stdout,
+    )?;
+    // Moving the checkout past the pinned commit must not change the site
+    let checkout = repo.path().join("vendor/lib");
+    for (key, value) in [
+        ("user.name", "Test User"),
+        ("user.email", "test@example.com"),
+    ] {
+        Command::new("git")
+            .args(["config", key, value])
+            .current_dir(&checkout)
+            .output()?;
+    }
+    common::write_file(&checkout, "unpinned.rs", "pub fn later() {}\n")?;
+    common::git_add(&checkout, &["."])?;
+    common::git_commit(&checkout, "Unpinned commit")?;
+    let output = tempfile::TempDir::new()?;
+
+    // Act
+    let result = Command::new(env!("CARGO_BIN_EXE_gitkyl"))
+        .arg(repo.path())
+        .arg("-o")
+        .arg(output.path())
+        .arg("--no-open")
+        .output()?;
+
+    // Assert
+    assert!(result.status.success(), "gitkyl should succeed");
+    let nested_site = format!("submodules/vendor/lib/{}/index.html", pinned.trim());
+    let nested_index = std::fs::read_to_string(output.path().join(&nested_site))?;
+    assert!(
+        nested_index.contains("lib.rs"),
+        "Checked out submodule gets a site"
+    );
+    assert!(
+        !nested_index.contains("unpinned.rs"),
+        "Nested site shows the pinned commit"
+    );
+
+    let branch = String::from_utf8(
+        Command::new("git")
+            .args(["branch", "--show-current"])
+            .current_dir(repo.path())
+            .output()?
+            .stdout,
+    )?;
+    let tree_page = output
+        .path()
+        .join("tree")
+        .join(branch.trim())
+        .join("vendor.html");
+    let html = std::fs::read_to_string(tree_page)?;
+    assert!(html.contains("submodule-row"));
+    assert!(html.contains(&nested_site));
+
+    Ok(())
+}
+
+/// Tests raw file copies back blob page buttons and markdown images.
+#[test]
+fn test_raw_files_e2e() -> Result<()> {
+    // Arrange
+    let repo = common::create_test_repo()?;
+    common::write_file(repo.path(), "docs/guide.md", "![Logo](../img/logo.png)\n")?;
+    common::write_file(repo.path(), "src/lib.rs", "pub fn a() {}\n")?;
+    let png = [0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00];
+    fs::create_dir_all(repo.path().join("img"))?;
+    fs::write(repo.path().join("img/logo.png"), png)?;
+    common::git_add(repo.path(), &["."])?;
+    common::git_commit(repo.path(), "Initial commit")?;
+    let output = tempfile::TempDir::new()?;
+
+    // Act
+    let result = Command::new(env!("CARGO_BIN_EXE_gitkyl"))
+        .arg(repo.path())
+        .arg("-o")
+        .arg(output.path())
+        .arg("--no-open")
+        .output()?;
+
+    // Assert
+    assert!(result.status.success(), "gitkyl should succeed");
+    let branch = String::from_utf8(
+        Command::new("git")
+            .args(["branch", "--show-current"])
+            .current_dir(repo.path())
+            .output()?
+            .stdout,
+    )?;
+    let branch = branch.trim();
+    let raw = output.path().join("raw").join(branch);
+    assert_eq!(fs::read(raw.join("img/logo.png"))?, png);
+    assert_eq!(
+        fs::read_to_string(raw.join("src/lib.rs"))?,
+        "pub fn a() {}\n"
+    );
+
+    let guide = fs::read_to_string(
+        output
+            .path()
+            .join("blob")
+            .join(branch)
+            .join("docs/guide.md.html"),
+    )?;
+    assert!(
+        guide.contains(&format!("src=\"../../../raw/{}/img/logo.png\"", branch)),
+        "Markdown images should load the raw file"
+    );
+    let lib = fs::read_to_string(
+        output
+            .path()
+            .join("blob")
+            .join(branch)
+            .join("src/lib.rs.html"),
+    )?;
+    assert!(lib.contains(&format!("../../../raw/{}/src/lib.rs", branch)));
+    assert!(lib.contains("download=\"lib.rs\""));
+
+    Ok(())
+}
@@ -114,6 +114,24 @@ fn test_config_validation_valid() -> Result<()> {
         owner: None,
         theme: "Catppuccin-Latte".to_string(),
         no_open: true,
+        follow_renames: false,
+        force: false,
+        jobs: None,
+        mailmap: None,
+        allowed_signers: None,
+        notes_refs: Vec::new(),
+        multi: false,
+        branch_archives: false,
+        include_branches: vec![],
+        exclude_branches: vec![],
+        include_tags: vec![],
+        exclude_tags: vec![],
+        max_tags: None,
+        max_commits: None,
+        since: None,
+        stop_at_default_branch: false,
+        dumb_http: false,
+        base_url: None,
     };
 
     // Act
@@ -136,6 +154,24 @@ fn test_config_validation_invalid_repo() {
         owner: None,
         theme: "Catppuccin-Latte".to_string(),
         no_open: true,
+        follow_renames: false,
+        force: false,
+        jobs: None,
+        mailmap: None,
+        allowed_signers: None,
+        notes_refs: Vec::new(),
+        multi: false,
+        branch_archives: false,
+        include_branches: vec![],
+        exclude_branches: vec![],
+        include_tags: vec![],
+        exclude_tags: vec![],
+        max_tags: None,
+        max_commits: None,
+        since: None,
+        stop_at_default_branch: false,
+        dumb_http: false,
+        base_url: None,
     };
 
     // Act
@@ -159,6 +195,24 @@ fn test_project_name_custom() -> Result<()> {
         owner: None,
         theme: "Catppuccin-Latte".to_string(),
         no_open: true,
+        follow_renames: false,
+        force: false,
+        jobs: None,
+        mailmap: None,
+        allowed_signers: None,
+        notes_refs: Vec::new(),
+        multi: false,
+        branch_archives: false,
+        include_branches: vec![],
+        exclude_branches: vec![],
+        include_tags: vec![],
+        exclude_tags: vec![],
+        max_tags: None,
+        max_commits: None,
+        since: None,
+        stop_at_default_branch: false,
+        dumb_http: false,
+        base_url: None,
     };
 
     // Act
@@ -181,6 +235,24 @@ fn test_project_name_fallback() -> Result<()> {
         owner: None,
         theme: "Catppuccin-Latte".to_string(),
         no_open: true,
+        follow_renames: false,
+        force: false,
+        jobs: None,
+        mailmap: None,
+        allowed_signers: None,
+        notes_refs: Vec::new(),
+        multi: false,
+        branch_archives: false,
+        include_branches: vec![],
+        exclude_branches: vec![],
+        include_tags: vec![],
+        exclude_tags: vec![],
+        max_tags: None,
+        max_commits: None,
+        since: None,
+        stop_at_default_branch: false,
+        dumb_http: false,
+        base_url: None,
     };
 
     // Act
@@ -203,6 +275,24 @@ fn test_project_name_with_current_dir() -> Result<()> {
         owner: None,
         theme: "Catppuccin-Latte".to_string(),
         no_open: true,
+        follow_renames: false,
+        force: false,
+        jobs: None,
+        mailmap: None,
+        allowed_signers: None,
+        notes_refs: Vec::new(),
+        multi: false,
+        branch_archives: false,
+        include_branches: vec![],
+        exclude_branches: vec![],
+        include_tags: vec![],
+        exclude_tags: vec![],
+        max_tags: None,
+        max_commits: None,
+        since: None,
+        stop_at_default_branch: false,
+        dumb_http: false,
+        base_url: None,
     };
 
     // Act
@@ -228,6 +318,24 @@ fn test_project_name_root_path() {
         owner: None,
         theme: "Catppuccin-Latte".to_string(),
         no_open: true,
+        follow_renames: false,
+        force: false,
+        jobs: None,
+        mailmap: None,
+        allowed_signers: None,
+        notes_refs: Vec::new(),
+        multi: false,
+        branch_archives: false,
+        include_branches: vec![],
+        exclude_branches: vec![],
+        include_tags: vec![],
+        exclude_tags: vec![],
+        max_tags: None,
+        max_commits: None,
+        since: None,
+        stop_at_default_branch: false,
+        dumb_http: false,
+        base_url: None,
     };
 
     // Act
@@ -743,12 +851,11 @@ mod tree_page_tests {
         let oid_bytes = hex::decode(hex_40).expect("Invalid OID");
         let oid = gix::ObjectId::try_from(&oid_bytes[..]).expect("Failed to create OID");
 
-        unsafe {
-            std::mem::transmute::<(BString, gix::ObjectId), gitkyl::FileEntry>((
-                BString::from(path.as_bytes()),
-                oid,
-            ))
-        }
+        gitkyl::FileEntry::new(
+            BString::from(path.as_bytes()),
+            oid,
+            gix::object::tree::EntryKind::Blob.into(),
+        )
     }
 
     fn create_test_commit(oid: &str, message: &str, author: &str, date: i64) -> CommitInfo {